@@ -0,0 +1,42 @@
+//! # AppLogger - Trait Implementations
+//!
+//! This module contains trait implementations for `AppLogger`.
+//!
+//! ## Implemented Traits
+//!
+//! - `log::Log`
+
+use super::types::{AppLogger, LogLevel, LogRecord, LOG_BUFFER_LIMIT};
+
+impl log::Log for AppLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let level = match record.level() {
+            log::Level::Error => LogLevel::Error,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Debug => LogLevel::Debug,
+            log::Level::Trace => LogLevel::Trace,
+        };
+        let Ok(mut inbox) = self.inbox.lock() else {
+            return;
+        };
+        // Nobody drains the inbox while the window is hidden; keep the newest.
+        if inbox.len() >= LOG_BUFFER_LIMIT {
+            inbox.pop_front();
+        }
+        inbox.push_back(LogRecord {
+            id: 0,
+            timestamp: chrono::Local::now(),
+            level,
+            module: record.target().to_string(),
+            message: record.args().to_string(),
+        });
+    }
+    fn flush(&self) {}
+}
@@ -0,0 +1,22 @@
+//! # AuditLogState - Trait Implementations
+//!
+//! This module contains trait implementations for `AuditLogState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::AuditLogState;
+
+impl Default for AuditLogState {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            records: Vec::new(),
+            skipped_lines: 0,
+            operation_filter: None,
+            path_filter: String::new(),
+            error_msg: None,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! # AutostartState - Trait Implementations
+//!
+//! This module contains trait implementations for `AutostartState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::AutostartState;
+
+impl Default for AutostartState {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            loader: None,
+            loaded: false,
+            errors: Vec::new(),
+            add_program: None,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! # DirectoryWatchState - Trait Implementations
+//!
+//! This module contains trait implementations for `DirectoryWatchState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use std::collections::{HashMap, HashSet};
+use super::types::DirectoryWatchState;
+
+impl Default for DirectoryWatchState {
+    fn default() -> Self {
+        Self {
+            watcher: None,
+            receiver: None,
+            watched: HashSet::new(),
+            pending: HashMap::new(),
+            listings: HashMap::new(),
+            failures: HashMap::new(),
+            unwatchable: HashMap::new(),
+            watcher_failed_at: None,
+            synced_paths: None,
+            error_msg: None,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! # DisksState - Trait Implementations
+//!
+//! This module contains trait implementations for `DisksState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::{BlockDeviceStats, DisksState};
+
+impl Default for DisksState {
+    fn default() -> Self {
+        Self {
+            disks: sysinfo::Disks::new(),
+            loader: None,
+            loaded: false,
+            devices: Vec::new(),
+            total: BlockDeviceStats {
+                name: "All disks".to_string(),
+                ..BlockDeviceStats::default()
+            },
+            selected_device: None,
+            sampled_at: None,
+            process_totals: std::collections::HashMap::new(),
+            processes_sampled_at: None,
+        }
+    }
+}
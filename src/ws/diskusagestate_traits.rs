@@ -0,0 +1,28 @@
+//! # DiskUsageState - Trait Implementations
+//!
+//! This module contains trait implementations for `DiskUsageState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::DiskUsageState;
+
+impl Default for DiskUsageState {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            root_path: String::new(),
+            source_panel: 0,
+            is_running: false,
+            receiver: None,
+            cancel_flag: None,
+            report: None,
+            focus: Vec::new(),
+            top_n: 20,
+            scanned_files: 0,
+            scanned_bytes: 0,
+            error_msg: None,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! # DuplicateFinderState - Trait Implementations
+//!
+//! This module contains trait implementations for `DuplicateFinderState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::{ChecksumAlgorithm, DuplicateFinderState};
+
+impl Default for DuplicateFinderState {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            scan_left: true,
+            scan_right: false,
+            recursive: true,
+            min_size: "1".to_string(),
+            algorithm: ChecksumAlgorithm::SHA256,
+            is_running: false,
+            receiver: None,
+            cancel_flag: None,
+            groups: Vec::new(),
+            stage: String::new(),
+            progress_done: 0,
+            progress_total: 0,
+            error_msg: None,
+        }
+    }
+}
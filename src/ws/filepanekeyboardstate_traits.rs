@@ -0,0 +1,27 @@
+//! # FilepaneKeyboardState - Trait Implementations
+//!
+//! This module contains trait implementations for `FilepaneKeyboardState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::FilepaneKeyboardState;
+
+impl Default for FilepaneKeyboardState {
+    fn default() -> Self {
+        Self {
+            active_panel: 0,
+            cursor: [None, None],
+            visible: [Vec::new(), Vec::new()],
+            page_rows: 20,
+            scroll_to_cursor: false,
+            pending_actions: Vec::new(),
+            pending_text: String::new(),
+            jump_buffer: String::new(),
+            last_jump: None,
+            show_help: false,
+            mkdir_name: None,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! # FilepaneKeymap - Trait Implementations
+//!
+//! This module contains trait implementations for `FilepaneKeymap`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use egui::Key;
+use super::types::{FilepaneKeyAction, FilepaneKeymap, KeyBinding};
+
+impl Default for FilepaneKeymap {
+    fn default() -> Self {
+        Self {
+            bindings: vec![
+                (FilepaneKeyAction::MoveUp, vec![KeyBinding::plain(Key::ArrowUp)]),
+                (FilepaneKeyAction::MoveDown, vec![KeyBinding::plain(Key::ArrowDown)]),
+                (FilepaneKeyAction::PageUp, vec![KeyBinding::plain(Key::PageUp)]),
+                (FilepaneKeyAction::PageDown, vec![KeyBinding::plain(Key::PageDown)]),
+                (FilepaneKeyAction::Home, vec![KeyBinding::plain(Key::Home)]),
+                (FilepaneKeyAction::End, vec![KeyBinding::plain(Key::End)]),
+                (FilepaneKeyAction::Open, vec![KeyBinding::plain(Key::Enter)]),
+                (FilepaneKeyAction::Parent, vec![KeyBinding::plain(Key::Backspace)]),
+                (FilepaneKeyAction::SwitchPanel, vec![KeyBinding::plain(Key::Tab)]),
+                (
+                    FilepaneKeyAction::ToggleMark,
+                    vec![KeyBinding::plain(Key::Insert), KeyBinding::plain(Key::Space)],
+                ),
+                (FilepaneKeyAction::View, vec![KeyBinding::plain(Key::F3)]),
+                (FilepaneKeyAction::Copy, vec![KeyBinding::plain(Key::F5)]),
+                (FilepaneKeyAction::Move, vec![KeyBinding::plain(Key::F6)]),
+                (FilepaneKeyAction::MakeDirectory, vec![KeyBinding::plain(Key::F7)]),
+                (
+                    FilepaneKeyAction::Delete,
+                    vec![KeyBinding::plain(Key::F8), KeyBinding::plain(Key::Delete)],
+                ),
+                (FilepaneKeyAction::Refresh, vec![KeyBinding::ctrl(Key::R)]),
+                (
+                    FilepaneKeyAction::Back,
+                    vec![KeyBinding { key: Key::ArrowLeft, ctrl: false, shift: false, alt: true }],
+                ),
+                (
+                    FilepaneKeyAction::Forward,
+                    vec![KeyBinding { key: Key::ArrowRight, ctrl: false, shift: false, alt: true }],
+                ),
+                (FilepaneKeyAction::QuickJump, vec![KeyBinding::ctrl(Key::G)]),
+                (FilepaneKeyAction::ToggleBookmark, vec![KeyBinding::ctrl(Key::D)]),
+                (FilepaneKeyAction::ShowHelp, vec![KeyBinding::plain(Key::F1)]),
+            ],
+        }
+    }
+}
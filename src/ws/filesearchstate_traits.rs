@@ -0,0 +1,37 @@
+//! # FileSearchState - Trait Implementations
+//!
+//! This module contains trait implementations for `FileSearchState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::FileSearchState;
+
+impl Default for FileSearchState {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            root_path: String::new(),
+            source_panel: 0,
+            name_pattern: String::new(),
+            use_regex: false,
+            case_sensitive: false,
+            content_pattern: String::new(),
+            min_size: String::new(),
+            max_size: String::new(),
+            modified_after: String::new(),
+            modified_before: String::new(),
+            include_directories: false,
+            results: Vec::new(),
+            scanned_dirs: 0,
+            scanned_files: 0,
+            is_running: false,
+            started_at: None,
+            receiver: None,
+            cancel_flag: None,
+            selected_result: None,
+            error_msg: None,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! # FileViewerState - Trait Implementations
+//!
+//! This module contains trait implementations for `FileViewerState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::{FileViewerMode, FileViewerState, TextEncoding};
+
+impl Default for FileViewerState {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            path: String::new(),
+            file_size: 0,
+            mode: FileViewerMode::Text,
+            encoding: TextEncoding::Utf8,
+            text_page: 0,
+            text_page_offsets: Vec::new(),
+            text_lines: Vec::new(),
+            text_reached_eof: false,
+            text_indexer: None,
+            pending_page: None,
+            goto_line_input: String::new(),
+            highlight_line: None,
+            hex_offset: 0,
+            hex_bytes: Vec::new(),
+            hex_offset_input: String::new(),
+            image_texture: None,
+            error_msg: None,
+        }
+    }
+}
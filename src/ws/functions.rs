@@ -35,3 +35,503 @@ pub fn main() -> eframe::Result {
         Box::new(|cc| Ok(Box::new(ProcessManagerApp::new(cc)))),
     )
 }
+
+/// Scores `candidate` against `query` as a case-insensitive subsequence
+/// match, or `None` when some query character is missing. Consecutive
+/// characters and matches at the start of a word or path segment score
+/// higher, and shorter candidates win ties.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    let query: Vec<char> = query.to_lowercase().chars().filter(|c| !c.is_whitespace()).collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+    let candidate: Vec<char> = candidate.chars().collect();
+    let mut score = 0;
+    let mut query_index = 0;
+    let mut previous_match: Option<usize> = None;
+    for (index, ch) in candidate.iter().enumerate() {
+        if query_index == query.len() {
+            break;
+        }
+        if !ch.to_lowercase().eq(std::iter::once(query[query_index])) {
+            continue;
+        }
+        score += 1;
+        if previous_match == Some(index.wrapping_sub(1)) {
+            score += 5;
+        }
+        let at_boundary = index == 0
+            || matches!(candidate[index - 1], '/' | '\\' | ' ' | '_' | '-' | '.');
+        if at_boundary {
+            score += 8;
+        }
+        previous_match = Some(index);
+        query_index += 1;
+    }
+    if query_index < query.len() {
+        return None;
+    }
+    Some(score * 10 - candidate.len() as i32)
+}
+
+/// True for `sftp://` panel paths served by a remote backend.
+pub fn is_remote_path(path: &str) -> bool {
+    crate::ws::SftpTarget::parse(path).is_some()
+}
+
+/// Parent of a panel path; remote paths stop at the server's root instead of
+/// climbing into the `sftp://` prefix.
+pub fn parent_directory(path: &str) -> Option<String> {
+    if let Some((target, remote)) = crate::ws::SftpTarget::parse(path) {
+        let parent = std::path::Path::new(&remote).parent()?;
+        return Some(target.uri(&parent.to_string_lossy().replace('\\', "/")));
+    }
+    std::path::Path::new(path)
+        .parent()
+        .map(|parent| parent.to_string_lossy().to_string())
+}
+
+/// Entry `name` inside the panel directory `directory`, with the separator
+/// its backend expects.
+pub fn join_panel_path(directory: &str, name: &str) -> String {
+    if is_remote_path(directory) {
+        format!("{}/{}", directory.trim_end_matches('/'), name)
+    } else {
+        std::path::Path::new(directory).join(name).to_string_lossy().to_string()
+    }
+}
+
+/// Whether two files have byte-for-byte identical contents.
+pub fn same_contents(first: &str, second: &str) -> std::io::Result<bool> {
+    const CHUNK: usize = 64 * 1024;
+    let mut first = std::io::BufReader::with_capacity(CHUNK, File::open(first)?);
+    let mut second = std::io::BufReader::with_capacity(CHUNK, File::open(second)?);
+    let mut first_buffer = vec![0u8; CHUNK];
+    let mut second_buffer = vec![0u8; CHUNK];
+    loop {
+        let read = read_full(&mut first, &mut first_buffer)?;
+        if read_full(&mut second, &mut second_buffer[..read.max(1)])? != read {
+            return Ok(false);
+        }
+        if read == 0 {
+            return Ok(true);
+        }
+        if first_buffer[..read] != second_buffer[..read] {
+            return Ok(false);
+        }
+    }
+}
+
+/// Copies `reader` into `writer`, sleeping between chunks so the average
+/// rate stays at or below `bytes_per_sec`. `None` copies at full speed.
+pub fn copy_limited(
+    reader: &mut impl std::io::Read,
+    writer: &mut impl std::io::Write,
+    bytes_per_sec: Option<f64>,
+) -> std::io::Result<u64> {
+    let Some(limit) = bytes_per_sec.filter(|limit| *limit > 0.0) else {
+        return std::io::copy(reader, writer);
+    };
+    let started = Instant::now();
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut total = 0u64;
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => return Ok(total),
+            Ok(read) => read,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        writer.write_all(&buffer[..read])?;
+        total += read as u64;
+        let due = Duration::from_secs_f64(total as f64 / limit);
+        if let Some(ahead) = due.checked_sub(started.elapsed()) {
+            std::thread::sleep(ahead);
+        }
+    }
+}
+
+/// Fills `buffer` unless the end of the file comes first.
+fn read_full(reader: &mut impl std::io::Read, buffer: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..])? {
+            0 => break,
+            read => filled += read,
+        }
+    }
+    Ok(filled)
+}
+
+/// Shrinks a 32-bit BGRA/BGRX image to fit within `max_width` x
+/// `max_height`, keeping its aspect ratio. Each thumbnail pixel averages all
+/// source pixels it covers; images are never enlarged.
+pub fn downscale_bgra(
+    data: &[u8],
+    width: usize,
+    height: usize,
+    max_width: usize,
+    max_height: usize,
+) -> egui::ColorImage {
+    let scale = f32::min(
+        max_width.max(1) as f32 / width as f32,
+        max_height.max(1) as f32 / height as f32,
+    )
+    .min(1.0);
+    let thumb_width = ((width as f32 * scale) as usize).max(1);
+    let thumb_height = ((height as f32 * scale) as usize).max(1);
+    let mut pixels = Vec::with_capacity(thumb_width * thumb_height);
+    for ty in 0..thumb_height {
+        let y0 = ty * height / thumb_height;
+        let y1 = ((ty + 1) * height / thumb_height).max(y0 + 1);
+        for tx in 0..thumb_width {
+            let x0 = tx * width / thumb_width;
+            let x1 = ((tx + 1) * width / thumb_width).max(x0 + 1);
+            let mut sum = [0u32; 3];
+            for y in y0..y1 {
+                let row = &data[(y * width + x0) * 4..(y * width + x1) * 4];
+                for pixel in row.chunks_exact(4) {
+                    sum[0] += pixel[2] as u32;
+                    sum[1] += pixel[1] as u32;
+                    sum[2] += pixel[0] as u32;
+                }
+            }
+            let count = ((y1 - y0) * (x1 - x0)) as u32;
+            pixels.push(
+                egui::Color32::from_rgb(
+                    (sum[0] / count) as u8,
+                    (sum[1] / count) as u8,
+                    (sum[2] / count) as u8,
+                ),
+            );
+        }
+    }
+    egui::ColorImage {
+        size: [thumb_width, thumb_height],
+        pixels,
+    }
+}
+
+/// Captures a window's current content as a thumbnail. Safe to call from
+/// worker threads: on X11 each thread keeps its own server connection.
+#[cfg(windows)]
+pub fn capture_window_image(window_id: u64, max_width: i32, max_height: i32) -> Option<egui::ColorImage> {
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::Graphics::Gdi::{
+        CreateCompatibleDC, CreateCompatibleBitmap, SelectObject, DeleteDC, DeleteObject,
+        GetDC, ReleaseDC, BitBlt, SRCCOPY, GetDIBits, BITMAPINFO, BITMAPINFOHEADER,
+        BI_RGB, DIB_RGB_COLORS,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::GetWindowRect;
+    
+    unsafe {
+        let hwnd = HWND(window_id as *mut _);
+        
+        // Get window size
+        let mut rect = std::mem::zeroed();
+        if GetWindowRect(hwnd, &mut rect).is_err() {
+            return None;
+        }
+        
+        let width = (rect.right - rect.left) as i32;
+        let height = (rect.bottom - rect.top) as i32;
+        
+        if width <= 0 || height <= 0 {
+            return None;
+        }
+        
+        // Create DC and bitmap
+        let hdc_window = GetDC(hwnd);
+        if hdc_window.0.is_null() {
+            return None;
+        }
+        
+        let hdc_mem = CreateCompatibleDC(hdc_window);
+        if hdc_mem.0.is_null() {
+            ReleaseDC(hwnd, hdc_window);
+            return None;
+        }
+        
+        let hbitmap = CreateCompatibleBitmap(hdc_window, width, height);
+        if hbitmap.0.is_null() {
+            DeleteDC(hdc_mem);
+            ReleaseDC(hwnd, hdc_window);
+            return None;
+        }
+        
+        let old_bitmap = SelectObject(hdc_mem, hbitmap);
+        
+        // Capture window content using BitBlt
+        let _ = BitBlt(hdc_mem, 0, 0, width, height, hdc_window, 0, 0, SRCCOPY);
+        
+        // Get bitmap data
+        let mut bmi = BITMAPINFO {
+            bmiHeader: BITMAPINFOHEADER {
+                biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+                biWidth: width,
+                biHeight: -height, // Top-down
+                biPlanes: 1,
+                biBitCount: 32,
+                biCompression: BI_RGB.0,
+                biSizeImage: 0,
+                biXPelsPerMeter: 0,
+                biYPelsPerMeter: 0,
+                biClrUsed: 0,
+                biClrImportant: 0,
+            },
+            bmiColors: [std::mem::zeroed()],
+        };
+        
+        let mut buffer: Vec<u8> = vec![0; (width * height * 4) as usize];
+        
+        let result = GetDIBits(
+            hdc_mem,
+            hbitmap,
+            0,
+            height as u32,
+            Some(buffer.as_mut_ptr() as *mut _),
+            &mut bmi,
+            DIB_RGB_COLORS,
+        );
+        
+        // Cleanup
+        SelectObject(hdc_mem, old_bitmap);
+        DeleteObject(hbitmap);
+        DeleteDC(hdc_mem);
+        ReleaseDC(hwnd, hdc_window);
+        
+        if result == 0 {
+            return None;
+        }
+        
+        Some(downscale_bgra(
+            &buffer,
+            width as usize,
+            height as usize,
+            max_width.max(32) as usize,
+            max_height.max(32) as usize,
+        ))
+    }
+}
+
+#[cfg(target_os = "linux")]
+thread_local! {
+    static CAPTURE_CONNECTION: std::cell::RefCell<Option<crate::ws::X11Windows>> = const {
+        std::cell::RefCell::new(None)
+    };
+}
+
+/// Captures a window's current content as a thumbnail. Safe to call from
+/// worker threads: on X11 each thread keeps its own server connection.
+#[cfg(target_os = "linux")]
+pub fn capture_window_image(window_id: u64, max_width: i32, max_height: i32) -> Option<egui::ColorImage> {
+    CAPTURE_CONNECTION.with(|connection| {
+        let mut connection = connection.borrow_mut();
+        if connection.is_none() {
+            *connection = crate::ws::X11Windows::connect().ok();
+        }
+        let x11 = connection.as_ref()?;
+        let captured = x11.capture(
+            window_id as u32,
+            max_width.max(32) as usize,
+            max_height.max(32) as usize,
+        );
+        if captured.is_err() {
+            // Usually the window went away; only a connection that no longer
+            // answers is dropped, to be reopened on the next capture.
+            use x11rb::protocol::xproto::ConnectionExt;
+            let alive = x11
+                .conn
+                .get_input_focus()
+                .ok()
+                .and_then(|cookie| cookie.reply().ok())
+                .is_some();
+            if !alive {
+                *connection = None;
+            }
+        }
+        captured.ok()
+    })
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+pub fn capture_window_image(_window_id: u64, _max_width: i32, _max_height: i32) -> Option<egui::ColorImage> {
+    None
+}
+
+/// Undoes the Composite redirection this thread's connection set up to
+/// capture the window.
+#[cfg(target_os = "linux")]
+pub fn release_window_capture(window_id: u64) {
+    CAPTURE_CONNECTION.with(|connection| {
+        if let Some(x11) = connection.borrow().as_ref() {
+            x11.release(window_id as u32);
+        }
+    });
+}
+
+/// Captures keep no per-window state here.
+#[cfg(not(target_os = "linux"))]
+pub fn release_window_capture(_window_id: u64) {}
+
+/// Decodes an image file into a square-bounded icon of at most `size`
+/// pixels per side.
+pub fn load_icon_image(path: &str, size: u32) -> Option<egui::ColorImage> {
+    let image = image::ImageReader::open(path)
+        .ok()?
+        .with_guessed_format()
+        .ok()?
+        .decode()
+        .ok()?;
+    let rgba = image.thumbnail(size, size).to_rgba8();
+    Some(
+        egui::ColorImage::from_rgba_unmultiplied(
+            [rgba.width() as usize, rgba.height() as usize],
+            rgba.as_raw(),
+        ),
+    )
+}
+
+/// Extracts the first icon embedded in an executable's PE resources.
+#[cfg(windows)]
+pub fn extract_executable_icon(exe_path: &str) -> Option<egui::ColorImage> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows::core::PCWSTR;
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::Graphics::Gdi::{
+        DeleteObject, GetDC, GetDIBits, GetObjectW, ReleaseDC, BITMAP, BITMAPINFO,
+        BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS,
+    };
+    use windows::Win32::UI::Shell::ExtractIconExW;
+    use windows::Win32::UI::WindowsAndMessaging::{DestroyIcon, GetIconInfo, HICON, ICONINFO};
+    let wide: Vec<u16> = std::ffi::OsStr::new(exe_path)
+        .encode_wide()
+        .chain(std::iter::once(0))
+        .collect();
+    unsafe {
+        let mut icon = HICON::default();
+        if ExtractIconExW(PCWSTR::from_raw(wide.as_ptr()), 0, Some(&mut icon), None, 1) == 0
+            || icon.is_invalid()
+        {
+            return None;
+        }
+        let mut info = ICONINFO::default();
+        let pixels = GetIconInfo(icon, &mut info)
+            .ok()
+            .and_then(|_| {
+                let mut bitmap = BITMAP::default();
+                let read = GetObjectW(
+                    info.hbmColor,
+                    std::mem::size_of::<BITMAP>() as i32,
+                    Some(&mut bitmap as *mut BITMAP as *mut std::ffi::c_void),
+                );
+                if read == 0 || bitmap.bmWidth <= 0 || bitmap.bmHeight <= 0 {
+                    return None;
+                }
+                let (width, height) = (bitmap.bmWidth, bitmap.bmHeight);
+                let mut bmi = BITMAPINFO {
+                    bmiHeader: BITMAPINFOHEADER {
+                        biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+                        biWidth: width,
+                        biHeight: -height,
+                        biPlanes: 1,
+                        biBitCount: 32,
+                        biCompression: BI_RGB.0,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                };
+                let mut buffer = vec![0u8; (width * height * 4) as usize];
+                let hdc = GetDC(HWND(std::ptr::null_mut()));
+                let lines = GetDIBits(
+                    hdc,
+                    info.hbmColor,
+                    0,
+                    height as u32,
+                    Some(buffer.as_mut_ptr() as *mut _),
+                    &mut bmi,
+                    DIB_RGB_COLORS,
+                );
+                ReleaseDC(HWND(std::ptr::null_mut()), hdc);
+                (lines != 0).then_some((buffer, width as usize, height as usize))
+            });
+        let _ = DeleteObject(info.hbmColor);
+        let _ = DeleteObject(info.hbmMask);
+        let _ = DestroyIcon(icon);
+        let (mut buffer, width, height) = pixels?;
+        // Icons without an alpha channel rely on the mask; show them opaque.
+        let has_alpha = buffer.chunks_exact(4).any(|pixel| pixel[3] != 0);
+        for pixel in buffer.chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+            if !has_alpha {
+                pixel[3] = 255;
+            }
+        }
+        Some(egui::ColorImage::from_rgba_unmultiplied([width, height], &buffer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An empty directory under the system temp dir, unique to this test.
+    fn scratch_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("winshell-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn same_contents_compares_bytes() {
+        let dir = scratch_dir("same-contents");
+        let write = |name: &str, content: &[u8]| {
+            let path = dir.join(name);
+            std::fs::write(&path, content).unwrap();
+            path.to_string_lossy().to_string()
+        };
+        // Larger than one comparison chunk, differing only at the end.
+        let mut big = vec![7u8; 200 * 1024];
+        let a = write("a", &big);
+        let b = write("b", &big);
+        *big.last_mut().unwrap() = 8;
+        let c = write("c", &big);
+        let short = write("short", &big[..1000]);
+        let empty = write("empty", b"");
+        assert!(same_contents(&a, &b).unwrap());
+        assert!(!same_contents(&a, &c).unwrap());
+        assert!(!same_contents(&a, &short).unwrap());
+        assert!(!same_contents(&short, &a).unwrap());
+        assert!(same_contents(&empty, &empty).unwrap());
+        assert!(!same_contents(&empty, &a).unwrap());
+        assert!(same_contents(&a, &dir.join("missing").to_string_lossy()).is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn join_panel_path_uses_the_backend_separator() {
+        assert_eq!(join_panel_path("sftp://alice@host:22/srv/", "a.txt"), "sftp://alice@host:22/srv/a.txt");
+        assert_eq!(join_panel_path("sftp://alice@host:22/", "etc"), "sftp://alice@host:22/etc");
+        assert_eq!(
+            join_panel_path("/home/alice", "notes.md"),
+            std::path::Path::new("/home/alice").join("notes.md").to_string_lossy()
+        );
+    }
+
+    #[test]
+    fn copy_limited_holds_the_rate() {
+        let data = vec![3u8; 256 * 1024];
+        let mut copied = Vec::new();
+        let started = Instant::now();
+        // 256 KiB at 1 MiB/s needs about a quarter of a second.
+        let bytes = copy_limited(&mut data.as_slice(), &mut copied, Some(1024.0 * 1024.0)).unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(240));
+        assert_eq!(bytes, data.len() as u64);
+        assert_eq!(copied, data);
+
+        let mut copied = Vec::new();
+        assert_eq!(copy_limited(&mut data.as_slice(), &mut copied, None).unwrap(), data.len() as u64);
+        assert_eq!(copied, data);
+    }
+}
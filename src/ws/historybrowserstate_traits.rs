@@ -0,0 +1,24 @@
+//! # HistoryBrowserState - Trait Implementations
+//!
+//! This module contains trait implementations for `HistoryBrowserState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use std::collections::HashSet;
+use super::types::{HistoryBrowserState, HistoryExportFormat};
+
+impl Default for HistoryBrowserState {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            files: Vec::new(),
+            selected_file: None,
+            search: String::new(),
+            selected: HashSet::new(),
+            export_format: HistoryExportFormat::Text,
+            status: None,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! # LauncherIndexState - Trait Implementations
+//!
+//! This module contains trait implementations for `LauncherIndexState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::LauncherIndexState;
+
+impl Default for LauncherIndexState {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            loader: None,
+            query: String::new(),
+            icons: std::collections::HashMap::new(),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! # LocalBackend - Trait Implementations
+//!
+//! This module contains trait implementations for `LocalBackend`.
+//!
+//! ## Implemented Traits
+//!
+//! - `FileSystemBackend`
+
+use super::functions::same_contents;
+use super::types::{FileInfo, FileSystemBackend, LocalBackend};
+
+impl FileSystemBackend for LocalBackend {
+    fn label(&self) -> String {
+        "local".to_string()
+    }
+    fn read_dir(&self, path: &str) -> std::io::Result<Vec<FileInfo>> {
+        Ok(
+            std::fs::read_dir(path)?
+                .flatten()
+                .filter_map(|entry| FileInfo::new(entry.path()).ok())
+                .collect(),
+        )
+    }
+    fn stat(&self, path: &str) -> std::io::Result<FileInfo> {
+        FileInfo::new(std::path::PathBuf::from(path))
+            .map_err(|e| std::io::Error::other(e.to_string()))
+    }
+    fn create_dir_all(&self, path: &str) -> std::io::Result<()> {
+        std::fs::create_dir_all(path)
+    }
+    fn rename(&self, from: &str, to: &str) -> std::io::Result<()> {
+        std::fs::rename(from, to)
+    }
+    fn remove_file(&self, path: &str) -> std::io::Result<()> {
+        std::fs::remove_file(path)
+    }
+    fn remove_dir(&self, path: &str) -> std::io::Result<()> {
+        std::fs::remove_dir(path)
+    }
+    fn read_link(&self, path: &str) -> std::io::Result<String> {
+        Ok(std::fs::read_link(path)?.to_string_lossy().to_string())
+    }
+    fn hard_link(&self, target: &str, link_path: &str, replace: bool) -> std::io::Result<()> {
+        if !replace || std::fs::symlink_metadata(link_path).is_err() {
+            return std::fs::hard_link(target, link_path);
+        }
+        let temp_path = format!("{}.winshell-link", link_path);
+        std::fs::hard_link(target, &temp_path)?;
+        // The file being replaced may have changed since it was found to be
+        // a duplicate; compare again just before it is swapped out.
+        let result = match same_contents(target, link_path) {
+            Ok(true) => std::fs::rename(&temp_path, link_path),
+            Ok(false) => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("{} no longer has the same contents as {}", link_path, target),
+            )),
+            Err(e) => Err(e),
+        };
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+        result
+    }
+    fn symlink(&self, target: &str, link_path: &str) -> std::io::Result<()> {
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(target, link_path)
+        }
+        #[cfg(target_os = "windows")]
+        {
+            if std::path::Path::new(target).is_dir() {
+                std::os::windows::fs::symlink_dir(target, link_path)
+            } else {
+                std::os::windows::fs::symlink_file(target, link_path)
+            }
+        }
+    }
+    fn open_read(&self, path: &str) -> std::io::Result<Box<dyn std::io::Read + '_>> {
+        Ok(Box::new(std::fs::File::open(path)?))
+    }
+    fn create_file(&self, path: &str) -> std::io::Result<Box<dyn std::io::Write + '_>> {
+        Ok(Box::new(std::fs::File::create(path)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("winshell-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn hard_link_replaces_identical_duplicate() {
+        let dir = scratch_dir("link-duplicate");
+        let keep = dir.join("keep").to_string_lossy().to_string();
+        let duplicate = dir.join("duplicate").to_string_lossy().to_string();
+        std::fs::write(&keep, b"same bytes").unwrap();
+        std::fs::write(&duplicate, b"same bytes").unwrap();
+        LocalBackend.hard_link(&keep, &duplicate, true).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            let inode = |path: &str| std::fs::metadata(path).unwrap().ino();
+            assert_eq!(inode(&keep), inode(&duplicate));
+        }
+        assert!(!std::path::Path::new(&format!("{}.winshell-link", duplicate)).exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn hard_link_refuses_changed_duplicate() {
+        let dir = scratch_dir("link-changed");
+        let keep = dir.join("keep").to_string_lossy().to_string();
+        let duplicate = dir.join("duplicate").to_string_lossy().to_string();
+        std::fs::write(&keep, b"same bytes").unwrap();
+        std::fs::write(&duplicate, b"other bytes").unwrap();
+        let error = LocalBackend.hard_link(&keep, &duplicate, true).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(std::fs::read(&duplicate).unwrap(), b"other bytes");
+        assert!(!std::path::Path::new(&format!("{}.winshell-link", duplicate)).exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn hard_link_without_replace_keeps_existing_file() {
+        let dir = scratch_dir("link-existing");
+        let target = dir.join("target").to_string_lossy().to_string();
+        let existing = dir.join("existing").to_string_lossy().to_string();
+        std::fs::write(&target, b"same bytes").unwrap();
+        std::fs::write(&existing, b"same bytes").unwrap();
+        let error = LocalBackend.hard_link(&target, &existing, false).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::AlreadyExists);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,22 @@
+//! # LogRecord - Trait Implementations
+//!
+//! This module contains trait implementations for `LogRecord`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Display`
+
+use super::types::LogRecord;
+
+impl std::fmt::Display for LogRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {:<5} [{}] {}",
+            self.timestamp.format("%Y-%m-%d %H:%M:%S%.3f"),
+            self.level.name(),
+            self.module,
+            self.message
+        )
+    }
+}
@@ -0,0 +1,20 @@
+//! # LogSettings - Trait Implementations
+//!
+//! This module contains trait implementations for `LogSettings`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::{LogLevel, LogSettings};
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        Self {
+            to_file: false,
+            max_file_kb: 1024,
+            library_level: LogLevel::Warn,
+            history_retention_days: 0,
+        }
+    }
+}
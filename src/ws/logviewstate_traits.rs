@@ -0,0 +1,25 @@
+//! # LogViewState - Trait Implementations
+//!
+//! This module contains trait implementations for `LogViewState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::{LogLevel, LogSettings, LogViewState};
+
+impl Default for LogViewState {
+    fn default() -> Self {
+        Self {
+            min_level: LogLevel::Trace,
+            module_filter: None,
+            search: String::new(),
+            next_id: 1,
+            settings: LogSettings::default(),
+            file_sink: None,
+            file_error: None,
+            visible: Vec::new(),
+            visible_key: None,
+        }
+    }
+}
@@ -2,6 +2,31 @@
 
 pub mod processmanagerapp_traits;
 pub mod fontpickerstate_traits;
+pub mod fileviewerstate_traits;
+pub mod filesearchstate_traits;
+pub mod diskusagestate_traits;
+pub mod duplicatefinderstate_traits;
+pub mod propertieseditorstate_traits;
+pub mod directorywatchstate_traits;
+pub mod filepanekeymap_traits;
+pub mod filepanekeyboardstate_traits;
+pub mod quickjumpstate_traits;
+pub mod launcherindexstate_traits;
+pub mod servicesstate_traits;
+pub mod autostartstate_traits;
+pub mod disksstate_traits;
+pub mod sftpconnectstate_traits;
+pub mod localbackend_traits;
+pub mod sftpbackend_traits;
+pub mod auditlogstate_traits;
+pub mod historybrowserstate_traits;
+pub mod waylandtoplevelstate_traits;
+pub mod thumbnailpipeline_traits;
+pub mod x11windows_traits;
+pub mod logrecord_traits;
+pub mod logsettings_traits;
+pub mod logviewstate_traits;
+pub mod applogger_traits;
 pub mod processmanagerapp_is_user_admin_group;
 pub mod processmanagerapp_restart_as_admin_group;
 pub mod processmanagerapp_configure_fonts_group;
@@ -30,6 +55,17 @@ pub mod processmanagerapp_show_taskbar_view_group;
 pub mod processmanagerapp_show_live_view_group;
 pub mod processmanagerapp_show_new_program_launcher_group;
 pub mod processmanagerapp_show_font_picker_group;
+pub mod processmanagerapp_show_file_viewer_group;
+pub mod processmanagerapp_show_file_search_group;
+pub mod processmanagerapp_show_disk_usage_group;
+pub mod processmanagerapp_show_duplicate_finder_group;
+pub mod processmanagerapp_show_properties_editor_group;
+pub mod processmanagerapp_watch_directories_group;
+pub mod processmanagerapp_handle_filepane_keys_group;
+pub mod processmanagerapp_show_quick_jump_group;
+pub mod processmanagerapp_show_sftp_connect_group;
+pub mod processmanagerapp_record_audit_group;
+pub mod processmanagerapp_add_log_group;
 pub mod processmanagerapp_show_settings_view_group;
 pub mod processmanagerapp_show_filepane_view_group;
 pub mod processmanagerapp_redo_last_action_group;
@@ -39,6 +75,15 @@ pub mod processmanagerapp_simple_truncate_filename_group;
 pub mod processmanagerapp_truncate_filename_group;
 pub mod processmanagerapp_execute_command_group;
 pub mod processmanagerapp_save_conversation_history_group;
+pub mod processmanagerapp_show_history_browser_group;
+pub mod processmanagerapp_arrange_windows_group;
+pub mod processmanagerapp_launch_profile_group;
+pub mod processmanagerapp_program_groups_group;
+pub mod processmanagerapp_launcher_index_group;
+pub mod processmanagerapp_process_icons_group;
+pub mod processmanagerapp_show_services_view_group;
+pub mod processmanagerapp_show_autostart_view_group;
+pub mod processmanagerapp_show_disks_view_group;
 pub mod processmanagerapp_show_kill_confirm_dialog_group;
 pub mod processmanagerapp_show_logs_view_group;
 pub mod processmanagerapp_add_conversation_entry_group;
@@ -50,6 +95,31 @@ pub mod functions;
 // Re-export all types
 pub use processmanagerapp_traits::*;
 pub use fontpickerstate_traits::*;
+pub use fileviewerstate_traits::*;
+pub use filesearchstate_traits::*;
+pub use diskusagestate_traits::*;
+pub use duplicatefinderstate_traits::*;
+pub use propertieseditorstate_traits::*;
+pub use directorywatchstate_traits::*;
+pub use filepanekeymap_traits::*;
+pub use filepanekeyboardstate_traits::*;
+pub use quickjumpstate_traits::*;
+pub use launcherindexstate_traits::*;
+pub use servicesstate_traits::*;
+pub use autostartstate_traits::*;
+pub use disksstate_traits::*;
+pub use sftpconnectstate_traits::*;
+pub use localbackend_traits::*;
+pub use sftpbackend_traits::*;
+pub use auditlogstate_traits::*;
+pub use historybrowserstate_traits::*;
+pub use waylandtoplevelstate_traits::*;
+pub use thumbnailpipeline_traits::*;
+pub use x11windows_traits::*;
+pub use logrecord_traits::*;
+pub use logsettings_traits::*;
+pub use logviewstate_traits::*;
+pub use applogger_traits::*;
 pub use processmanagerapp_is_user_admin_group::*;
 pub use processmanagerapp_restart_as_admin_group::*;
 pub use processmanagerapp_configure_fonts_group::*;
@@ -78,6 +148,17 @@ pub use processmanagerapp_show_taskbar_view_group::*;
 pub use processmanagerapp_show_live_view_group::*;
 pub use processmanagerapp_show_new_program_launcher_group::*;
 pub use processmanagerapp_show_font_picker_group::*;
+pub use processmanagerapp_show_file_viewer_group::*;
+pub use processmanagerapp_show_file_search_group::*;
+pub use processmanagerapp_show_disk_usage_group::*;
+pub use processmanagerapp_show_duplicate_finder_group::*;
+pub use processmanagerapp_show_properties_editor_group::*;
+pub use processmanagerapp_watch_directories_group::*;
+pub use processmanagerapp_handle_filepane_keys_group::*;
+pub use processmanagerapp_show_quick_jump_group::*;
+pub use processmanagerapp_show_sftp_connect_group::*;
+pub use processmanagerapp_record_audit_group::*;
+pub use processmanagerapp_add_log_group::*;
 pub use processmanagerapp_show_settings_view_group::*;
 pub use processmanagerapp_show_filepane_view_group::*;
 pub use processmanagerapp_redo_last_action_group::*;
@@ -87,6 +168,15 @@ pub use processmanagerapp_simple_truncate_filename_group::*;
 pub use processmanagerapp_truncate_filename_group::*;
 pub use processmanagerapp_execute_command_group::*;
 pub use processmanagerapp_save_conversation_history_group::*;
+pub use processmanagerapp_show_history_browser_group::*;
+pub use processmanagerapp_arrange_windows_group::*;
+pub use processmanagerapp_launch_profile_group::*;
+pub use processmanagerapp_program_groups_group::*;
+pub use processmanagerapp_launcher_index_group::*;
+pub use processmanagerapp_process_icons_group::*;
+pub use processmanagerapp_show_services_view_group::*;
+pub use processmanagerapp_show_autostart_view_group::*;
+pub use processmanagerapp_show_disks_view_group::*;
 pub use processmanagerapp_show_kill_confirm_dialog_group::*;
 pub use processmanagerapp_show_logs_view_group::*;
 pub use processmanagerapp_add_conversation_entry_group::*;
@@ -0,0 +1,82 @@
+use crate::ws::LogFileSink;
+use crate::ws::LogLevel;
+use crate::ws::LogRecord;
+use crate::ws::APP_LOGGER;
+use crate::ws::LOG_BUFFER_LIMIT;
+// # ProcessManagerApp - add_log_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// `add_log` keeps its plain-string signature and logs at `Info`; warnings
+// and failures go through `add_log_at` with their level. The module comes
+// from the calling source file.
+
+use super::processmanagerapp_type::ProcessManagerApp;
+
+impl ProcessManagerApp {
+    #[track_caller]
+    pub fn add_log(&mut self, message: String) {
+        self.add_log_at(LogLevel::Info, message);
+    }
+    #[track_caller]
+    pub fn add_log_at(&mut self, level: LogLevel, message: String) {
+        let module = Self::log_module(std::panic::Location::caller().file());
+        self.push_log_record(LogRecord {
+            id: 0,
+            timestamp: chrono::Local::now(),
+            level,
+            module,
+            message,
+        });
+    }
+    /// `src/ws/processmanagerapp_show_filepane_view_group.rs` logs as
+    /// `show_filepane_view`.
+    fn log_module(file: &str) -> String {
+        let stem = std::path::Path::new(file)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let stem = stem.strip_prefix("processmanagerapp_").unwrap_or(&stem);
+        stem.strip_suffix("_group").unwrap_or(stem).to_string()
+    }
+    fn push_log_record(&mut self, mut record: LogRecord) {
+        record.id = self.log_view.next_id;
+        self.log_view.next_id += 1;
+        if let Some(sink) = self.log_view.file_sink.as_mut() {
+            let max_bytes = self.log_view.settings.max_file_kb * 1024;
+            match sink.write(&record, max_bytes) {
+                Ok(()) => self.log_view.file_error = None,
+                Err(e) => self.log_view.file_error = Some(e.to_string()),
+            }
+        }
+        self.logs.push_back(record);
+        while self.logs.len() > LOG_BUFFER_LIMIT {
+            if let Some(evicted) = self.logs.pop_front() {
+                self.selected_log_ids.remove(&evicted.id);
+            }
+        }
+    }
+    /// Moves records from libraries using the `log` crate into the app log.
+    pub(crate) fn drain_library_logs(&mut self) {
+        let records = match APP_LOGGER.inbox.lock() {
+            Ok(mut inbox) => std::mem::take(&mut *inbox),
+            Err(_) => return,
+        };
+        for record in records {
+            self.push_log_record(record);
+        }
+    }
+    pub fn log_file_path() -> std::path::PathBuf {
+        Self::get_config_path().with_file_name("winshell.log")
+    }
+    /// Applies `log_view.settings`: the library level and the log file.
+    pub(crate) fn apply_log_settings(&mut self) {
+        log::set_max_level(self.log_view.settings.library_level.to_filter());
+        if !self.log_view.settings.to_file {
+            self.log_view.file_sink = None;
+            self.log_view.file_error = None;
+        } else if self.log_view.file_sink.is_none() {
+            self.log_view.file_sink = Some(LogFileSink::new(Self::log_file_path()));
+        }
+    }
+}
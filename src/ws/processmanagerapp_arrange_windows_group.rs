@@ -0,0 +1,348 @@
+use crate::ws::LogLevel;
+use crate::ws::SnapPosition;
+use crate::ws::WindowArrangement;
+use crate::ws::WindowLayout;
+use crate::ws::WindowLayoutRule;
+use crate::ws::WindowRect;
+// # ProcessManagerApp - arrange_windows_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Rectangles are outer frames in screen pixels on every platform, so a
+// layout saved on one run restores the same on-screen placement. Window
+// managers treat move requests as hints; a window may end up clamped to its
+// minimum size.
+
+use super::processmanagerapp_type::ProcessManagerApp;
+
+impl ProcessManagerApp {
+    /// The usable area of the monitor the window is on, without taskbars or
+    /// panels.
+    fn work_area(&self, window_id: u64) -> Result<WindowRect, String> {
+        #[cfg(windows)]
+        let area = {
+            use windows::Win32::Foundation::HWND;
+            use windows::Win32::Graphics::Gdi::{
+                GetMonitorInfoW, MonitorFromWindow, MONITORINFO, MONITOR_DEFAULTTONEAREST,
+            };
+            let mut info = MONITORINFO {
+                cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+                ..Default::default()
+            };
+            let found = unsafe {
+                let monitor = MonitorFromWindow(
+                    HWND(window_id as isize as *mut std::ffi::c_void),
+                    MONITOR_DEFAULTTONEAREST,
+                );
+                GetMonitorInfoW(monitor, &mut info)
+            };
+            if found.as_bool() {
+                let rect = info.rcWork;
+                Ok(WindowRect {
+                    x: rect.left,
+                    y: rect.top,
+                    width: rect.right - rect.left,
+                    height: rect.bottom - rect.top,
+                })
+            } else {
+                Err(format!("no monitor found for window {}", window_id))
+            }
+        };
+        #[cfg(target_os = "linux")]
+        let area = match &self.window_system {
+            Some(system) => system.work_area(window_id),
+            None => Err("no window system connection".to_string()),
+        };
+        #[cfg(not(any(windows, target_os = "linux")))]
+        let area = Err(format!("window placement is not supported for window {}", window_id));
+        area
+    }
+    fn window_rect(&self, window_id: u64) -> Result<WindowRect, String> {
+        #[cfg(windows)]
+        let rect = {
+            use windows::Win32::Foundation::{HWND, RECT};
+            use windows::Win32::UI::WindowsAndMessaging::GetWindowRect;
+            let mut rect = RECT::default();
+            unsafe {
+                GetWindowRect(HWND(window_id as isize as *mut std::ffi::c_void), &mut rect)
+            }
+                .map(|_| WindowRect {
+                    x: rect.left,
+                    y: rect.top,
+                    width: rect.right - rect.left,
+                    height: rect.bottom - rect.top,
+                })
+                .map_err(|e| e.to_string())
+        };
+        #[cfg(target_os = "linux")]
+        let rect = match &self.window_system {
+            Some(system) => system.outer_rect(window_id),
+            None => Err("no window system connection".to_string()),
+        };
+        #[cfg(not(any(windows, target_os = "linux")))]
+        let rect = Err(format!("cannot read the position of window {}", window_id));
+        rect
+    }
+    fn move_window(&self, window_id: u64, rect: WindowRect) -> Result<(), String> {
+        #[cfg(windows)]
+        let moved = {
+            use windows::Win32::Foundation::HWND;
+            use windows::Win32::UI::WindowsAndMessaging::{
+                SetWindowPos, ShowWindow, SWP_NOACTIVATE, SWP_NOZORDER, SW_RESTORE,
+            };
+            let hwnd = HWND(window_id as isize as *mut std::ffi::c_void);
+            unsafe {
+                // Maximized windows ignore SetWindowPos until restored.
+                let _ = ShowWindow(hwnd, SW_RESTORE);
+                SetWindowPos(
+                    hwnd,
+                    HWND(std::ptr::null_mut()),
+                    rect.x,
+                    rect.y,
+                    rect.width,
+                    rect.height,
+                    SWP_NOZORDER | SWP_NOACTIVATE,
+                )
+            }
+                .map_err(|e| e.to_string())
+        };
+        #[cfg(target_os = "linux")]
+        let moved = match &self.window_system {
+            Some(system) => system.move_resize(window_id, rect),
+            None => Err("no window system connection".to_string()),
+        };
+        #[cfg(not(any(windows, target_os = "linux")))]
+        let moved = Err(format!("cannot move window {} to {:?}", window_id, rect));
+        moved
+    }
+    /// Selected windows in Windows view order.
+    fn selected_windows(&self) -> Vec<u64> {
+        self.windows
+            .iter()
+            .map(|window| window.window_id)
+            .filter(|id| self.selected_window_ids.contains(id))
+            .collect()
+    }
+    pub(crate) fn arrange_windows(&mut self, arrangement: WindowArrangement) {
+        let windows = self.selected_windows();
+        if windows.is_empty() {
+            return;
+        }
+        // Snapped windows stay on their own monitors; tiles and cascades go
+        // to the monitor of the first selected window.
+        let mut targets = Vec::new();
+        let mut failed = 0;
+        if let WindowArrangement::Snap(_) = arrangement {
+            for window_id in &windows {
+                match self.work_area(*window_id) {
+                    Ok(area) => targets.extend(arrangement.rects(area, 1).into_iter().map(|rect| (*window_id, rect))),
+                    Err(e) => {
+                        self.add_log_at(LogLevel::Warn, format!("⚠️ Could not move window {}: {}", window_id, e));
+                        failed += 1;
+                    }
+                }
+            }
+        } else {
+            let area = match self.work_area(windows[0]) {
+                Ok(area) => area,
+                Err(e) => {
+                    self.add_log_at(LogLevel::Error, format!("❌ Cannot arrange windows: {}", e));
+                    return;
+                }
+            };
+            targets = windows.iter().copied().zip(arrangement.rects(area, windows.len())).collect();
+        }
+        for (window_id, rect) in targets {
+            if let Err(e) = self.move_window(window_id, rect) {
+                self.add_log_at(LogLevel::Warn, format!("⚠️ Could not move window {}: {}", window_id, e));
+                failed += 1;
+            }
+        }
+        self.add_log(
+            format!(
+                "🪟 Arranged {} window(s) ({:?}){}",
+                windows.len() - failed,
+                arrangement,
+                if failed > 0 { format!(", {} failed", failed) } else { String::new() },
+            ),
+        );
+    }
+    /// Saves where the selected windows (or all windows when none are
+    /// selected) currently are, matching each by its exact title.
+    fn save_window_layout(&mut self) {
+        let name = self.window_layout_name.trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+        let mut windows = self.selected_windows();
+        if windows.is_empty() {
+            windows = self.windows.iter().map(|window| window.window_id).collect();
+        }
+        let mut rules = Vec::new();
+        for window_id in windows {
+            let Some(window) = self.windows.iter().find(|w| w.window_id == window_id) else {
+                continue;
+            };
+            match self.window_rect(window_id) {
+                Ok(rect) => {
+                    rules
+                        .push(WindowLayoutRule {
+                            title_pattern: format!("^{}$", regex::escape(&window.window_title)),
+                            rect,
+                        })
+                }
+                Err(e) => {
+                    let message = format!("⚠️ Skipping '{}': {}", window.window_title, e);
+                    self.add_log_at(LogLevel::Warn, message);
+                }
+            }
+        }
+        if rules.is_empty() {
+            self.add_log_at(LogLevel::Error, format!("❌ Layout '{}' not saved: no window positions", name));
+            return;
+        }
+        self.add_log(format!("💾 Saved layout '{}' with {} window(s)", name, rules.len()));
+        let layout = WindowLayout { name, rules };
+        match self.window_layouts.iter_mut().find(|existing| existing.name == layout.name) {
+            Some(existing) => *existing = layout,
+            None => self.window_layouts.push(layout),
+        }
+        self.window_layout_name.clear();
+        self.save_config();
+    }
+    /// Moves every window to the first rule whose pattern matches its title.
+    fn apply_window_layout(&mut self, index: usize) {
+        let Some(layout) = self.window_layouts.get(index).cloned() else {
+            return;
+        };
+        let mut patterns = Vec::new();
+        for rule in &layout.rules {
+            match regex::Regex::new(&rule.title_pattern) {
+                Ok(pattern) => patterns.push((pattern, rule.rect)),
+                Err(e) => {
+                    self.add_log_at(LogLevel::Warn, 
+                        format!("⚠️ Layout '{}': bad pattern {}: {}", layout.name, rule.title_pattern, e),
+                    )
+                }
+            }
+        }
+        let moves: Vec<(u64, WindowRect)> = self
+            .windows
+            .iter()
+            .filter_map(|window| {
+                patterns
+                    .iter()
+                    .find(|(pattern, _)| pattern.is_match(&window.window_title))
+                    .map(|(_, rect)| (window.window_id, *rect))
+            })
+            .collect();
+        let mut moved = 0;
+        for (window_id, rect) in moves {
+            match self.move_window(window_id, rect) {
+                Ok(()) => moved += 1,
+                Err(e) => self.add_log_at(LogLevel::Warn, format!("⚠️ Could not move window {}: {}", window_id, e)),
+            }
+        }
+        self.add_log(format!("🪟 Applied layout '{}' to {} window(s)", layout.name, moved));
+    }
+    /// Toolbar menu with tiling, snapping and saved layouts for the
+    /// Windows view.
+    pub(crate) fn show_window_layout_menu(&mut self, ui: &mut egui::Ui) {
+        let selected = self.selected_windows().len();
+        let mut arrangement = None;
+        let mut apply = None;
+        let mut delete = None;
+        let mut save = false;
+        ui.menu_button(
+            format!("🪟 Layout ({} selected)", selected),
+            |ui| {
+                ui.horizontal(|ui| {
+                    if ui.button("☑ Select All").clicked() {
+                        self.selected_window_ids = self.windows.iter().map(|w| w.window_id).collect();
+                    }
+                    if ui.button("❌ Clear").clicked() {
+                        self.selected_window_ids.clear();
+                    }
+                });
+                ui.separator();
+                ui.add_enabled_ui(
+                    selected > 0,
+                    |ui| {
+                        for (label, choice) in [
+                            ("☰ Tile horizontally", WindowArrangement::TileHorizontally),
+                            ("▥ Tile vertically", WindowArrangement::TileVertically),
+                            ("🗗 Cascade", WindowArrangement::Cascade),
+                        ] {
+                            if ui.button(label).clicked() {
+                                arrangement = Some(choice);
+                                ui.close_menu();
+                            }
+                        }
+                        ui.menu_button(
+                            "📐 Snap",
+                            |ui| {
+                                for position in SnapPosition::ALL {
+                                    if ui.button(position.name()).clicked() {
+                                        arrangement = Some(WindowArrangement::Snap(position));
+                                        ui.close_menu();
+                                    }
+                                }
+                            },
+                        );
+                    },
+                );
+                if selected == 0 {
+                    ui.weak("Tick windows in the list to arrange them");
+                }
+                ui.separator();
+                ui.label("Saved layouts:");
+                if self.window_layouts.is_empty() {
+                    ui.weak("None yet");
+                }
+                for (index, layout) in self.window_layouts.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        if ui
+                            .button(format!("▶ {}", layout.name))
+                            .on_hover_text(format!("{} window rule(s)", layout.rules.len()))
+                            .clicked()
+                        {
+                            apply = Some(index);
+                            ui.close_menu();
+                        }
+                        if ui.small_button("✖").on_hover_text("Delete layout").clicked() {
+                            delete = Some(index);
+                        }
+                    });
+                }
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.window_layout_name)
+                            .hint_text("layout name")
+                            .desired_width(120.0),
+                    );
+                    if ui
+                        .button("💾 Save")
+                        .on_hover_text("Save positions of the selected windows, or of all windows")
+                        .clicked()
+                    {
+                        save = true;
+                    }
+                });
+            },
+        );
+        if let Some(arrangement) = arrangement {
+            self.arrange_windows(arrangement);
+        }
+        if let Some(index) = apply {
+            self.apply_window_layout(index);
+        }
+        if let Some(index) = delete {
+            let layout = self.window_layouts.remove(index);
+            self.add_log(format!("🗑 Deleted layout '{}'", layout.name));
+            self.save_config();
+        }
+        if save {
+            self.save_window_layout();
+        }
+    }
+}
@@ -5,6 +5,8 @@
 //! 🤖 Generated with [SplitRS](https://github.com/cool-japan/splitrs)
 
 use std::collections::{HashMap, HashSet};
+use crate::ws::LogLevel;
+use crate::ws::WindowAction;
 use super::processmanagerapp_type::ProcessManagerApp;
 
 impl ProcessManagerApp {
@@ -27,7 +29,64 @@ impl ProcessManagerApp {
         }
         #[cfg(target_os = "linux")]
         {
-            println!("Bringing window {} to foreground", window_id);
+            let result = match &self.window_system {
+                Some(system) => system.activate(window_id),
+                None => Err("no window system connection".to_string()),
+            };
+            if let Err(e) = result {
+                self.add_log_at(LogLevel::Error, format!("❌ Could not activate window {}: {}", window_id, e));
+            }
+        }
+    }
+    pub fn perform_window_action(&mut self, window_id: u64, action: WindowAction) {
+        #[cfg(windows)]
+        {
+            use windows::Win32::Foundation::{HWND, LPARAM, WPARAM};
+            use windows::Win32::UI::WindowsAndMessaging::{
+                IsZoomed, PostMessageW, ShowWindow, SW_MAXIMIZE, SW_MINIMIZE, SW_RESTORE,
+                WM_CLOSE,
+            };
+            unsafe {
+                let hwnd = HWND(window_id as isize as *mut std::ffi::c_void);
+                match action {
+                    WindowAction::Minimize => {
+                        let _ = ShowWindow(hwnd, SW_MINIMIZE);
+                    }
+                    WindowAction::ToggleMaximize => {
+                        let command = if IsZoomed(hwnd).as_bool() { SW_RESTORE } else { SW_MAXIMIZE };
+                        let _ = ShowWindow(hwnd, command);
+                    }
+                    WindowAction::Close => {
+                        let _ = PostMessageW(hwnd, WM_CLOSE, WPARAM(0), LPARAM(0));
+                    }
+                }
+            }
+        }
+        #[cfg(target_os = "linux")]
+        {
+            let result = match &self.window_system {
+                Some(system) => system.perform(window_id, action),
+                None => Err("no window system connection".to_string()),
+            };
+            if let Err(e) = result {
+                self.add_log_at(LogLevel::Error, format!("❌ {} failed for window {}: {}", action.name(), window_id, e));
+                return;
+            }
+        }
+        if action == WindowAction::Close {
+            self.add_log(format!("✖ Asked window {} to close", window_id));
+        }
+    }
+    /// Minimize, maximize and close buttons for one window row.
+    pub(crate) fn show_window_action_buttons(
+        ui: &mut egui::Ui,
+        window_id: u64,
+        requested: &mut Option<(u64, WindowAction)>,
+    ) {
+        for action in [WindowAction::Minimize, WindowAction::ToggleMaximize, WindowAction::Close] {
+            if ui.small_button(action.icon()).on_hover_text(action.name()).clicked() {
+                *requested = Some((window_id, action));
+            }
         }
     }
 }
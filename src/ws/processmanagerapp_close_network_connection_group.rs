@@ -29,6 +29,7 @@ impl ProcessManagerApp {
         }
         #[cfg(windows)]
         {
+            use crate::ws::LogLevel;
             use windows::Win32::NetworkManagement::IpHelper::{
                 SetTcpEntry, MIB_TCPROW_LH, MIB_TCP_STATE_DELETE_TCB,
             };
@@ -103,7 +104,7 @@ impl ProcessManagerApp {
                         self.refresh_network_connections();
                         return true;
                     } else {
-                        self.add_log(
+                        self.add_log_at(LogLevel::Error, 
                             format!(
                                 "❌ Failed to close connection (error {}): {} -> {}. May need admin rights.",
                                 result, conn.local_addr, conn.remote_addr
@@ -11,7 +11,7 @@ use super::processmanagerapp_type::ProcessManagerApp;
 impl ProcessManagerApp {
     pub fn execute_command(&mut self, command: &FilepaneCommand) {
         match command {
-            FilepaneCommand::CopyFile { source, destination } => {
+            FilepaneCommand::CopyFile { source, destination, .. } => {
                 self.add_log(format!("Copying {} to {}", source, destination));
             }
             FilepaneCommand::MoveFile { source, destination } => {
@@ -34,6 +34,15 @@ impl ProcessManagerApp {
                     format!("Calculating {} checksum for {}", algorithm.name(), path),
                 );
             }
+            FilepaneCommand::CreateHardlink { target, link_path, .. } => {
+                self.add_log(format!("Hardlinking {} to {}", link_path, target));
+            }
+            FilepaneCommand::CreateSymlink { target, link_path } => {
+                self.add_log(format!("Symlinking {} to {}", link_path, target));
+            }
+            FilepaneCommand::Batch { commands } => {
+                self.add_log(format!("Running batch of {} operations", commands.len()));
+            }
         }
         if self.filepane_active_tab < self.filepane_tabs.len() {
             let tab = &mut self.filepane_tabs[self.filepane_active_tab];
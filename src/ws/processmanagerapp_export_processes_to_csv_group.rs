@@ -15,13 +15,14 @@ impl ProcessManagerApp {
             "processes_{}.csv", chrono::Local::now().format("%Y%m%d_%H%M%S")
         );
         let mut file = File::create(&filename).map_err(|e| e.to_string())?;
-        writeln!(file, "PID,Name,Memory (bytes),CPU %,Parent PID,Status,Runtime (s)")
+        writeln!(file, "PID,Name,Memory (bytes),CPU %,Parent PID,Status,Runtime (s),Read (bytes/s),Write (bytes/s)")
             .map_err(|e| e.to_string())?;
         for process in &self.processes {
             writeln!(
-                file, "{},{},{},{:.2},{},{},{}", process.pid, process.name, process
+                file, "{},{},{},{:.2},{},{},{},{},{}", process.pid, process.name, process
                 .memory, process.cpu_usage, process.parent_pid.map(| p | p.to_string())
-                .unwrap_or_else(|| "-".to_string()), process.status, process.run_time
+                .unwrap_or_else(|| "-".to_string()), process.status, process.run_time,
+                process.disk_read_rate, process.disk_write_rate
             )
                 .map_err(|e| e.to_string())?;
         }
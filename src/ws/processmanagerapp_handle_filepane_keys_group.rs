@@ -0,0 +1,391 @@
+use crate::ws::FilepaneCommand;
+use crate::ws::FilepaneKeyAction;
+use crate::ws::ViewMode;
+use crate::ws::{join_panel_path, parent_directory};
+// # ProcessManagerApp - handle_filepane_keys_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Bound keys are taken out of the raw input before egui sees it, so Tab and
+// the arrow keys drive the panels instead of egui's focus navigation.
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+use super::processmanagerapp_type::ProcessManagerApp;
+
+/// Type-to-jump keeps extending the search while keys arrive this quickly.
+const JUMP_TIMEOUT: Duration = Duration::from_millis(1000);
+
+impl ProcessManagerApp {
+    /// Called from `raw_input_hook`: queues Filepane key actions and typed
+    /// text while no text field has keyboard focus.
+    pub(crate) fn capture_filepane_keys(
+        &mut self,
+        ctx: &egui::Context,
+        raw_input: &mut egui::RawInput,
+    ) {
+        if self.view_mode != ViewMode::Filepane || ctx.wants_keyboard_input()
+            || self.filepane_show_confirm || self.filepane_keyboard.mkdir_name.is_some()
+            || self.quick_jump.is_open
+        {
+            return;
+        }
+        let keymap = &self.filepane_keymap;
+        let keyboard = &mut self.filepane_keyboard;
+        raw_input
+            .events
+            .retain(|event| match event {
+                egui::Event::Key { key, pressed, modifiers, .. } => {
+                    match keymap.action_for(*key, *modifiers) {
+                        Some(action) => {
+                            if *pressed {
+                                keyboard.pending_actions.push(action);
+                            }
+                            false
+                        }
+                        None => true,
+                    }
+                }
+                egui::Event::Text(text) => {
+                    if !text.trim().is_empty() {
+                        keyboard.pending_text.push_str(text);
+                    }
+                    false
+                }
+                _ => true,
+            });
+    }
+    pub(crate) fn handle_filepane_keys(&mut self) {
+        let actions = std::mem::take(&mut self.filepane_keyboard.pending_actions);
+        for action in actions {
+            self.run_filepane_key_action(action);
+        }
+        let text = std::mem::take(&mut self.filepane_keyboard.pending_text);
+        if !text.is_empty() {
+            self.jump_to_typed_name(&text);
+        }
+    }
+    fn run_filepane_key_action(&mut self, action: FilepaneKeyAction) {
+        let panel = self.filepane_keyboard.active_panel;
+        let page = self.filepane_keyboard.page_rows.max(1) as isize;
+        match action {
+            FilepaneKeyAction::MoveUp => self.move_filepane_cursor(panel, -1),
+            FilepaneKeyAction::MoveDown => self.move_filepane_cursor(panel, 1),
+            FilepaneKeyAction::PageUp => self.move_filepane_cursor(panel, -page),
+            FilepaneKeyAction::PageDown => self.move_filepane_cursor(panel, page),
+            FilepaneKeyAction::Home => self.move_filepane_cursor(panel, isize::MIN),
+            FilepaneKeyAction::End => self.move_filepane_cursor(panel, isize::MAX),
+            FilepaneKeyAction::Open => {
+                let Some(entry) = self.filepane_cursor_entry(panel) else {
+                    return;
+                };
+                if entry.is_directory {
+                    let previous = Path::new(&self.filepane_panel_path(panel))
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string());
+                    let is_parent = self.is_filepane_parent_row(panel, &entry.path);
+                    self.navigate_filepane_panel(
+                        panel,
+                        entry.path,
+                        if is_parent { previous } else { None },
+                    );
+                } else {
+                    self.open_file_with_system(&entry.path);
+                }
+            }
+            FilepaneKeyAction::Parent => {
+                let current = self.filepane_panel_path(panel);
+                if let Some(parent) = parent_directory(&current) {
+                    let previous = Path::new(&current)
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string());
+                    self.navigate_filepane_panel(panel, parent, previous);
+                }
+            }
+            FilepaneKeyAction::SwitchPanel => {
+                self.filepane_keyboard.active_panel = 1 - panel;
+                self.filepane_keyboard.scroll_to_cursor = true;
+            }
+            FilepaneKeyAction::ToggleMark => {
+                if let Some(entry) = self.filepane_cursor_entry(panel) {
+                    if !self.is_filepane_parent_row(panel, &entry.path) {
+                        let tab = &mut self.filepane_tabs[self.filepane_active_tab];
+                        tab.show_checkboxes = true;
+                        let marks = if panel == 0 {
+                            &mut tab.left_checkboxes
+                        } else {
+                            &mut tab.right_checkboxes
+                        };
+                        if !marks.remove(&entry.name) {
+                            marks.insert(entry.name);
+                        }
+                    }
+                }
+                self.move_filepane_cursor(panel, 1);
+            }
+            FilepaneKeyAction::View => {
+                if let Some(entry) = self.filepane_cursor_entry(panel) {
+                    if !entry.is_directory {
+                        self.open_file_viewer(&entry.path);
+                    }
+                }
+            }
+            FilepaneKeyAction::Copy | FilepaneKeyAction::Move | FilepaneKeyAction::Delete => {
+                self.request_filepane_keyboard_operation(panel, action);
+            }
+            FilepaneKeyAction::MakeDirectory => {
+                self.filepane_keyboard.mkdir_name = Some(String::new());
+            }
+            FilepaneKeyAction::Refresh => {
+                for side in 0..2 {
+                    let path = self.filepane_panel_path(side);
+                    self.invalidate_directory_listing(&path);
+                }
+                self.add_log("🔄 Refreshed both panels".to_string());
+            }
+            FilepaneKeyAction::ShowHelp => {
+                self.filepane_keyboard.show_help = !self.filepane_keyboard.show_help;
+            }
+            FilepaneKeyAction::Back => self.filepane_go_back(panel),
+            FilepaneKeyAction::Forward => self.filepane_go_forward(panel),
+            FilepaneKeyAction::QuickJump => self.open_quick_jump(),
+            FilepaneKeyAction::ToggleBookmark => {
+                let path = self.filepane_panel_path(panel);
+                self.toggle_filepane_bookmark(&path);
+            }
+        }
+    }
+    pub(crate) fn filepane_panel_path(&self, panel: usize) -> String {
+        let tab = &self.filepane_tabs[self.filepane_active_tab];
+        if panel == 0 { tab.left_path.clone() } else { tab.right_path.clone() }
+    }
+    /// The ".." row is the parent directory's own entry at the top of a panel.
+    fn is_filepane_parent_row(&self, panel: usize, path: &str) -> bool {
+        parent_directory(&self.filepane_panel_path(panel)).as_deref() == Some(path)
+    }
+    fn filepane_cursor_entry(&self, panel: usize) -> Option<crate::ws::FileInfo> {
+        let cursor = self.filepane_keyboard.cursor[panel].as_ref()?;
+        self.filepane_keyboard.visible[panel]
+            .iter()
+            .find(|info| &info.name == cursor)
+            .cloned()
+    }
+    /// Moves the cursor by `delta` rows, clamped to the listing; the cursor
+    /// entry doubles as the panel selection.
+    fn move_filepane_cursor(&mut self, panel: usize, delta: isize) {
+        let visible = &self.filepane_keyboard.visible[panel];
+        if visible.is_empty() {
+            return;
+        }
+        let current = self.filepane_keyboard.cursor[panel]
+            .as_ref()
+            .and_then(|name| visible.iter().position(|info| &info.name == name));
+        let target = match current {
+            Some(index) => (index as isize).saturating_add(delta),
+            None if delta < 0 => visible.len() as isize - 1,
+            None => 0,
+        }
+            .clamp(0, visible.len() as isize - 1) as usize;
+        let name = visible[target].name.clone();
+        self.set_filepane_cursor(panel, name);
+    }
+    pub(crate) fn set_filepane_cursor(&mut self, panel: usize, name: String) {
+        let tab = &mut self.filepane_tabs[self.filepane_active_tab];
+        let selected = if panel == 0 { &mut tab.selected_left } else { &mut tab.selected_right };
+        selected.clear();
+        selected.push(name.clone());
+        self.filepane_keyboard.cursor[panel] = Some(name);
+        self.filepane_keyboard.active_panel = panel;
+        self.filepane_keyboard.scroll_to_cursor = true;
+    }
+    pub(crate) fn navigate_filepane_panel(&mut self, panel: usize, path: String, focus: Option<String>) {
+        let tab = &mut self.filepane_tabs[self.filepane_active_tab];
+        if panel == 0 {
+            tab.left_path = path;
+            tab.selected_left.clear();
+            tab.left_checkboxes.clear();
+        } else {
+            tab.right_path = path;
+            tab.selected_right.clear();
+            tab.right_checkboxes.clear();
+        }
+        self.filepane_keyboard.visible[panel].clear();
+        self.filepane_keyboard.cursor[panel] = None;
+        if let Some(name) = focus {
+            self.set_filepane_cursor(panel, name);
+        }
+    }
+    fn jump_to_typed_name(&mut self, text: &str) {
+        let keyboard = &mut self.filepane_keyboard;
+        if keyboard.last_jump.is_none_or(|last| last.elapsed() > JUMP_TIMEOUT) {
+            keyboard.jump_buffer.clear();
+        }
+        keyboard.jump_buffer.push_str(&text.to_lowercase());
+        keyboard.last_jump = Some(Instant::now());
+        let panel = keyboard.active_panel;
+        let found = keyboard.visible[panel]
+            .iter()
+            .find(|info| info.name.to_lowercase().starts_with(&keyboard.jump_buffer))
+            .map(|info| info.name.clone());
+        if let Some(name) = found {
+            self.set_filepane_cursor(panel, name);
+        }
+    }
+    /// Copy, move or delete the checked entries of `panel`, or the cursor
+    /// entry when nothing is checked, through the usual confirmation dialog.
+    fn request_filepane_keyboard_operation(&mut self, panel: usize, action: FilepaneKeyAction) {
+        let tab = &self.filepane_tabs[self.filepane_active_tab];
+        let panel_path = self.filepane_panel_path(panel);
+        let destination = self.filepane_panel_path(1 - panel);
+        let marks = if panel == 0 { &tab.left_checkboxes } else { &tab.right_checkboxes };
+        let mut names: Vec<String> = marks.iter().cloned().collect();
+        names.sort();
+        if names.is_empty() {
+            match self.filepane_cursor_entry(panel) {
+                Some(entry) if !self.is_filepane_parent_row(panel, &entry.path) => {
+                    names.push(entry.name)
+                }
+                _ => {
+                    self.add_log("No files selected".to_string());
+                    return;
+                }
+            }
+        }
+        let mut commands: Vec<FilepaneCommand> = names
+            .iter()
+            .map(|name| {
+                let source = join_panel_path(&panel_path, name);
+                match action {
+                    FilepaneKeyAction::Copy => FilepaneCommand::CopyFile {
+                        source,
+                        destination: destination.clone(),
+                        speed_limit: None,
+                    },
+                    FilepaneKeyAction::Move => FilepaneCommand::MoveFile {
+                        source,
+                        destination: destination.clone(),
+                    },
+                    _ => FilepaneCommand::DeleteFile { path: source },
+                }
+            })
+            .collect();
+        let file_list = names.join("\n");
+        let message = match action {
+            FilepaneKeyAction::Copy => {
+                format!(
+                    "Are you sure you want to copy {} item(s):\n\n{}\n\nto:\n\n{}", names
+                    .len(), file_list, destination
+                )
+            }
+            FilepaneKeyAction::Move => {
+                format!(
+                    "⚠️ Are you sure you want to MOVE {} item(s):\n\n{}\n\nto:\n\n{}", names
+                    .len(), file_list, destination
+                )
+            }
+            _ => {
+                format!(
+                    "⚠️ DANGER: Are you sure you want to delete {} item(s)?\n\n{}\n\n{}",
+                    names.len(), file_list, Self::delete_consequence(std::slice::from_ref(&panel_path))
+                )
+            }
+        };
+        let command = if commands.len() == 1 {
+            commands.remove(0)
+        } else {
+            FilepaneCommand::Batch { commands }
+        };
+        self.request_file_operation_confirmation(&command, message);
+    }
+    pub(crate) fn show_filepane_keyboard_windows(&mut self, ctx: &egui::Context) {
+        if self.filepane_keyboard.show_help {
+            let mut open = true;
+            egui::Window::new("⌨️ Filepane Keys")
+                .open(&mut open)
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(
+                    ctx,
+                    |ui| {
+                        egui::Grid::new("filepane_keymap_help")
+                            .num_columns(2)
+                            .striped(true)
+                            .spacing([24.0, 4.0])
+                            .show(
+                                ui,
+                                |ui| {
+                                    for action in FilepaneKeyAction::ALL {
+                                        let keys: Vec<String> = self
+                                            .filepane_keymap
+                                            .bindings_for(action)
+                                            .iter()
+                                            .map(|b| b.label())
+                                            .collect();
+                                        ui.label(egui::RichText::new(keys.join(", ")).monospace());
+                                        ui.label(action.description());
+                                        ui.end_row();
+                                    }
+                                },
+                            );
+                        ui.separator();
+                        ui.label("Type letters to jump to a matching entry.");
+                        ui.label(
+                            egui::RichText::new(
+                                    format!(
+                                        "Rebind keys in the [Keymap] section of {}",
+                                        Self::get_config_path().display()
+                                    ),
+                                )
+                                .weak(),
+                        );
+                    },
+                );
+            self.filepane_keyboard.show_help = open;
+        }
+        let Some(mut name) = self.filepane_keyboard.mkdir_name.take() else {
+            return;
+        };
+        let panel = self.filepane_keyboard.active_panel;
+        let parent = self.filepane_panel_path(panel);
+        let mut open = true;
+        let mut create = false;
+        let mut cancel = false;
+        egui::Window::new("📁 New Directory")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(
+                ctx,
+                |ui| {
+                    ui.label(format!("Create in {}", parent));
+                    let response = ui.text_edit_singleline(&mut name);
+                    response.request_focus();
+                    if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        create = true;
+                    }
+                    ui.horizontal(|ui| {
+                        if ui.button("✅ Create").clicked() {
+                            create = true;
+                        }
+                        if ui.button("❌ Cancel").clicked() {
+                            cancel = true;
+                        }
+                    });
+                },
+            );
+        if cancel || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            open = false;
+        }
+        let trimmed = name.trim();
+        if create && !trimmed.is_empty() {
+            let path = join_panel_path(&parent, trimmed);
+            self.execute_real_command(&FilepaneCommand::CreateDirectory { path });
+            self.invalidate_directory_listing(&parent);
+            self.set_filepane_cursor(panel, trimmed.to_string());
+        } else if open {
+            self.filepane_keyboard.mkdir_name = Some(name);
+        }
+    }
+}
@@ -0,0 +1,429 @@
+use crate::ws::LogLevel;
+use crate::ws::ConsoleLine;
+use crate::ws::ConsoleStream;
+use crate::ws::CustomProgram;
+use crate::ws::ManagedProcess;
+// # ProcessManagerApp - launch_profile_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Every program started from the New view is kept as a `ManagedProcess` so
+// its exit code can be reported. Captured output is read on one thread per
+// pipe and drained once per frame; the UI never blocks on the child.
+
+use std::io::{BufRead, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::{Duration, Instant};
+use super::processmanagerapp_type::ProcessManagerApp;
+
+/// A run this long counts as healthy and resets the restart backoff.
+const HEALTHY_RUN: Duration = Duration::from_secs(60);
+const MAX_BACKOFF_SHIFT: u32 = 6;
+
+type SpawnedProfile = (Child, Option<ChildStdin>, Option<Receiver<ConsoleLine>>);
+
+fn forward_lines(reader: impl std::io::Read, stream: ConsoleStream, sender: Sender<ConsoleLine>) {
+    let mut reader = std::io::BufReader::new(reader);
+    let mut buffer = Vec::new();
+    loop {
+        buffer.clear();
+        match reader.read_until(b'\n', &mut buffer) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {
+                let text = String::from_utf8_lossy(&buffer)
+                    .trim_end_matches(['\r', '\n'])
+                    .to_string();
+                if sender.send(ConsoleLine { stream, text }).is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+fn spawn_profile(profile: &CustomProgram) -> Result<SpawnedProfile, String> {
+    let mut cmd = Command::new(&profile.path);
+    #[cfg(windows)]
+    if !profile.args.trim().is_empty() {
+        use std::os::windows::process::CommandExt;
+        // Windows programs parse their own command line; pass it untouched.
+        cmd.raw_arg(&profile.args);
+    }
+    #[cfg(not(windows))]
+    cmd.args(shlex::split(&profile.args).ok_or("the arguments have an unbalanced quote")?);
+    if !profile.working_dir.trim().is_empty() {
+        cmd.current_dir(profile.working_dir.trim());
+    } else if let Some(parent) = std::path::Path::new(&profile.path).parent() {
+        if parent.exists() {
+            cmd.current_dir(parent);
+        }
+    }
+    cmd.envs(profile.env.iter().map(|(key, value)| (key, value)));
+    if profile.capture_output {
+        cmd.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped());
+        #[cfg(windows)]
+        {
+            use std::os::windows::process::CommandExt;
+            // CREATE_NO_WINDOW: the console pane replaces the console window.
+            cmd.creation_flags(0x0800_0000);
+        }
+    }
+    let mut child = cmd.spawn().map_err(|e| e.to_string())?;
+    if !profile.capture_output {
+        return Ok((child, None, None));
+    }
+    let (sender, receiver) = mpsc::channel();
+    if let Some(stdout) = child.stdout.take() {
+        let sender = sender.clone();
+        std::thread::spawn(move || forward_lines(stdout, ConsoleStream::Stdout, sender));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        std::thread::spawn(move || forward_lines(stderr, ConsoleStream::Stderr, sender));
+    }
+    let stdin = child.stdin.take();
+    Ok((child, stdin, Some(receiver)))
+}
+
+impl ProcessManagerApp {
+    /// Starts `profile` and keeps watching it. Elevated launches go through
+    /// the shell and cannot be watched, so they return PID 0.
+    pub(crate) fn launch_profile(&mut self, profile: CustomProgram) -> Result<u32, String> {
+        let mut launch_info = if profile.admin {
+            format!("Launching with elevation: {}", profile.path)
+        } else {
+            format!("Launching: {}", profile.path)
+        };
+        if !profile.args.is_empty() {
+            launch_info = format!("{} {}", launch_info, profile.args);
+        }
+        self.add_log(launch_info);
+        #[cfg(windows)]
+        if profile.admin {
+            return self.launch_elevated(&profile.path, &profile.args);
+        }
+        match spawn_profile(&profile) {
+            Ok((child, stdin, output)) => {
+                let pid = child.id();
+                let id = self.next_managed_id;
+                self.next_managed_id += 1;
+                let mut managed = ManagedProcess {
+                    id,
+                    profile,
+                    pid,
+                    child: Some(child),
+                    stdin,
+                    output,
+                    lines: std::collections::VecDeque::new(),
+                    started_at: Instant::now(),
+                    exit_code: None,
+                    restarts: 0,
+                    restart_at: None,
+                    stopped: false,
+                    input: String::new(),
+                };
+                managed.push_line(ConsoleStream::System, format!("Started with PID {}", pid));
+                if managed.profile.capture_output {
+                    self.selected_console = Some(id);
+                }
+                self.managed_processes.push(managed);
+                self.add_log(format!("✓ Successfully launched program with PID: {}", pid));
+                Ok(pid)
+            }
+            Err(e) => {
+                let error_msg = format!("✗ Failed to launch: {}", e);
+                self.add_log_at(LogLevel::Error, error_msg.clone());
+                Err(error_msg)
+            }
+        }
+    }
+    /// The profile described by the New view's launch form.
+    pub(crate) fn launch_form_profile(&self) -> CustomProgram {
+        let name = if self.program_profile_name.trim().is_empty() {
+            std::path::Path::new(&self.program_path)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_else(|| self.program_path.clone())
+        } else {
+            self.program_profile_name.trim().to_string()
+        };
+        CustomProgram {
+            name,
+            path: self.program_path.clone(),
+            args: self.program_args.clone(),
+            admin: self.run_as_admin,
+            working_dir: self.program_working_dir.trim().to_string(),
+            env: CustomProgram::parse_env(&self.program_env),
+            capture_output: self.program_capture,
+            restart_on_crash: self.program_restart,
+        }
+    }
+    pub(crate) fn edit_launch_profile(&mut self, profile: &CustomProgram) {
+        self.program_profile_name = profile.name.clone();
+        self.program_path = profile.path.clone();
+        self.program_args = profile.args.clone();
+        self.run_as_admin = profile.admin;
+        self.program_working_dir = profile.working_dir.clone();
+        self.program_env = profile.env_text();
+        self.program_capture = profile.capture_output;
+        self.program_restart = profile.restart_on_crash;
+    }
+    pub(crate) fn clear_launch_form(&mut self) {
+        self.edit_launch_profile(&CustomProgram {
+            capture_output: true,
+            ..CustomProgram::default()
+        });
+    }
+    pub(crate) fn save_launch_profile(&mut self) {
+        let profile = self.launch_form_profile();
+        let name = profile.name.clone();
+        match self.custom_programs.iter_mut().find(|p| p.name == profile.name) {
+            Some(existing) => *existing = profile,
+            None => self.custom_programs.push(profile),
+        }
+        self.save_config();
+        self.add_log(format!("💾 Saved launch profile '{}'", name));
+    }
+    /// Drains captured output, reports exits and restarts crashed programs
+    /// whose backoff has elapsed. Called once per frame.
+    pub(crate) fn poll_managed_processes(&mut self, ctx: &egui::Context) {
+        let mut messages = Vec::new();
+        let mut busy = false;
+        for managed in &mut self.managed_processes {
+            let lines: Vec<ConsoleLine> = managed
+                .output
+                .as_ref()
+                .map(|output| output.try_iter().collect())
+                .unwrap_or_default();
+            for line in lines {
+                managed.push_line(line.stream, line.text);
+            }
+            if managed.is_running() {
+                busy |= managed.output.is_some();
+                let status = managed.child.as_mut().and_then(|child| child.try_wait().ok()).flatten();
+                if let Some(status) = status {
+                    let code = status.code();
+                    managed.exit_code = Some(code);
+                    managed.stdin = None;
+                    let description = match code {
+                        Some(code) => format!("exit code {}", code),
+                        None => "terminated by a signal".to_string(),
+                    };
+                    managed.push_line(ConsoleStream::System, format!("Exited: {}", description));
+                    let (level, icon) = if status.success() { (LogLevel::Info, "✓") } else { (LogLevel::Warn, "⚠️") };
+                    messages.push((
+                        level,
+                        format!("{} {} (PID {}) exited: {}", icon, managed.profile.name, managed.pid, description),
+                    ));
+                    if managed.profile.restart_on_crash && !managed.stopped && !status.success() {
+                        if managed.started_at.elapsed() >= HEALTHY_RUN {
+                            managed.restarts = 0;
+                        }
+                        let delay = Duration::from_secs(1 << managed.restarts.min(MAX_BACKOFF_SHIFT));
+                        managed.restart_at = Some(Instant::now() + delay);
+                        managed.push_line(
+                            ConsoleStream::System,
+                            format!("Restarting in {}s", delay.as_secs()),
+                        );
+                    }
+                }
+            }
+            let Some(restart_at) = managed.restart_at else {
+                continue;
+            };
+            busy = true;
+            if Instant::now() < restart_at {
+                continue;
+            }
+            managed.restart_at = None;
+            managed.restarts += 1;
+            match spawn_profile(&managed.profile) {
+                Ok((child, stdin, output)) => {
+                    managed.pid = child.id();
+                    managed.child = Some(child);
+                    managed.stdin = stdin;
+                    managed.output = output;
+                    managed.started_at = Instant::now();
+                    managed.exit_code = None;
+                    let message = format!("Restarted with PID {} (restart {})", managed.pid, managed.restarts);
+                    managed.push_line(ConsoleStream::System, message.clone());
+                    messages.push((LogLevel::Info, format!("🔁 {}: {}", managed.profile.name, message)));
+                }
+                Err(e) => {
+                    let delay = Duration::from_secs(1 << managed.restarts.min(MAX_BACKOFF_SHIFT));
+                    managed.restart_at = Some(Instant::now() + delay);
+                    let message = format!("Restart failed: {}; retrying in {}s", e, delay.as_secs());
+                    managed.push_line(ConsoleStream::System, message.clone());
+                    messages.push((LogLevel::Error, format!("❌ {}: {}", managed.profile.name, message)));
+                }
+            }
+        }
+        for (level, message) in messages {
+            self.add_log_at(level, message);
+        }
+        if busy {
+            ctx.request_repaint_after(Duration::from_millis(250));
+        }
+    }
+    /// Kills the process and cancels any pending restart.
+    pub(crate) fn stop_managed_process(&mut self, id: u64) {
+        let Some(managed) = self.managed_processes.iter_mut().find(|m| m.id == id) else {
+            return;
+        };
+        managed.stopped = true;
+        managed.restart_at = None;
+        let pid = managed.pid;
+        let name = managed.profile.name.clone();
+        let result = match managed.child.as_mut() {
+            Some(child) if managed.exit_code.is_none() => child.kill().map_err(|e| e.to_string()),
+            _ => Ok(()),
+        };
+        match result {
+            Ok(()) => self.add_log(format!("⏹ Stopped {} (PID {})", name, pid)),
+            Err(e) => self.add_log_at(LogLevel::Error, format!("❌ Could not stop {} (PID {}): {}", name, pid, e)),
+        }
+    }
+    fn send_console_input(&mut self, id: u64) {
+        let Some(managed) = self.managed_processes.iter_mut().find(|m| m.id == id) else {
+            return;
+        };
+        let text = std::mem::take(&mut managed.input);
+        let written = match managed.stdin.as_mut() {
+            Some(stdin) => writeln!(stdin, "{}", text).and_then(|_| stdin.flush()),
+            None => return,
+        };
+        match written {
+            Ok(()) => managed.push_line(ConsoleStream::Stdin, text),
+            Err(e) => {
+                managed.stdin = None;
+                managed.push_line(ConsoleStream::System, format!("stdin closed: {}", e));
+            }
+        }
+    }
+    /// Lists watched processes and shows the console of the selected one.
+    pub(crate) fn show_launch_consoles(&mut self, ui: &mut egui::Ui) {
+        if self.managed_processes.is_empty() {
+            return;
+        }
+        ui.add_space(20.0);
+        ui.separator();
+        ui.add_space(10.0);
+        ui.heading("🖥 Launched Processes");
+        ui.add_space(5.0);
+        let mut stop = None;
+        let mut remove = None;
+        let mut send = None;
+        for managed in &self.managed_processes {
+            ui.horizontal(|ui| {
+                let status = if let Some(restart_at) = managed.restart_at {
+                    format!(
+                        "🔁 restarting in {}s",
+                        restart_at.saturating_duration_since(Instant::now()).as_secs() + 1
+                    )
+                } else {
+                    match managed.exit_code {
+                        None => "▶ running".to_string(),
+                        Some(Some(code)) => format!("⏹ exit {}", code),
+                        Some(None) => "⏹ killed".to_string(),
+                    }
+                };
+                let label = format!("{} — PID {} — {}", managed.profile.name, managed.pid, status);
+                if ui
+                    .selectable_label(self.selected_console == Some(managed.id), label)
+                    .on_hover_text(format!("{} {}", managed.profile.path, managed.profile.args))
+                    .clicked()
+                {
+                    self.selected_console = Some(managed.id);
+                }
+                if managed.restarts > 0 {
+                    ui.weak(format!("{} restart(s)", managed.restarts));
+                }
+                if managed.is_running() || managed.restart_at.is_some() {
+                    if ui.small_button("⏹ Stop").clicked() {
+                        stop = Some(managed.id);
+                    }
+                } else if ui.small_button("✖").on_hover_text("Remove from list").clicked() {
+                    remove = Some(managed.id);
+                }
+            });
+        }
+        if let Some(managed) = self
+            .selected_console
+            .and_then(|id| self.managed_processes.iter_mut().find(|m| m.id == id))
+        {
+            ui.add_space(5.0);
+            if !managed.profile.capture_output {
+                ui.weak("Output is not captured for this program");
+            } else {
+                egui::Frame::group(ui.style())
+                    .show(
+                        ui,
+                        |ui| {
+                            egui::ScrollArea::vertical()
+                                .id_salt(("launch_console", managed.id))
+                                .max_height(300.0)
+                                .auto_shrink([false, true])
+                                .stick_to_bottom(true)
+                                .show(
+                                    ui,
+                                    |ui| {
+                                        for line in &managed.lines {
+                                            let text = egui::RichText::new(&line.text).monospace();
+                                            match line.stream {
+                                                ConsoleStream::Stdout => ui.label(text),
+                                                ConsoleStream::Stderr => {
+                                                    ui.colored_label(egui::Color32::LIGHT_RED, text)
+                                                }
+                                                ConsoleStream::Stdin => {
+                                                    ui.colored_label(
+                                                        egui::Color32::LIGHT_BLUE,
+                                                        egui::RichText::new(format!("> {}", line.text)).monospace(),
+                                                    )
+                                                }
+                                                ConsoleStream::System => ui.weak(format!("— {}", line.text)),
+                                            };
+                                        }
+                                    },
+                                );
+                        },
+                    );
+                ui.horizontal(|ui| {
+                    let has_stdin = managed.stdin.is_some();
+                    let response = ui.add_enabled(
+                        has_stdin,
+                        egui::TextEdit::singleline(&mut managed.input)
+                            .hint_text("send a line to stdin")
+                            .desired_width(350.0),
+                    );
+                    let entered = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    if ui.add_enabled(has_stdin, egui::Button::new("⏎ Send")).clicked() || entered {
+                        send = Some(managed.id);
+                    }
+                    if ui
+                        .add_enabled(has_stdin, egui::Button::new("Close stdin"))
+                        .on_hover_text("Send end-of-file")
+                        .clicked()
+                    {
+                        managed.stdin = None;
+                        managed.push_line(ConsoleStream::System, "stdin closed".to_string());
+                    }
+                    if ui.button("🗑 Clear").clicked() {
+                        managed.lines.clear();
+                    }
+                });
+            }
+        }
+        if let Some(id) = send {
+            self.send_console_input(id);
+        }
+        if let Some(id) = stop {
+            self.stop_managed_process(id);
+        }
+        if let Some(id) = remove {
+            self.managed_processes.retain(|m| m.id != id);
+            if self.selected_console == Some(id) {
+                self.selected_console = None;
+            }
+        }
+    }
+}
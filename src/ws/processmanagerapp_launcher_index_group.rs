@@ -0,0 +1,687 @@
+use crate::ws::fuzzy_score;
+use crate::ws::load_icon_image;
+use crate::ws::CustomProgram;
+#[cfg(target_os = "linux")]
+use crate::ws::{IconDirectoryKind, IconTheme, IconThemeDirectory};
+use crate::ws::LauncherEntry;
+use crate::ws::LauncherSource;
+// # ProcessManagerApp - launcher_index_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// The index is scanned on a background thread the first time the New view
+// is shown, and again on request. Icons are decoded lazily, only for rows
+// that are on screen, and cached by file path.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use super::processmanagerapp_type::ProcessManagerApp;
+
+const MAX_LAUNCHER_RESULTS: usize = 50;
+const LAUNCHER_ICON_SIZE: f32 = 20.0;
+
+/// Directories holding executables named by `PATH`, in search order.
+fn path_directories() -> Vec<PathBuf> {
+    std::env::var_os("PATH")
+        .map(|path| std::env::split_paths(&path).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(windows)]
+fn is_executable(path: &Path) -> bool {
+    let extensions = std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string());
+    let Some(extension) = path.extension() else {
+        return false;
+    };
+    let extension = format!(".{}", extension.to_string_lossy()).to_uppercase();
+    path.is_file() && extensions.split(';').any(|known| known.eq_ignore_ascii_case(&extension))
+}
+
+#[cfg(not(any(unix, windows)))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+fn scan_path_executables() -> Vec<LauncherEntry> {
+    let mut seen = HashSet::new();
+    let mut entries = Vec::new();
+    for directory in path_directories() {
+        let Ok(read_dir) = std::fs::read_dir(&directory) else {
+            continue;
+        };
+        for item in read_dir.flatten() {
+            let path = item.path();
+            let name = item.file_name().to_string_lossy().to_string();
+            if !seen.insert(name.to_lowercase()) || !is_executable(&path) {
+                continue;
+            }
+            entries
+                .push(LauncherEntry {
+                    name,
+                    path: path.to_string_lossy().to_string(),
+                    args: String::new(),
+                    working_dir: String::new(),
+                    comment: String::new(),
+                    icon_path: None,
+                    terminal: false,
+                    source: LauncherSource::Path,
+                    location: path.to_string_lossy().to_string(),
+                });
+        }
+    }
+    entries
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn xdg_data_directories() -> Vec<PathBuf> {
+    let mut directories = Vec::new();
+    match std::env::var_os("XDG_DATA_HOME") {
+        Some(home) if !home.is_empty() => directories.push(PathBuf::from(home)),
+        _ => directories.extend(dirs::home_dir().map(|home| home.join(".local/share"))),
+    }
+    let system = std::env::var("XDG_DATA_DIRS")
+        .ok()
+        .filter(|dirs| !dirs.is_empty())
+        .unwrap_or_else(|| "/usr/local/share:/usr/share".to_string());
+    directories.extend(system.split(':').filter(|dir| !dir.is_empty()).map(PathBuf::from));
+    directories
+}
+
+/// Splits an `Exec` value into words, honouring double quotes and
+/// backslash escapes, and drops field codes such as `%U`.
+#[cfg(target_os = "linux")]
+pub(crate) fn split_desktop_exec(exec: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut quoted = false;
+    let mut chars = exec.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' => {
+                quoted = !quoted;
+            }
+            '\\' if quoted => {
+                if let Some(escaped) = chars.next() {
+                    word.push(escaped);
+                }
+            }
+            '%' => {
+                match chars.next() {
+                    Some('%') => word.push('%'),
+                    Some(_) | None => {}
+                }
+            }
+            ch if ch.is_whitespace() && !quoted => {
+                if !word.is_empty() {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+            ch => {
+                word.push(ch);
+            }
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// Size icons are looked up at; the closest available size wins.
+#[cfg(target_os = "linux")]
+const DESKTOP_ICON_SIZE: u32 = 48;
+
+/// The icon theme the desktop is set to: GTK's `gtk-icon-theme-name`, then
+/// KDE's `[Icons] Theme`, then `hicolor`.
+#[cfg(target_os = "linux")]
+fn current_icon_theme() -> String {
+    let Some(config) = dirs::config_dir() else {
+        return "hicolor".to_string();
+    };
+    let setting = |file: &str, section: &str, key: &str| {
+        let content = std::fs::read_to_string(config.join(file)).ok()?;
+        let mut in_section = false;
+        for line in content.lines() {
+            let line = line.trim();
+            if line.starts_with('[') {
+                in_section = line == section;
+            } else if let Some((name, value)) = line.split_once('=').filter(|_| in_section) {
+                if name.trim() == key && !value.trim().is_empty() {
+                    return Some(value.trim().trim_matches('"').to_string());
+                }
+            }
+        }
+        None
+    };
+    setting("gtk-4.0/settings.ini", "[Settings]", "gtk-icon-theme-name")
+        .or_else(|| setting("gtk-3.0/settings.ini", "[Settings]", "gtk-icon-theme-name"))
+        .or_else(|| setting("kdeglobals", "[Icons]", "Theme"))
+        .unwrap_or_else(|| "hicolor".to_string())
+}
+
+/// Reads `name`'s `index.theme` from the first base directory that has one.
+#[cfg(target_os = "linux")]
+fn load_icon_theme(name: &str, base_dirs: &[PathBuf]) -> Option<IconTheme> {
+    let roots: Vec<PathBuf> = base_dirs.iter().map(|base| base.join(name)).filter(|root| root.is_dir()).collect();
+    let content = roots.iter().find_map(|root| std::fs::read_to_string(root.join("index.theme")).ok())?;
+    let mut sections: std::collections::HashMap<String, std::collections::HashMap<String, String>> =
+        std::collections::HashMap::new();
+    let mut section = String::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(name) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+            section = name.to_string();
+        } else if let Some((key, value)) = line.split_once('=') {
+            sections
+                .entry(section.clone())
+                .or_default()
+                .entry(key.trim().to_string())
+                .or_insert_with(|| value.trim().to_string());
+        }
+    }
+    let main = sections.get("Icon Theme")?;
+    let list = |key: &str| -> Vec<String> {
+        main.get(key)
+            .map(|value| value.split(',').map(str::trim).filter(|item| !item.is_empty()).map(str::to_string).collect())
+            .unwrap_or_default()
+    };
+    let directories = list("Directories")
+        .into_iter()
+        .chain(list("ScaledDirectories"))
+        .filter_map(|path| {
+            let fields = sections.get(&path)?;
+            let number = |key: &str| fields.get(key).and_then(|value| value.parse::<u32>().ok());
+            let size = number("Size")?;
+            let kind = match fields.get("Type").map(String::as_str) {
+                Some("Fixed") => IconDirectoryKind::Fixed,
+                Some("Scalable") => {
+                    IconDirectoryKind::Scalable {
+                        min_size: number("MinSize").unwrap_or(size),
+                        max_size: number("MaxSize").unwrap_or(size),
+                    }
+                }
+                _ => IconDirectoryKind::Threshold { threshold: number("Threshold").unwrap_or(2) },
+            };
+            Some(IconThemeDirectory {
+                path,
+                size,
+                scale: number("Scale").unwrap_or(1),
+                kind,
+            })
+        })
+        .collect();
+    Some(IconTheme {
+        name: name.to_string(),
+        roots,
+        directories,
+        inherits: list("Inherits"),
+    })
+}
+
+/// The current theme followed by everything it inherits, depth first, with
+/// `hicolor` last. Read once per run.
+#[cfg(target_os = "linux")]
+fn icon_theme_chain(data_dirs: &[PathBuf]) -> &'static [IconTheme] {
+    static CHAIN: std::sync::OnceLock<Vec<IconTheme>> = std::sync::OnceLock::new();
+    CHAIN.get_or_init(|| {
+        let base_dirs: Vec<PathBuf> = dirs::home_dir()
+            .map(|home| home.join(".icons"))
+            .into_iter()
+            .chain(data_dirs.iter().map(|dir| dir.join("icons")))
+            .collect();
+        let mut chain: Vec<IconTheme> = Vec::new();
+        let mut pending = vec![current_icon_theme()];
+        while let Some(name) = pending.pop() {
+            if chain.iter().any(|theme| theme.name == name) {
+                continue;
+            }
+            if let Some(theme) = load_icon_theme(&name, &base_dirs) {
+                // Parents are searched in the order listed, before the
+                // next sibling of this theme.
+                pending.extend(theme.inherits.iter().rev().cloned());
+                chain.push(theme);
+            }
+        }
+        if let Some(index) = chain.iter().position(|theme| theme.name == "hicolor") {
+            let hicolor = chain.remove(index);
+            chain.push(hicolor);
+        } else {
+            chain.extend(load_icon_theme("hicolor", &base_dirs));
+        }
+        chain
+    })
+}
+
+/// `LookupIcon` from the icon theme specification: an exact size match in
+/// any directory of the theme, else the closest size. Only PNG files are
+/// considered, since SVG and XPM cannot be decoded here; an icon that a
+/// theme only ships as SVG falls through to the inherited themes.
+#[cfg(target_os = "linux")]
+fn lookup_theme_icon(theme: &IconTheme, icon: &str, size: u32) -> Option<PathBuf> {
+    let file = &format!("{}.png", icon);
+    let candidates = || {
+        theme
+            .directories
+            .iter()
+            .flat_map(|directory| {
+                theme.roots.iter().map(move |root| (directory, root.join(&directory.path).join(file)))
+            })
+    };
+    candidates()
+        .find(|(directory, path)| directory.matches_size(size, 1) && path.is_file())
+        .or_else(|| {
+            candidates()
+                .filter(|(_, path)| path.is_file())
+                .min_by_key(|(directory, _)| directory.size_distance(size, 1))
+        })
+        .map(|(_, path)| path)
+}
+
+/// Looks an icon name up in the current icon theme, the themes it
+/// inherits, hicolor and finally pixmaps, following the icon theme
+/// specification. Only PNG files are returned, since those are what can be
+/// decoded.
+#[cfg(target_os = "linux")]
+pub(crate) fn resolve_desktop_icon(icon: &str, data_dirs: &[PathBuf]) -> Option<String> {
+    if icon.is_empty() {
+        return None;
+    }
+    let path = Path::new(icon);
+    if path.is_absolute() {
+        return path.is_file().then(|| icon.to_string());
+    }
+    let file = format!("{}.png", icon);
+    icon_theme_chain(data_dirs)
+        .iter()
+        .find_map(|theme| lookup_theme_icon(theme, icon, DESKTOP_ICON_SIZE))
+        .or_else(|| {
+            data_dirs.iter().map(|dir| dir.join("pixmaps").join(&file)).find(|candidate| candidate.is_file())
+        })
+        .map(|candidate| candidate.to_string_lossy().to_string())
+}
+
+#[cfg(target_os = "linux")]
+fn parse_desktop_entry(path: &Path, data_dirs: &[PathBuf]) -> Option<LauncherEntry> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let mut in_entry = false;
+    let mut fields = std::collections::HashMap::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_entry = line == "[Desktop Entry]";
+            continue;
+        }
+        if !in_entry {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            fields.entry(key.trim().to_string()).or_insert_with(|| value.trim().to_string());
+        }
+    }
+    let field = |key: &str| fields.get(key).map(String::as_str).unwrap_or("");
+    if field("Type") != "Application" || field("NoDisplay") == "true" || field("Hidden") == "true" {
+        return None;
+    }
+    let try_exec = field("TryExec");
+    if !try_exec.is_empty() {
+        let found = if Path::new(try_exec).is_absolute() {
+            is_executable(Path::new(try_exec))
+        } else {
+            path_directories().iter().any(|dir| is_executable(&dir.join(try_exec)))
+        };
+        if !found {
+            return None;
+        }
+    }
+    let mut words = split_desktop_exec(field("Exec")).into_iter();
+    let program = words.next()?;
+    Some(LauncherEntry {
+        name: if field("Name").is_empty() { program.clone() } else { field("Name").to_string() },
+        path: program,
+        // Re-quote so arguments with spaces survive being split again at launch.
+        args: shlex::try_join(words.as_slice().iter().map(String::as_str)).ok()?,
+        working_dir: field("Path").to_string(),
+        comment: field("Comment").to_string(),
+        icon_path: resolve_desktop_icon(field("Icon"), data_dirs),
+        terminal: field("Terminal") == "true",
+        source: LauncherSource::DesktopEntry,
+        location: path.to_string_lossy().to_string(),
+    })
+}
+
+/// Desktop entries by desktop file ID; an ID found in an earlier data
+/// directory hides the same ID further down the list.
+#[cfg(target_os = "linux")]
+pub(crate) fn scan_desktop_entries() -> Vec<LauncherEntry> {
+    fn walk(root: &Path, directory: &Path, files: &mut Vec<(String, PathBuf)>) {
+        let Ok(read_dir) = std::fs::read_dir(directory) else {
+            return;
+        };
+        for item in read_dir.flatten() {
+            let path = item.path();
+            if path.is_dir() {
+                walk(root, &path, files);
+            } else if path.extension().is_some_and(|ext| ext == "desktop") {
+                let id = path
+                    .strip_prefix(root)
+                    .unwrap_or(&path)
+                    .to_string_lossy()
+                    .replace('/', "-");
+                files.push((id, path));
+            }
+        }
+    }
+    let data_dirs = xdg_data_directories();
+    let mut seen = HashSet::new();
+    let mut entries = Vec::new();
+    for data_dir in &data_dirs {
+        let root = data_dir.join("applications");
+        let mut files = Vec::new();
+        walk(&root, &root, &mut files);
+        for (id, path) in files {
+            if seen.insert(id) {
+                entries.extend(parse_desktop_entry(&path, &data_dirs));
+            }
+        }
+    }
+    entries
+}
+
+/// Icon files of installed applications, keyed by lowercase executable
+/// name and by desktop file ID (`org.gnome.Nautilus` also as `nautilus`).
+#[cfg(target_os = "linux")]
+pub(crate) fn desktop_icon_index() -> std::collections::HashMap<String, String> {
+    let mut index = std::collections::HashMap::new();
+    for entry in scan_desktop_entries() {
+        let Some(icon) = entry.icon_path else {
+            continue;
+        };
+        let mut keys = Vec::new();
+        keys.extend(Path::new(&entry.path).file_name().map(|name| name.to_string_lossy().to_lowercase()));
+        if let Some(id) = Path::new(&entry.location).file_stem() {
+            let id = id.to_string_lossy().to_lowercase();
+            keys.extend(id.rsplit('.').next().map(str::to_string));
+            keys.push(id);
+        }
+        for key in keys {
+            index.entry(key).or_insert_with(|| icon.clone());
+        }
+    }
+    index
+}
+
+/// Reads the target, arguments and working directory of a `.lnk` file.
+#[cfg(windows)]
+pub(crate) fn resolve_shortcut(path: &Path) -> Option<(String, String, String)> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows::core::{Interface, PCWSTR};
+    use windows::Win32::Storage::FileSystem::WIN32_FIND_DATAW;
+    use windows::Win32::System::Com::{
+        CoCreateInstance, IPersistFile, CLSCTX_INPROC_SERVER, STGM_READ,
+    };
+    use windows::Win32::UI::Shell::{IShellLinkW, ShellLink};
+    let wide: Vec<u16> = path.as_os_str().encode_wide().chain(std::iter::once(0)).collect();
+    let from_wide = |buffer: &[u16]| {
+        let end = buffer.iter().position(|c| *c == 0).unwrap_or(buffer.len());
+        String::from_utf16_lossy(&buffer[..end])
+    };
+    unsafe {
+        let link: IShellLinkW = CoCreateInstance(&ShellLink, None, CLSCTX_INPROC_SERVER).ok()?;
+        link.cast::<IPersistFile>().ok()?.Load(PCWSTR::from_raw(wide.as_ptr()), STGM_READ).ok()?;
+        let mut target = [0u16; 260];
+        let mut find_data = WIN32_FIND_DATAW::default();
+        link.GetPath(&mut target, &mut find_data, 0).ok()?;
+        let mut args = [0u16; 1024];
+        let _ = link.GetArguments(&mut args);
+        let mut working_dir = [0u16; 260];
+        let _ = link.GetWorkingDirectory(&mut working_dir);
+        let target = from_wide(&target);
+        if target.is_empty() {
+            return None;
+        }
+        Some((target, from_wide(&args), from_wide(&working_dir)))
+    }
+}
+
+#[cfg(windows)]
+fn scan_start_menu() -> Vec<LauncherEntry> {
+    use windows::Win32::System::Com::{CoInitializeEx, COINIT_APARTMENTTHREADED};
+    fn walk(directory: &Path, files: &mut Vec<PathBuf>) {
+        let Ok(read_dir) = std::fs::read_dir(directory) else {
+            return;
+        };
+        for item in read_dir.flatten() {
+            let path = item.path();
+            if path.is_dir() {
+                walk(&path, files);
+            } else if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("lnk")) {
+                files.push(path);
+            }
+        }
+    }
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+    }
+    let mut files = Vec::new();
+    for variable in ["APPDATA", "ProgramData"] {
+        if let Some(base) = std::env::var_os(variable) {
+            walk(&PathBuf::from(base).join("Microsoft\\Windows\\Start Menu\\Programs"), &mut files);
+        }
+    }
+    let mut seen = HashSet::new();
+    files
+        .into_iter()
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_string_lossy().to_string();
+            let (target, args, working_dir) = resolve_shortcut(&path)?;
+            // Uninstallers and documentation links are not programs to start.
+            if !seen.insert(name.to_lowercase())
+                || !target.to_lowercase().ends_with(".exe")
+                || name.to_lowercase().contains("uninstall")
+            {
+                return None;
+            }
+            Some(LauncherEntry {
+                name,
+                path: target,
+                args,
+                working_dir,
+                comment: String::new(),
+                icon_path: None,
+                terminal: false,
+                source: LauncherSource::StartMenu,
+                location: path.to_string_lossy().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Every launchable entry, platform entries before `PATH` executables.
+fn scan_launcher_entries() -> Vec<LauncherEntry> {
+    let mut entries = Vec::new();
+    #[cfg(target_os = "linux")]
+    entries.extend(scan_desktop_entries());
+    #[cfg(windows)]
+    entries.extend(scan_start_menu());
+    entries.extend(scan_path_executables());
+    entries
+}
+
+impl ProcessManagerApp {
+    pub(crate) fn rescan_launcher_index(&mut self) {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(scan_launcher_entries());
+        });
+        self.launcher_index.loader = Some(receiver);
+    }
+    fn poll_launcher_index(&mut self) {
+        let Some(loader) = &self.launcher_index.loader else {
+            return;
+        };
+        match loader.try_recv() {
+            Ok(entries) => {
+                self.launcher_index.loader = None;
+                let desktop = entries.iter().filter(|e| e.source != LauncherSource::Path).count();
+                self.add_log(
+                    format!(
+                        "🔎 Launcher index: {} application(s), {} PATH executable(s)",
+                        desktop, entries.len() - desktop
+                    ),
+                );
+                self.launcher_index.entries = entries;
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.launcher_index.loader = None;
+            }
+        }
+    }
+    /// Best matches for the query; applications rank above PATH
+    /// executables with the same score.
+    fn launcher_matches(&self) -> Vec<usize> {
+        let query = self.launcher_index.query.trim();
+        let mut scored: Vec<(i32, usize)> = self
+            .launcher_index
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| {
+                let file_name = Path::new(&entry.path)
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let score = fuzzy_score(query, &entry.name).max(fuzzy_score(query, &file_name))?;
+                Some((score, index))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        scored.into_iter().take(MAX_LAUNCHER_RESULTS).map(|(_, index)| index).collect()
+    }
+    fn launcher_icon(&mut self, ctx: &egui::Context, icon_path: &str) -> Option<egui::TextureHandle> {
+        if let Some(icon) = self.launcher_index.icons.get(icon_path) {
+            return icon.clone();
+        }
+        let icon = load_icon_image(icon_path, 48)
+            .map(|image| {
+                ctx.load_texture(format!("launcher_icon_{}", icon_path), image, egui::TextureOptions::LINEAR)
+            });
+        self.launcher_index.icons.insert(icon_path.to_string(), icon.clone());
+        icon
+    }
+    fn launcher_entry_profile(entry: &LauncherEntry) -> CustomProgram {
+        CustomProgram {
+            name: entry.name.clone(),
+            path: entry.path.clone(),
+            args: entry.args.clone(),
+            working_dir: entry.working_dir.clone(),
+            capture_output: entry.terminal,
+            ..CustomProgram::default()
+        }
+    }
+    /// Fuzzy search over installed applications and PATH, shown at the top
+    /// of the New view.
+    pub(crate) fn show_launcher_search(&mut self, ui: &mut egui::Ui) {
+        if self.launcher_index.entries.is_empty() && self.launcher_index.loader.is_none() {
+            self.rescan_launcher_index();
+        }
+        self.poll_launcher_index();
+        ui.horizontal(|ui| {
+            ui.label("🔎 Find:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.launcher_index.query)
+                    .desired_width(350.0)
+                    .hint_text("search installed applications and PATH"),
+            );
+            if self.launcher_index.loader.is_some() {
+                ui.spinner();
+                ui.ctx().request_repaint_after(std::time::Duration::from_millis(100));
+            } else if ui.button("🔄 Rescan").clicked() {
+                self.rescan_launcher_index();
+            }
+        });
+        if self.launcher_index.query.trim().is_empty() {
+            return;
+        }
+        let matches = self.launcher_matches();
+        if matches.is_empty() {
+            ui.weak("No matching applications");
+            return;
+        }
+        let mut launch = None;
+        let mut pin = None;
+        let mut fill = None;
+        egui::ScrollArea::vertical()
+            .id_salt("launcher_results")
+            .max_height(260.0)
+            .show(
+                ui,
+                |ui| {
+                    for index in matches {
+                        let entry = self.launcher_index.entries[index].clone();
+                        ui.horizontal(|ui| {
+                            let icon = entry
+                                .icon_path
+                                .as_deref()
+                                .and_then(|path| self.launcher_icon(ui.ctx(), path));
+                            match icon {
+                                Some(texture) => {
+                                    ui.add(
+                                        egui::Image::new(&texture)
+                                            .fit_to_exact_size(egui::vec2(LAUNCHER_ICON_SIZE, LAUNCHER_ICON_SIZE)),
+                                    );
+                                }
+                                None => {
+                                    ui.add_sized(
+                                        [LAUNCHER_ICON_SIZE, LAUNCHER_ICON_SIZE],
+                                        egui::Label::new(entry.source.icon()),
+                                    );
+                                }
+                            }
+                            let mut hover = format!("{} {}", entry.path, entry.args);
+                            if !entry.comment.is_empty() {
+                                hover = format!("{}\n{}", entry.comment, hover);
+                            }
+                            hover = format!("{}\nFrom {}", hover, entry.location);
+                            if ui.link(&entry.name).on_hover_text(hover).clicked() {
+                                fill = Some(index);
+                            }
+                            if ui.small_button("▶").on_hover_text("Launch").clicked() {
+                                launch = Some(index);
+                            }
+                            let pinned = self.custom_programs.iter().any(|p| p.name == entry.name);
+                            if ui
+                                .add_enabled(!pinned, egui::Button::new("📌").small())
+                                .on_hover_text("Pin to Custom Programs")
+                                .clicked()
+                            {
+                                pin = Some(index);
+                            }
+                        });
+                    }
+                },
+            );
+        if let Some(index) = fill {
+            let profile = Self::launcher_entry_profile(&self.launcher_index.entries[index]);
+            self.edit_launch_profile(&profile);
+        }
+        if let Some(index) = launch {
+            let profile = Self::launcher_entry_profile(&self.launcher_index.entries[index]);
+            let _ = self.launch_profile(profile);
+            self.refresh_all_data();
+        }
+        if let Some(index) = pin {
+            let profile = Self::launcher_entry_profile(&self.launcher_index.entries[index]);
+            self.add_log(format!("📌 Pinned {}", profile.name));
+            self.custom_programs.push(profile);
+            self.save_config();
+        }
+    }
+}
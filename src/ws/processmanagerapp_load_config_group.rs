@@ -1,6 +1,11 @@
 use crate::ws::AppConfig;
 use crate::ws::types::Theme;
 use crate::ws::CustomProgram;
+use crate::ws::FilepaneKeymap;
+use crate::ws::FilepaneBookmark;
+use crate::ws::{LogLevel, LogSettings};
+use crate::ws::{WindowLayout, WindowLayoutRule};
+use crate::ws::{ProgramGroup, ProgramGroupStep};
 // # ProcessManagerApp - load_config_group Methods
 //
 // This module contains method implementations for `ProcessManagerApp`.
@@ -21,40 +26,97 @@ impl ProcessManagerApp {
             live_grid_size: 3,
             live_detail_percent: 0.5,
             attempt_start_as_admin: true,
+            keymap: FilepaneKeymap::default(),
+            bookmarks: Vec::new(),
+            recent_dirs: Vec::new(),
+            logging: LogSettings::default(),
+            window_layouts: Vec::new(),
+            program_groups: Vec::new(),
+            warnings: Vec::new(),
         };
         if let Ok(content) = std::fs::read_to_string(&config_path) {
-            let mut current_program = CustomProgram {
-                name: String::new(),
-                path: String::new(),
-                args: String::new(),
-                admin: false,
-            };
+            let mut current_program = CustomProgram::default();
             let mut in_program = false;
             let mut in_settings = false;
+            let mut in_keymap = false;
+            let mut in_bookmarks = false;
+            let mut in_recent = false;
+            let mut current_layout: Option<WindowLayout> = None;
+            let mut current_group: Option<ProgramGroup> = None;
             for line in content.lines() {
                 let line = line.trim();
+                if line.starts_with('[') {
+                    config.window_layouts.extend(current_layout.take());
+                    config.program_groups.extend(current_group.take());
+                }
                 if line.starts_with("[Program]") {
                     if in_program && !current_program.name.is_empty()
                         && !current_program.path.is_empty()
                     {
                         config.programs.push(current_program.clone());
                     }
-                    current_program = CustomProgram {
-                        name: String::new(),
-                        path: String::new(),
-                        args: String::new(),
-                        admin: false,
-                    };
+                    current_program = CustomProgram::default();
                     in_program = true;
                     in_settings = false;
-                } else if line.starts_with("[Settings]") {
+                    in_keymap = false;
+                    in_bookmarks = false;
+                    in_recent = false;
+                } else if line.starts_with('[') {
                     if in_program && !current_program.name.is_empty()
                         && !current_program.path.is_empty()
                     {
                         config.programs.push(current_program.clone());
                     }
-                    in_settings = true;
+                    in_settings = line.starts_with("[Settings]");
+                    in_keymap = line.starts_with("[Keymap]");
+                    in_bookmarks = line.starts_with("[Bookmarks]");
+                    in_recent = line.starts_with("[RecentDirs]");
                     in_program = false;
+                    if line.starts_with("[Layout]") {
+                        current_layout = Some(WindowLayout {
+                            name: String::new(),
+                            rules: Vec::new(),
+                        });
+                    }
+                    if line.starts_with("[Group]") {
+                        current_group = Some(ProgramGroup::default());
+                    }
+                } else if let Some(layout) = current_layout.as_mut() {
+                    match line.split_once('=') {
+                        Some(("Name", name)) => layout.name = name.trim().to_string(),
+                        Some(("Window", value)) => {
+                            match WindowLayoutRule::from_config(value) {
+                                Some(rule) => layout.rules.push(rule),
+                                None => config.warnings.push(format!("⚠️ Ignoring layout entry '{}'", line)),
+                            }
+                        }
+                        _ => {}
+                    }
+                } else if let Some(group) = current_group.as_mut() {
+                    match line.split_once('=') {
+                        Some(("Name", name)) => group.name = name.trim().to_string(),
+                        Some(("Step", value)) => {
+                            match ProgramGroupStep::from_config(value) {
+                                Some(step) => group.steps.push(step),
+                                None => config.warnings.push(format!("⚠️ Ignoring group entry '{}'", line)),
+                            }
+                        }
+                        _ => {}
+                    }
+                } else if in_bookmarks {
+                    if let Some(bookmark) = FilepaneBookmark::from_config_line(line) {
+                        config.bookmarks.push(bookmark);
+                    }
+                } else if in_recent {
+                    if let Some(("Path", path)) = line.split_once('=') {
+                        config.recent_dirs.push(path.trim().to_string());
+                    }
+                } else if in_keymap {
+                    if let Some((action, keys)) = line.split_once('=') {
+                        if let Err(e) = config.keymap.set_from_config(action.trim(), keys) {
+                            config.warnings.push(format!("⚠️ Ignoring keymap entry '{}': {}", line, e));
+                        }
+                    }
                 } else if in_program {
                     if let Some((key, value)) = line.split_once('=') {
                         let key = key.trim();
@@ -64,6 +126,14 @@ impl ProcessManagerApp {
                             "Path" => current_program.path = value.to_string(),
                             "Args" => current_program.args = value.to_string(),
                             "Admin" => current_program.admin = value == "true",
+                            "WorkingDir" => current_program.working_dir = value.to_string(),
+                            "Env" => {
+                                current_program.env.extend(CustomProgram::parse_env(value));
+                            }
+                            "CaptureOutput" => current_program.capture_output = value == "true",
+                            "RestartOnCrash" => {
+                                current_program.restart_on_crash = value == "true";
+                            }
                             _ => {}
                         }
                     }
@@ -94,6 +164,22 @@ impl ProcessManagerApp {
                             "AttemptStartAsAdmin" => {
                                 config.attempt_start_as_admin = value == "true";
                             }
+                            "LogToFile" => config.logging.to_file = value == "true",
+                            "LogFileMaxKb" => {
+                                if let Ok(kb) = value.parse() {
+                                    config.logging.max_file_kb = kb;
+                                }
+                            }
+                            "HistoryRetentionDays" => {
+                                if let Ok(days) = value.parse() {
+                                    config.logging.history_retention_days = days;
+                                }
+                            }
+                            "LogLibraryLevel" => {
+                                if let Some(level) = LogLevel::from_name(value) {
+                                    config.logging.library_level = level;
+                                }
+                            }
                             _ => {}
                         }
                     }
@@ -104,6 +190,8 @@ impl ProcessManagerApp {
             {
                 config.programs.push(current_program);
             }
+            config.window_layouts.extend(current_layout);
+            config.program_groups.extend(current_group);
         }
         config
     }
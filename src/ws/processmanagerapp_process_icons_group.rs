@@ -0,0 +1,52 @@
+// # ProcessManagerApp - process_icons_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Views ask `ProcessIconCache::get` while drawing; a missing icon is
+// requested once and the emoji from `get_process_icon` is drawn until the
+// worker answers.
+
+use super::processmanagerapp_type::ProcessManagerApp;
+
+impl ProcessManagerApp {
+    /// Uploads icons the worker has finished since the last frame.
+    pub(crate) fn poll_process_icons(&mut self, ctx: &egui::Context) {
+        let results: Vec<(String, Option<egui::ColorImage>)> = self.process_icons.results.try_iter().collect();
+        for (key, image) in results {
+            self.process_icons.pending.remove(&key);
+            let texture = image
+                .map(|image| {
+                    ctx.load_texture(format!("process_icon_{}", key), image, egui::TextureOptions::LINEAR)
+                });
+            self.process_icons.textures.insert(key, texture);
+        }
+        if !self.process_icons.pending.is_empty() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+    }
+    pub(crate) fn exe_for_pid(&self, pid: u32) -> Option<String> {
+        self.processes
+            .iter()
+            .find(|process| process.pid == pid)
+            .and_then(|process| process.exe_path.clone())
+    }
+    /// Draws `icon` at `size` points, or the emoji for `name` without one.
+    pub(crate) fn show_process_icon(
+        ui: &mut egui::Ui,
+        icon: Option<egui::TextureHandle>,
+        name: &str,
+        size: f32,
+    ) -> egui::Response {
+        match icon {
+            Some(texture) => {
+                ui.add(egui::Image::new(&texture).fit_to_exact_size(egui::vec2(size, size)))
+            }
+            None => {
+                ui.add_sized(
+                    [size, size],
+                    egui::Label::new(egui::RichText::new(Self::get_process_icon(name)).size(size * 0.8)),
+                )
+            }
+        }
+    }
+}
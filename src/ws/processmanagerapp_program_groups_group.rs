@@ -0,0 +1,407 @@
+use crate::ws::LogLevel;
+use crate::ws::GroupLaunch;
+use crate::ws::ProgramGroup;
+use crate::ws::ProgramGroupStep;
+// # ProcessManagerApp - program_groups_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// A group launch advances one step at a time from `update`: wait out the
+// step's delay, start its profile, then, if the step names a port, hold
+// until a background probe sees it listening. Each running group keeps the
+// ids of what it started so "Stop" only touches its own processes.
+
+use std::time::{Duration, Instant};
+use super::processmanagerapp_type::ProcessManagerApp;
+
+/// How long a step may wait for its port before the launch is abandoned.
+const PORT_WAIT_TIMEOUT: Duration = Duration::from_secs(120);
+const PORT_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Looks for a listening TCP socket on `port` in the kernel's socket tables.
+#[cfg(target_os = "linux")]
+fn is_port_listening(port: u16) -> bool {
+    let listening = |entries: procfs::ProcResult<Vec<procfs::net::TcpNetEntry>>| {
+        entries
+            .map(|entries| {
+                entries
+                    .iter()
+                    .any(|entry| {
+                        entry.state == procfs::net::TcpState::Listen
+                            && entry.local_address.port() == port
+                    })
+            })
+            .unwrap_or(false)
+    };
+    listening(procfs::net::tcp()) || listening(procfs::net::tcp6())
+}
+
+/// Tries a loopback connection to `port`.
+#[cfg(not(target_os = "linux"))]
+fn is_port_listening(port: u16) -> bool {
+    let timeout = Duration::from_millis(500);
+    ["127.0.0.1", "::1"]
+        .iter()
+        .filter_map(|host| host.parse::<std::net::IpAddr>().ok())
+        .any(|ip| {
+            std::net::TcpStream::connect_timeout(&std::net::SocketAddr::new(ip, port), timeout)
+                .is_ok()
+        })
+}
+
+/// Checks `port` once per interval on a worker thread and reports each
+/// result; the thread ends once the port listens or the launch is dropped.
+fn start_port_probe(port: u16) -> std::sync::mpsc::Receiver<bool> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        loop {
+            let listening = is_port_listening(port);
+            if sender.send(listening).is_err() || listening {
+                return;
+            }
+            std::thread::sleep(PORT_CHECK_INTERVAL);
+        }
+    });
+    receiver
+}
+
+impl ProcessManagerApp {
+    pub(crate) fn launch_program_group(&mut self, index: usize) {
+        let Some(group) = self.program_groups.get(index).cloned() else {
+            return;
+        };
+        if self.group_launches.iter().any(|run| run.group == group.name && !run.is_finished()) {
+            self.add_log_at(LogLevel::Warn, format!("⚠️ Group '{}' is already launching", group.name));
+            return;
+        }
+        if group.steps.is_empty() {
+            return;
+        }
+        // Processes from an earlier finished or failed run stay under the
+        // group, so "Stop" still reaches them.
+        let mut launched = Vec::new();
+        self.group_launches
+            .retain(|run| {
+                if run.group == group.name {
+                    launched.extend(run.launched.iter().copied());
+                    false
+                } else {
+                    true
+                }
+            });
+        self.add_log(format!("📦 Launching group '{}' ({} step(s))", group.name, group.steps.len()));
+        let first_delay = Duration::from_secs(group.steps[0].delay_secs);
+        self.group_launches
+            .push(GroupLaunch {
+                group: group.name,
+                steps: group.steps,
+                step: 0,
+                ready_at: Instant::now() + first_delay,
+                waiting_port: None,
+                port_probe: None,
+                launched,
+                failure: None,
+            });
+    }
+    /// Kills everything the group's launches started and cancels any steps
+    /// not reached yet.
+    pub(crate) fn stop_program_group(&mut self, name: &str) {
+        let mut launched = Vec::new();
+        self.group_launches
+            .retain(|run| {
+                if run.group == name {
+                    launched.extend(run.launched.iter().copied());
+                    false
+                } else {
+                    true
+                }
+            });
+        for id in &launched {
+            self.stop_managed_process(*id);
+        }
+        self.add_log(format!("⏹ Stopped group '{}' ({} process(es))", name, launched.len()));
+    }
+    /// Advances every unfinished group launch as far as it can go this
+    /// frame.
+    pub(crate) fn poll_group_launches(&mut self, ctx: &egui::Context) {
+        for index in 0..self.group_launches.len() {
+            if let Err(failure) = self.advance_group_launch(index) {
+                self.add_log_at(LogLevel::Error, format!("❌ {}: {}", self.group_launches[index].group, failure));
+                let run = &mut self.group_launches[index];
+                run.failure = Some(failure);
+                run.port_probe = None;
+            }
+        }
+        if self.group_launches.iter().any(|run| !run.is_finished()) {
+            ctx.request_repaint_after(Duration::from_millis(250));
+        }
+    }
+    /// Fails with the reason when the launch has to be abandoned; what it
+    /// already started keeps running until the group is stopped.
+    fn advance_group_launch(&mut self, index: usize) -> Result<(), String> {
+        loop {
+            let run = &self.group_launches[index];
+            if run.is_finished() {
+                return Ok(());
+            }
+            let now = Instant::now();
+            if let Some((port, since)) = run.waiting_port {
+                let listening = run
+                    .port_probe
+                    .as_ref()
+                    .is_some_and(|probe| probe.try_iter().any(|listening| listening));
+                let group = run.group.clone();
+                if listening {
+                    self.add_log(format!("📦 {}: port {} is listening", group, port));
+                    self.next_group_step(index);
+                    continue;
+                }
+                if since.elapsed() >= PORT_WAIT_TIMEOUT {
+                    return Err(
+                        format!(
+                            "port {} not listening after {}s, launch abandoned", port,
+                            PORT_WAIT_TIMEOUT.as_secs()
+                        ),
+                    );
+                }
+                return Ok(());
+            }
+            if now < run.ready_at {
+                return Ok(());
+            }
+            let group = run.group.clone();
+            let step = run.steps[run.step].clone();
+            let Some(profile) = self
+                .custom_programs
+                .iter()
+                .find(|program| program.name == step.program)
+                .cloned() else {
+                self.add_log_at(LogLevel::Warn, format!("⚠️ {}: no launch profile named '{}', skipped", group, step.program));
+                self.next_group_step(index);
+                continue;
+            };
+            match self.launch_profile(profile) {
+                Ok(pid) => {
+                    let id = self
+                        .managed_processes
+                        .iter()
+                        .rev()
+                        .find(|managed| managed.pid == pid)
+                        .map(|managed| managed.id);
+                    let run = &mut self.group_launches[index];
+                    run.launched.extend(id);
+                    match step.wait_port {
+                        Some(port) => {
+                            run.waiting_port = Some((port, now));
+                            run.port_probe = Some(start_port_probe(port));
+                        }
+                        None => self.next_group_step(index),
+                    }
+                }
+                Err(e) => {
+                    return Err(format!("'{}' failed, launch abandoned: {}", step.program, e));
+                }
+            }
+        }
+    }
+    fn next_group_step(&mut self, index: usize) {
+        let run = &mut self.group_launches[index];
+        run.waiting_port = None;
+        run.port_probe = None;
+        run.step += 1;
+        match run.steps.get(run.step) {
+            Some(step) => run.ready_at = Instant::now() + Duration::from_secs(step.delay_secs),
+            None => {
+                let message = format!("✓ Group '{}' launched", run.group);
+                self.add_log(message);
+            }
+        }
+    }
+    fn group_status(&self, name: &str) -> Option<String> {
+        let run = self.group_launches.iter().find(|run| run.group == name)?;
+        if run.is_finished() {
+            let running = run
+                .launched
+                .iter()
+                .filter(|id| {
+                    self.managed_processes.iter().any(|m| m.id == **id && m.is_running())
+                })
+                .count();
+            let status = format!("{} of {} running", running, run.launched.len());
+            return Some(
+                match &run.failure {
+                    Some(failure) => format!("❌ {} ({})", failure, status),
+                    None => status,
+                },
+            );
+        }
+        let step = &run.steps[run.step];
+        let position = format!("step {}/{}", run.step + 1, run.steps.len());
+        Some(
+            match run.waiting_port {
+                Some((port, _)) => format!("{}: waiting for port {}", position, port),
+                None => {
+                    format!(
+                        "{}: starting '{}' in {}s", position, step.program, run.ready_at
+                        .saturating_duration_since(Instant::now()).as_secs()
+                    )
+                }
+            },
+        )
+    }
+    fn save_group_editor(&mut self) {
+        let Some(mut group) = self.group_editor.take() else {
+            return;
+        };
+        group.name = group.name.trim().to_string();
+        group.steps.retain(|step| !step.program.is_empty());
+        let name = group.name.clone();
+        match self.program_groups.iter_mut().find(|existing| existing.name == group.name) {
+            Some(existing) => *existing = group,
+            None => self.program_groups.push(group),
+        }
+        self.save_config();
+        self.add_log(format!("💾 Saved group '{}'", name));
+    }
+    /// Program Groups section of the New view.
+    pub(crate) fn show_program_groups(&mut self, ui: &mut egui::Ui) {
+        ui.add_space(20.0);
+        ui.separator();
+        ui.add_space(10.0);
+        ui.horizontal(|ui| {
+            ui.heading("📦 Program Groups");
+            if self.group_editor.is_none() && ui.button("➕ New Group").clicked() {
+                self.group_editor = Some(ProgramGroup::default());
+            }
+        });
+        ui.add_space(5.0);
+        if self.program_groups.is_empty() && self.group_editor.is_none() {
+            ui.weak("Save launch profiles, then group them to start several programs at once");
+        }
+        let mut launch = None;
+        let mut stop = None;
+        let mut edit = None;
+        let mut remove = None;
+        for (index, group) in self.program_groups.iter().enumerate() {
+            let status = self.group_status(&group.name);
+            ui.horizontal(|ui| {
+                let steps: Vec<String> = group.steps.iter().map(|step| step.program.clone()).collect();
+                ui.strong(&group.name).on_hover_text(steps.join(" → "));
+                if ui.button("▶ Launch").clicked() {
+                    launch = Some(index);
+                }
+                if ui.add_enabled(status.is_some(), egui::Button::new("⏹ Stop")).clicked() {
+                    stop = Some(group.name.clone());
+                }
+                if ui.small_button("✏").on_hover_text("Edit group").clicked() {
+                    edit = Some(index);
+                }
+                if ui.small_button("✖").on_hover_text("Delete group").clicked() {
+                    remove = Some(index);
+                }
+                if let Some(status) = status {
+                    ui.weak(status);
+                }
+            });
+        }
+        if let Some(index) = launch {
+            self.launch_program_group(index);
+        }
+        if let Some(name) = stop {
+            self.stop_program_group(&name);
+        }
+        if let Some(index) = edit {
+            self.group_editor = Some(self.program_groups[index].clone());
+        }
+        if let Some(index) = remove {
+            let group = self.program_groups.remove(index);
+            self.add_log(format!("🗑 Deleted group '{}'", group.name));
+            self.save_config();
+        }
+        self.show_group_editor(ui);
+    }
+    fn show_group_editor(&mut self, ui: &mut egui::Ui) {
+        let profiles: Vec<String> = self.custom_programs.iter().map(|p| p.name.clone()).collect();
+        let Some(group) = self.group_editor.as_mut() else {
+            return;
+        };
+        let mut save = false;
+        let mut cancel = false;
+        ui.add_space(5.0);
+        egui::Frame::group(ui.style())
+            .show(
+                ui,
+                |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("Group name:");
+                        ui.text_edit_singleline(&mut group.name);
+                    });
+                    let mut remove = None;
+                    let mut swap = None;
+                    let count = group.steps.len();
+                    for (index, step) in group.steps.iter_mut().enumerate() {
+                        ui.horizontal(|ui| {
+                            ui.label(format!("{}.", index + 1));
+                            egui::ComboBox::from_id_salt(("group_step_program", index))
+                                .selected_text(
+                                    if step.program.is_empty() { "choose profile" } else { &step.program },
+                                )
+                                .show_ui(
+                                    ui,
+                                    |ui| {
+                                        for name in &profiles {
+                                            ui.selectable_value(&mut step.program, name.clone(), name);
+                                        }
+                                    },
+                                );
+                            ui.label("delay");
+                            ui.add(egui::DragValue::new(&mut step.delay_secs).range(0..=3600).suffix(" s"));
+                            let mut waits = step.wait_port.is_some();
+                            if ui.checkbox(&mut waits, "wait for port").changed() {
+                                step.wait_port = waits.then_some(8080);
+                            }
+                            if let Some(port) = step.wait_port.as_mut() {
+                                ui.add(egui::DragValue::new(port).range(1..=65535));
+                            }
+                            if ui.add_enabled(index > 0, egui::Button::new("⬆").small()).clicked() {
+                                swap = Some(index - 1);
+                            }
+                            if ui.add_enabled(index + 1 < count, egui::Button::new("⬇").small()).clicked() {
+                                swap = Some(index);
+                            }
+                            if ui.small_button("✖").clicked() {
+                                remove = Some(index);
+                            }
+                        });
+                    }
+                    if let Some(index) = swap {
+                        group.steps.swap(index, index + 1);
+                    }
+                    if let Some(index) = remove {
+                        group.steps.remove(index);
+                    }
+                    if profiles.is_empty() {
+                        ui.weak("No launch profiles yet; save one above first");
+                    }
+                    ui.horizontal(|ui| {
+                        if ui.button("➕ Add Step").clicked() {
+                            group.steps.push(ProgramGroupStep::default());
+                        }
+                        let can_save = !group.name.trim().is_empty()
+                            && group.steps.iter().any(|step| !step.program.is_empty());
+                        if ui.add_enabled(can_save, egui::Button::new("💾 Save Group")).clicked() {
+                            save = true;
+                        }
+                        if ui.button("Cancel").clicked() {
+                            cancel = true;
+                        }
+                    });
+                },
+            );
+        if save {
+            self.save_group_editor();
+        } else if cancel {
+            self.group_editor = None;
+        }
+    }
+}
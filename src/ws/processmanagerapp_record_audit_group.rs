@@ -0,0 +1,268 @@
+use crate::ws::LogLevel;
+use crate::ws::AuditOperation;
+use crate::ws::AuditOutcome;
+use crate::ws::AuditRecord;
+use crate::ws::ChecksumAlgorithm;
+use crate::ws::FileSystemBackend;
+use crate::ws::FilepaneCommand;
+use crate::ws::AUDIT_HASH_LIMIT;
+// # ProcessManagerApp - record_audit_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Every copy/move/delete/rename/mkdir/link run by `execute_real_command` is
+// appended as one JSON object per line. The file is only ever opened in
+// append mode; the viewer reads it back without modifying it. The source is
+// sized and hashed right before the operation, on whichever thread runs it.
+
+use std::io::{BufRead, Write};
+use std::time::Instant;
+use super::processmanagerapp_type::ProcessManagerApp;
+
+impl ProcessManagerApp {
+    pub fn audit_log_path() -> std::path::PathBuf {
+        Self::get_config_path().with_file_name("vibe_winshell_audit.jsonl")
+    }
+    /// Starts an audit record for `command`. `None` for commands that are
+    /// not audited. The source is filled in by `inspect_audit_source`.
+    pub(crate) fn begin_audit(&self, command: &FilepaneCommand) -> Option<(AuditRecord, Instant)> {
+        let (operation, source, destination) = match command {
+            FilepaneCommand::CopyFile { source, destination, .. } => {
+                (AuditOperation::Copy, source, Some(Self::destination_entry(source, destination)))
+            }
+            FilepaneCommand::MoveFile { source, destination } => {
+                (AuditOperation::Move, source, Some(Self::destination_entry(source, destination)))
+            }
+            FilepaneCommand::DeleteFile { path } => (AuditOperation::Delete, path, None),
+            FilepaneCommand::CreateDirectory { path } => (AuditOperation::Mkdir, path, None),
+            FilepaneCommand::RenameFile { old_path, new_path } => {
+                (AuditOperation::Rename, old_path, Some(new_path.clone()))
+            }
+            FilepaneCommand::CreateHardlink { target, link_path, .. } => {
+                (AuditOperation::Hardlink, target, Some(link_path.clone()))
+            }
+            FilepaneCommand::CreateSymlink { target, link_path } => {
+                (AuditOperation::Symlink, target, Some(link_path.clone()))
+            }
+            _ => return None,
+        };
+        let record = AuditRecord {
+            timestamp: chrono::Local::now().to_rfc3339(),
+            duration_ms: 0,
+            user: std::env::var("USER")
+                .or_else(|_| std::env::var("USERNAME"))
+                .unwrap_or_default(),
+            host: sysinfo::System::host_name().unwrap_or_default(),
+            operation,
+            source: source.clone(),
+            destination,
+            is_directory: false,
+            size: None,
+            sha256: None,
+            outcome: AuditOutcome::Failure,
+            error: None,
+        };
+        Some((record, Instant::now()))
+    }
+    /// Records the source's type, size and hash before it is changed. Files
+    /// over `AUDIT_HASH_LIMIT` are recorded by size only.
+    pub(crate) fn inspect_audit_source(backend: &dyn FileSystemBackend, record: &mut AuditRecord) {
+        if !record.operation.inspects_source() {
+            return;
+        }
+        let Ok(info) = backend.stat(&record.source) else {
+            return;
+        };
+        record.is_directory = info.is_directory;
+        if info.is_directory || info.is_symlink() {
+            return;
+        }
+        record.size = Some(info.size);
+        if info.size <= AUDIT_HASH_LIMIT {
+            record.sha256 = backend
+                .open_read(&record.source)
+                .and_then(|reader| ChecksumAlgorithm::SHA256.hash_reader(reader))
+                .ok();
+        }
+    }
+    pub(crate) fn finish_audit(
+        &mut self,
+        (mut record, started): (AuditRecord, Instant),
+        outcome: Result<(), String>,
+    ) {
+        record.duration_ms = started.elapsed().as_millis() as u64;
+        match outcome {
+            Ok(()) => record.outcome = AuditOutcome::Success,
+            Err(e) => {
+                record.outcome = AuditOutcome::Failure;
+                record.error = Some(e);
+            }
+        }
+        let written = serde_json::to_string(&record)
+            .map_err(|e| e.to_string())
+            .and_then(|line| {
+                let mut file = std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(Self::audit_log_path())
+                    .map_err(|e| e.to_string())?;
+                writeln!(file, "{}", line).map_err(|e| e.to_string())
+            });
+        if let Err(e) = written {
+            self.add_log_at(LogLevel::Warn, format!("⚠️ Audit log write failed: {}", e));
+        }
+        if self.audit_log.is_open {
+            self.audit_log.records.push(record);
+        }
+    }
+    pub(crate) fn load_audit_log(&mut self) {
+        self.audit_log.records.clear();
+        self.audit_log.skipped_lines = 0;
+        self.audit_log.error_msg = None;
+        let file = match std::fs::File::open(Self::audit_log_path()) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return,
+            Err(e) => {
+                self.audit_log.error_msg = Some(e.to_string());
+                return;
+            }
+        };
+        for line in std::io::BufReader::new(file).lines() {
+            let Ok(line) = line else {
+                self.audit_log.skipped_lines += 1;
+                continue;
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<AuditRecord>(&line) {
+                Ok(record) => self.audit_log.records.push(record),
+                Err(_) => self.audit_log.skipped_lines += 1,
+            }
+        }
+    }
+    pub(crate) fn show_audit_log(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Operation:");
+            egui::ComboBox::from_id_salt("audit_operation_filter")
+                .selected_text(self.audit_log.operation_filter.map_or("All", |operation| operation.name()))
+                .show_ui(
+                    ui,
+                    |ui| {
+                        ui.selectable_value(&mut self.audit_log.operation_filter, None, "All");
+                        for operation in AuditOperation::ALL {
+                            ui.selectable_value(
+                                &mut self.audit_log.operation_filter,
+                                Some(operation),
+                                operation.name(),
+                            );
+                        }
+                    },
+                );
+            ui.label("Path:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.audit_log.path_filter)
+                    .hint_text("part of source or destination")
+                    .desired_width(240.0),
+            );
+            if ui.button("🔄 Reload").clicked() {
+                self.load_audit_log();
+            }
+        });
+        ui.label(format!("📄 {}", Self::audit_log_path().display()));
+        if let Some(error) = &self.audit_log.error_msg {
+            ui.colored_label(egui::Color32::RED, format!("❌ {}", error));
+        }
+        if self.audit_log.skipped_lines > 0 {
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                format!("⚠️ {} unreadable line(s) skipped", self.audit_log.skipped_lines),
+            );
+        }
+        let path_filter = self.audit_log.path_filter.to_lowercase();
+        let matches: Vec<&AuditRecord> = self
+            .audit_log
+            .records
+            .iter()
+            .rev()
+            .filter(|record| {
+                self.audit_log
+                    .operation_filter
+                    .is_none_or(|operation| record.operation == operation)
+            })
+            .filter(|record| {
+                path_filter.is_empty()
+                    || record.source.to_lowercase().contains(&path_filter)
+                    || record
+                        .destination
+                        .as_ref()
+                        .is_some_and(|d| d.to_lowercase().contains(&path_filter))
+            })
+            .collect();
+        ui.label(format!("{} of {} entries", matches.len(), self.audit_log.records.len()));
+        ui.separator();
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(
+                ui,
+                |ui| {
+                    egui::Grid::new("audit_log_grid")
+                        .striped(true)
+                        .num_columns(7)
+                        .show(
+                            ui,
+                            |ui| {
+                                for header in [
+                                    "Time",
+                                    "User",
+                                    "Operation",
+                                    "Source",
+                                    "Destination",
+                                    "Size",
+                                    "Outcome",
+                                ] {
+                                    ui.strong(header);
+                                }
+                                ui.end_row();
+                                for record in matches {
+                                    let time = chrono::DateTime::parse_from_rfc3339(&record.timestamp)
+                                        .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+                                        .unwrap_or_else(|_| record.timestamp.clone());
+                                    ui.label(time);
+                                    ui.label(format!("{}@{}", record.user, record.host));
+                                    ui.label(record.operation.name());
+                                    ui.label(&record.source);
+                                    ui.label(record.destination.as_deref().unwrap_or("—"));
+                                    let size = match (record.is_directory, record.size) {
+                                        (true, _) => "📁".to_string(),
+                                        (false, Some(size)) => self.format_file_size(size),
+                                        (false, None) => "—".to_string(),
+                                    };
+                                    let size_label = ui.label(size);
+                                    if let Some(hash) = &record.sha256 {
+                                        size_label.on_hover_text(format!("SHA-256 {}", hash));
+                                    }
+                                    let outcome = if record.outcome == AuditOutcome::Success {
+                                        ui.colored_label(egui::Color32::GREEN, "✅ success")
+                                    } else {
+                                        ui.colored_label(egui::Color32::RED, "❌ failure")
+                                    };
+                                    outcome.on_hover_text(
+                                        format!(
+                                            "{} ms{}",
+                                            record.duration_ms,
+                                            record
+                                                .error
+                                                .as_ref()
+                                                .map(|e| format!("\n{}", e))
+                                                .unwrap_or_default(),
+                                        ),
+                                    );
+                                    ui.end_row();
+                                }
+                            },
+                        );
+                },
+            );
+    }
+}
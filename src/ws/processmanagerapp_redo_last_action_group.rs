@@ -19,7 +19,7 @@ impl ProcessManagerApp {
         };
         if let Some(command) = command {
             let log_message = match &command {
-                FilepaneCommand::CopyFile { source, destination } => {
+                FilepaneCommand::CopyFile { source, destination, .. } => {
                     format!("Redo: Copy {} -> {}", source, destination)
                 }
                 FilepaneCommand::MoveFile { source, destination } => {
@@ -40,6 +40,15 @@ impl ProcessManagerApp {
                 FilepaneCommand::CalculateChecksum { path, algorithm } => {
                     format!("Redo: {} checksum for {}", algorithm.name(), path)
                 }
+                FilepaneCommand::CreateHardlink { target, link_path, .. } => {
+                    format!("Redo: Hardlink {} -> {}", link_path, target)
+                }
+                FilepaneCommand::CreateSymlink { target, link_path } => {
+                    format!("Redo: Symlink {} -> {}", link_path, target)
+                }
+                FilepaneCommand::Batch { commands } => {
+                    format!("Redo: Batch of {} operations", commands.len())
+                }
             };
             self.add_log(log_message);
             let tab = &mut self.filepane_tabs[self.filepane_active_tab];
@@ -1,4 +1,5 @@
 use std::time::Instant;
+use crate::ws::LogLevel;
 use crate::ws::WindowInfo;
 use crate::ws::ProcessInfo;
 use crate::ws::FileHandle;
@@ -19,6 +20,7 @@ impl ProcessManagerApp {
         self.refresh_file_handles();
         self.refresh_network_connections();
         self.update_history();
+        self.refresh_disk_io();
         self.last_update = Instant::now();
     }
     fn update_history(&mut self) {
@@ -111,28 +113,91 @@ impl ProcessManagerApp {
         }
         #[cfg(target_os = "linux")]
         {
-            for process in &self.processes {
-                if process.name.contains("firefox") || process.name.contains("chrome")
-                    || process.name.contains("code") || process.name.contains("terminal")
-                    || process.name.contains("nautilus")
-                    || process.name.contains("gedit")
-                {
-                    self.windows
-                        .push(WindowInfo {
-                            pid: process.pid,
-                            process_name: process.name.clone(),
-                            window_title: format!("{} - Window", process.name),
-                            window_id: process.pid as u64,
-                            is_foreground: false,
-                        });
+            if self.window_system.is_none() {
+                match crate::ws::LinuxWindowSystem::connect() {
+                    Ok(system) => {
+                        self.add_log(format!("🪟 Enumerating windows through {}", system.name()));
+                        self.window_system = Some(system);
+                        self.window_system_error = None;
+                    }
+                    Err(e) => {
+                        if self.window_system_error.as_ref() != Some(&e) {
+                            self.add_log_at(LogLevel::Warn, format!("⚠️ No window system available: {}", e));
+                            self.window_system_error = Some(e);
+                        }
+                        self.foreground_window_id = None;
+                        return;
+                    }
                 }
             }
-            if !self.windows.is_empty() {
-                self.foreground_window_id = Some(self.windows[0].window_id);
-                self.windows[0].is_foreground = true;
+            let listed = match self.window_system.as_mut() {
+                Some(system) => system.list_windows(),
+                None => return,
+            };
+            let listed = match listed {
+                Ok(listed) => listed,
+                Err(e) => {
+                    // Reconnect on the next refresh, e.g. after a compositor restart.
+                    self.add_log_at(LogLevel::Warn, format!("⚠️ Lost window system connection: {}", e));
+                    self.window_system = None;
+                    self.window_system_error = Some(e);
+                    self.foreground_window_id = None;
+                    return;
+                }
+            };
+            self.foreground_window_id = None;
+            for window in listed {
+                let process = match window.pid {
+                    Some(pid) => self.processes.iter().find(|p| p.pid == pid),
+                    None => Self::process_for_app_id(&self.processes, &window.class),
+                };
+                let process_name = match process {
+                    Some(process) => process.name.clone(),
+                    None if !window.class.is_empty() => window.class.clone(),
+                    None => format!("pid-{}", window.pid.unwrap_or(0)),
+                };
+                if window.is_active {
+                    self.foreground_window_id = Some(window.id);
+                }
+                self.windows
+                    .push(WindowInfo {
+                        pid: process.map(|p| p.pid).or(window.pid).unwrap_or(0),
+                        process_name,
+                        window_title: window.title,
+                        window_id: window.id,
+                        is_foreground: window.is_active,
+                    });
             }
         }
     }
+    /// Best guess at the process behind a Wayland app id such as
+    /// `org.gnome.Nautilus` or `firefox`, by executable or process name.
+    #[cfg(target_os = "linux")]
+    fn process_for_app_id<'a>(
+        processes: &'a [ProcessInfo],
+        app_id: &str,
+    ) -> Option<&'a ProcessInfo> {
+        if app_id.is_empty() {
+            return None;
+        }
+        let app_id = app_id.to_lowercase();
+        let short = app_id.rsplit('.').next().unwrap_or(&app_id).to_string();
+        let matches = |name: &str| {
+            let name = name.to_lowercase();
+            name == app_id || name == short
+        };
+        processes
+            .iter()
+            .filter(|process| {
+                matches(&process.name)
+                    || process
+                        .exe_path
+                        .as_deref()
+                        .and_then(|path| std::path::Path::new(path).file_name())
+                        .is_some_and(|name| matches(&name.to_string_lossy()))
+            })
+            .min_by_key(|process| process.pid)
+    }
     pub fn refresh_processes(&mut self) {
         self.system.refresh_cpu_all();
         self.system.refresh_memory();
@@ -143,12 +208,32 @@ impl ProcessManagerApp {
             .filter(|w| w.is_foreground)
             .map(|w| w.pid)
             .collect();
+        // Rates come from the cumulative counters (`/proc/<pid>/io` on
+        // Linux) rather than sysinfo's per-refresh deltas, so they stay
+        // correct when refreshes are irregular.
+        let now = Instant::now();
+        let interval = self
+            .disks
+            .processes_sampled_at
+            .map(|sampled| now.duration_since(sampled).as_secs_f64())
+            .filter(|seconds| *seconds > 0.0);
+        let previous_totals = std::mem::take(&mut self.disks.process_totals);
+        let mut io_totals = HashMap::new();
+        let rate = |current: u64, previous: Option<u64>| match (previous, interval) {
+            (Some(previous), Some(seconds)) => {
+                (current.saturating_sub(previous) as f64 / seconds) as u64
+            }
+            _ => 0,
+        };
         self.processes = self
             .system
             .processes()
             .iter()
             .map(|(pid, process)| {
                 let pid_u32 = pid.as_u32();
+                let usage = process.disk_usage();
+                let previous = previous_totals.get(&pid_u32);
+                io_totals.insert(pid_u32, (usage.total_read_bytes, usage.total_written_bytes));
                 ProcessInfo {
                     pid: pid_u32,
                     name: process.name().to_string_lossy().to_string(),
@@ -159,9 +244,13 @@ impl ProcessManagerApp {
                     run_time: process.run_time(),
                     is_foreground: foreground_pids.contains(&pid_u32),
                     exe_path: process.exe().map(|p| p.to_string_lossy().to_string()),
+                    disk_read_rate: rate(usage.total_read_bytes, previous.map(|p| p.0)),
+                    disk_write_rate: rate(usage.total_written_bytes, previous.map(|p| p.1)),
                 }
             })
             .collect();
+        self.disks.process_totals = io_totals;
+        self.disks.processes_sampled_at = Some(now);
         self.sort_processes();
     }
     fn refresh_file_handles(&mut self) {
@@ -1,9 +1,28 @@
 use sysinfo::System;
 use std::time::Instant;
 use std::time::Duration;
+use crate::ws::LogLevel;
 use crate::ws::SortColumn;
 use crate::ws::ViewMode;
 use crate::ws::FontPickerState;
+use crate::ws::FileViewerState;
+use crate::ws::FileSearchState;
+use crate::ws::DiskUsageState;
+use crate::ws::DuplicateFinderState;
+use crate::ws::PropertiesEditorState;
+use crate::ws::DirectoryWatchState;
+use crate::ws::FilepaneKeyboardState;
+use crate::ws::QuickJumpState;
+use crate::ws::LauncherIndexState;
+use crate::ws::ProcessIconCache;
+use crate::ws::ServicesState;
+use crate::ws::AutostartState;
+use crate::ws::DisksState;
+use crate::ws::SftpConnectState;
+use crate::ws::AuditLogState;
+use crate::ws::ThumbnailPipeline;
+use crate::ws::HistoryBrowserState;
+use crate::ws::LogViewState;
 use crate::ws::FilepaneTab;
 // # ProcessManagerApp - restart_as_admin_group Methods
 //
@@ -15,7 +34,6 @@ use std::collections::{HashMap, HashSet};
 use super::processmanagerapp_type::ProcessManagerApp;
 
 use egui;
-use crate::ws::types;
 
 impl ProcessManagerApp {
     pub fn restart_as_admin() -> Result<(), String> {
@@ -84,20 +102,42 @@ impl ProcessManagerApp {
             custom_refresh_input: String::from("2"),
             show_refresh_input: false,
             foreground_window_id: None,
+            #[cfg(target_os = "linux")]
+            window_system: None,
+            window_system_error: None,
             items_per_page: 50,
             current_page: 0,
             show_window_grid: false,
+            selected_window_ids: HashSet::new(),
+            window_layouts: config.window_layouts,
+            window_layout_name: String::new(),
             program_path: String::new(),
             program_args: String::new(),
-            logs: Vec::new(),
-            selected_log_indices: HashSet::new(),
+            program_working_dir: String::new(),
+            program_env: String::new(),
+            program_capture: true,
+            program_restart: false,
+            program_profile_name: String::new(),
+            managed_processes: Vec::new(),
+            next_managed_id: 1,
+            selected_console: None,
+            program_groups: config.program_groups,
+            group_launches: Vec::new(),
+            group_editor: None,
+            logs: std::collections::VecDeque::new(),
+            selected_log_ids: HashSet::new(),
+            log_view: LogViewState {
+                settings: config.logging.clone(),
+                ..LogViewState::default()
+            },
             run_as_admin: false,
             custom_programs: config.programs,
             taskbar_page: 0,
             live_page: 0,
             live_thumbnails: HashMap::new(),
-            live_current_capture_index: 0,
-            live_last_capture: Instant::now(),
+            live_pipeline: ThumbnailPipeline::start(
+                std::thread::available_parallelism().map_or(2, |n| n.get() / 2).clamp(1, 4),
+            ),
             live_capture_interval: Duration::from_millis(200),
             live_paused: false,
             live_grid_size: config.live_grid_size,
@@ -109,6 +149,30 @@ impl ProcessManagerApp {
             use_noto_font: config.use_noto,
             font_path: config.font_path,
             font_picker: FontPickerState::default(),
+            file_viewer: FileViewerState::default(),
+            file_search: FileSearchState::default(),
+            disk_usage: DiskUsageState::default(),
+            duplicate_finder: DuplicateFinderState::default(),
+            properties_editor: PropertiesEditorState::default(),
+            directory_watch: DirectoryWatchState::default(),
+            filepane_keymap: config.keymap,
+            filepane_keyboard: FilepaneKeyboardState::default(),
+            filepane_bookmarks: config.bookmarks,
+            filepane_recent_dirs: config.recent_dirs,
+            filepane_recent_dirs_changed: false,
+            quick_jump: QuickJumpState::default(),
+            launcher_index: LauncherIndexState::default(),
+            process_icons: ProcessIconCache::start(),
+            services: ServicesState::default(),
+            autostart: AutostartState::default(),
+            disks: DisksState::default(),
+            sftp_connect: SftpConnectState::default(),
+            audit_log: AuditLogState::default(),
+            history_browser: HistoryBrowserState::default(),
+            filepane_backend_jobs: Vec::new(),
+            filepane_copy_batches: Vec::new(),
+            filepane_next_batch_id: 0,
+            sftp_sessions: std::collections::HashMap::new(),
             filepane_tabs: vec![
                 FilepaneTab::new("Tab 1".to_string(), std::env::current_dir()
                 .unwrap_or_else(| _ | std::path::PathBuf::from("C:\\")).to_string_lossy()
@@ -117,6 +181,7 @@ impl ProcessManagerApp {
             ],
             filepane_active_tab: 0,
             filepane_swap_columns: false,
+            filepane_one_filesystem: true,
             filepane_config_path: "filepane_config.ini".to_string(),
             context_menu_file: None,
             show_context_menu: false,
@@ -128,9 +193,17 @@ impl ProcessManagerApp {
             filepane_pending_operation: None,
             filepane_operation_history: Vec::new(),
             filepane_trash_items: Vec::new(),
+            filepane_dir_sizes: HashMap::new(),
+            filepane_size_receiver: None,
         };
         app.configure_fonts(&cc.egui_ctx, false);
+        let _ = log::set_logger(&crate::ws::APP_LOGGER);
+        app.apply_log_settings();
         app.add_log("WinShell started".to_string());
+        for warning in config.warnings {
+            app.add_log_at(LogLevel::Warn, warning);
+        }
+        app.prune_history_files();
         app.refresh_all_data();
         app
     }
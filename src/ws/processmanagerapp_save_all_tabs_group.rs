@@ -1,3 +1,4 @@
+use crate::ws::LogLevel;
 use crate::ws::FileInfo;
 // # ProcessManagerApp - save_all_tabs_group Methods
 //
@@ -9,12 +10,20 @@ use std::collections::{HashMap, HashSet};
 use super::processmanagerapp_type::ProcessManagerApp;
 use crate::ws::FilepaneCommand;
 use crate::ws::TrashItem;
+use crate::ws::{TraversalGuard, TraversalSkip};
+use crate::ws::{copy_limited, same_contents};
+use crate::ws::{is_remote_path, FileSystemBackend, LocalBackend, SftpTarget};
+use crate::ws::{AuditRecord, BackendJob};
+use std::io::Write;
+use std::sync::Arc;
+use std::time::Instant;
 
 // use windows::Win32::Shell::SHFILEOPSTRUCTW;
 // use windows::Win32::Shell::FO_DELETE;
 // use windows::Win32::Shell::FOF_ALLOWUNDO;
 // use windows::Win32::Shell::SHFileOperationW;
 
+#[cfg(target_os = "windows")]
 use windows::Win32::UI::Shell::{
     SHFILEOPSTRUCTW, SHFileOperationW, FO_DELETE, FOF_ALLOWUNDO, FOF_NOCONFIRMATION,
 };
@@ -148,6 +157,7 @@ impl ProcessManagerApp {
                                         if let Some(action) = confirm_action {
                                             self.add_log("🔥 Final confirmation received - executing operation".to_string());
                                             self.execute_real_command(&action);
+                                            self.prune_duplicate_groups();
                                         }
                                         self.filepane_show_confirm = false;
                                         self.filepane_second_confirm = false;
@@ -179,120 +189,509 @@ impl ProcessManagerApp {
         }
     }
     pub fn show_file_properties(&mut self, file_info: &FileInfo) {
-        let file_type = if file_info.is_directory { "Directory" } else { "File" };
-        let modified_time = file_info
-            .modified
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
-        let formatted_time = std::time::UNIX_EPOCH
-            + std::time::Duration::from_secs(modified_time);
-        self.add_log(format!("=== Properties for {} ===", file_info.name));
-        self.add_log(format!("Type: {}", file_type));
-        self.add_log(format!("Size: {} bytes", file_info.size));
-        self.add_log(format!("Modified: {:?}", formatted_time));
-        self.add_log(format!("Path: {}", file_info.path));
-        if let Some(ref ext) = file_info.extension {
-            self.add_log(format!("Extension: {}", ext));
-        }
-        self.add_log("=================================".to_string());
+        self.open_properties_editor(&file_info.path);
     }
     pub fn execute_real_command(&mut self, command: &FilepaneCommand) {
-        match command {
-            FilepaneCommand::CopyFile { source, destination } => {
-                self.real_copy_file(source, destination);
+        if let FilepaneCommand::Batch { commands } = command {
+            self.add_log(format!("📦 BATCH: Running {} operations", commands.len()));
+            for inner in commands {
+                self.execute_real_command(inner);
+            }
+        } else if let Some(mut audit) = self.begin_audit(command) {
+            if Self::command_touches_remote(command) || Self::command_is_throttled(command) {
+                self.start_backend_job(command, audit, None);
+            } else {
+                Self::inspect_audit_source(&LocalBackend, &mut audit.0);
+                let outcome = match command {
+                    FilepaneCommand::DeleteFile { path } => self.real_delete_file(path),
+                    _ => self.execute_backend_command(command),
+                };
+                self.finish_audit(audit, outcome);
+                self.invalidate_dir_sizes(command);
+            }
+        } else {
+            self.execute_command(command);
+            self.invalidate_dir_sizes(command);
+        }
+
+        // Add to operation history
+        if let Some(ref operation) = self.filepane_pending_operation {
+            self.filepane_operation_history.push(operation.clone());
+        }
+        self.filepane_pending_operation = None;
+    }
+
+    /// Drops cached folder sizes that `command` may have changed: every
+    /// path it touched, the folders containing them and anything inside.
+    fn invalidate_dir_sizes(&mut self, command: &FilepaneCommand) {
+        let touched = match command {
+            FilepaneCommand::CopyFile { source, destination, .. } => {
+                vec![Self::destination_entry(source, destination)]
             }
             FilepaneCommand::MoveFile { source, destination } => {
-                self.real_move_file(source, destination);
+                vec![source.clone(), Self::destination_entry(source, destination)]
             }
-            FilepaneCommand::DeleteFile { path } => {
-                self.real_delete_file(path);
+            FilepaneCommand::DeleteFile { path } | FilepaneCommand::CreateDirectory { path } => {
+                vec![path.clone()]
             }
-            FilepaneCommand::CreateDirectory { path } => {
-                self.real_create_directory(path);
+            FilepaneCommand::RenameFile { old_path, new_path } => {
+                vec![old_path.clone(), new_path.clone()]
+            }
+            FilepaneCommand::CreateHardlink { link_path, .. }
+            | FilepaneCommand::CreateSymlink { link_path, .. } => vec![link_path.clone()],
+            _ => return,
+        };
+        self.filepane_dir_sizes.retain(|cached, _| {
+            let cached = std::path::Path::new(cached);
+            !touched.iter().any(|path| {
+                let path = std::path::Path::new(path);
+                path.starts_with(cached) || cached.starts_with(path)
+            })
+        });
+    }
+
+    /// What deleting `paths` means, for confirmation dialogs: local items go
+    /// to the trash, items on an SFTP server are gone for good.
+    pub(crate) fn delete_consequence(paths: &[String]) -> &'static str {
+        match (
+            paths.iter().any(|path| is_remote_path(path)),
+            paths.iter().all(|path| is_remote_path(path)),
+        ) {
+            (false, _) => "This will move items to trash and can be undone.",
+            (true, true) => "Items on an SFTP server are deleted permanently and cannot be undone.",
+            (true, false) => {
+                "Local items go to trash and can be undone; items on an SFTP server are deleted permanently."
+            }
+        }
+    }
+
+    fn command_touches_remote(command: &FilepaneCommand) -> bool {
+        match command {
+            FilepaneCommand::CopyFile { source, destination, .. }
+            | FilepaneCommand::MoveFile { source, destination } => {
+                is_remote_path(source) || is_remote_path(destination)
+            }
+            FilepaneCommand::DeleteFile { path } | FilepaneCommand::CreateDirectory { path } => {
+                is_remote_path(path)
             }
             FilepaneCommand::RenameFile { old_path, new_path } => {
-                self.real_rename_file(old_path, new_path);
+                is_remote_path(old_path) || is_remote_path(new_path)
             }
-            _ => {
-                self.execute_command(command);
+            FilepaneCommand::CreateHardlink { target, link_path, .. }
+            | FilepaneCommand::CreateSymlink { target, link_path } => {
+                is_remote_path(target) || is_remote_path(link_path)
             }
+            _ => false,
         }
+    }
 
-        // Add to operation history
-        if let Some(ref operation) = self.filepane_pending_operation {
-            self.filepane_operation_history.push(operation.clone());
+    /// Speed-limited copies sleep between chunks, so they run on a worker
+    /// thread even when both sides are local.
+    fn command_is_throttled(command: &FilepaneCommand) -> bool {
+        matches!(command, FilepaneCommand::CopyFile { speed_limit: Some(_), .. })
+    }
+
+    /// Keeps a copy or move of a local tree on one filesystem when that
+    /// option is set; remote sources have nothing to guard.
+    fn command_guard(&self, command: &FilepaneCommand) -> Option<TraversalGuard> {
+        match command {
+            FilepaneCommand::CopyFile { source, .. } | FilepaneCommand::MoveFile { source, .. }
+                if !is_remote_path(source) =>
+            {
+                Some(TraversalGuard::new(std::path::Path::new(source), self.filepane_one_filesystem))
+            }
+            _ => None,
         }
-        self.filepane_pending_operation = None;
     }
 
-    fn real_copy_file(&mut self, source: &str, destination: &str) {
-        let source_path = std::path::Path::new(source);
-        let dest_path = std::path::Path::new(destination).join(
-            source_path.file_name().unwrap_or_default()
-        );
+    /// The backends serving the first and second path of `command`.
+    fn command_backends(
+        &self,
+        command: &FilepaneCommand,
+    ) -> std::io::Result<(Arc<dyn FileSystemBackend>, Arc<dyn FileSystemBackend>)> {
+        let (first, second) = match command {
+            FilepaneCommand::CopyFile { source, destination, .. }
+            | FilepaneCommand::MoveFile { source, destination } => (source, destination),
+            FilepaneCommand::RenameFile { old_path, new_path } => (old_path, new_path),
+            FilepaneCommand::CreateHardlink { target, link_path, .. }
+            | FilepaneCommand::CreateSymlink { target, link_path } => (link_path, target),
+            FilepaneCommand::DeleteFile { path } | FilepaneCommand::CreateDirectory { path } => {
+                (path, path)
+            }
+            _ => return Ok((Arc::new(LocalBackend), Arc::new(LocalBackend))),
+        };
+        Ok((self.filesystem(first)?, self.filesystem(second)?))
+    }
 
-        self.add_log(format!("📋 COPY: Starting copy operation"));
-        self.add_log(format!("   Source: {}", source));
-        self.add_log(format!("   Destination: {}", dest_path.display()));
+    /// Logs what `command` is about to do; the result is logged when it
+    /// finishes.
+    fn log_backend_command(&mut self, command: &FilepaneCommand) {
+        let message = match command {
+            FilepaneCommand::CopyFile { source, destination, .. } => {
+                format!("📋 COPY: {} -> {}", source, Self::destination_entry(source, destination))
+            }
+            FilepaneCommand::MoveFile { source, destination } => {
+                format!("✂️ MOVE: {} -> {}", source, Self::destination_entry(source, destination))
+            }
+            FilepaneCommand::DeleteFile { path } => format!("🗑️ DELETE: {} (permanent)", path),
+            FilepaneCommand::CreateDirectory { path } => format!("📁 CREATE: {}", path),
+            FilepaneCommand::RenameFile { old_path, new_path } => {
+                format!("🏷️ RENAME: {} -> {}", old_path, new_path)
+            }
+            FilepaneCommand::CreateHardlink { target, link_path, .. } => {
+                format!("🔗 HARDLINK: {} -> {}", link_path, target)
+            }
+            FilepaneCommand::CreateSymlink { target, link_path } => {
+                format!("🔗 SYMLINK: {} -> {}", link_path, target)
+            }
+            _ => return,
+        };
+        self.add_log(message);
+    }
 
-        match if source_path.is_dir() {
-            self.copy_directory(source_path, &dest_path)
-        } else {
-            self.copy_file(source_path, &dest_path)
-        } {
-            Ok(_) => {
-                self.add_log(format!("✅ Successfully copied to {}", dest_path.display()));
+    /// Runs a local command through `LocalBackend`, the same code path
+    /// remote panels use.
+    fn execute_backend_command(&mut self, command: &FilepaneCommand) -> Result<(), String> {
+        self.log_backend_command(command);
+        let mut skipped = Vec::new();
+        let guard = self.command_guard(command);
+        let result = self
+            .command_backends(command)
+            .and_then(|(first, second)| {
+                Self::run_backend_command(&*first, &*second, command, guard, &mut skipped)
+            });
+        for (path, skip) in skipped {
+            self.log_traversal_skip(&path, skip);
+        }
+        self.report_backend_result(result.map_err(|e| e.to_string()))
+    }
+
+    fn report_backend_result(&mut self, result: Result<String, String>) -> Result<(), String> {
+        match result {
+            Ok(message) => {
+                self.add_log(format!("✅ {}", message));
+                Ok(())
             }
             Err(e) => {
-                self.add_log(format!("❌ Copy failed: {}", e));
+                self.add_log_at(LogLevel::Error, format!("❌ Operation failed: {}", e));
+                Err(e)
             }
         }
     }
 
-    fn copy_file(&self, source: &std::path::Path, destination: &std::path::Path) -> std::io::Result<()> {
-        std::fs::copy(source, destination)?;
-        Ok(())
+    /// Starts a command on an SFTP panel, or a speed-limited copy, on a
+    /// worker thread; its result is picked up by `poll_backend_jobs`.
+    /// Remote deletes are permanent: servers have no trash to restore from.
+    pub(crate) fn start_backend_job(
+        &mut self,
+        command: &FilepaneCommand,
+        audit: (AuditRecord, Instant),
+        batch: Option<u64>,
+    ) {
+        self.log_backend_command(command);
+        let (first, second) = match self.command_backends(command) {
+            Ok(backends) => backends,
+            Err(e) => {
+                let outcome = self.report_backend_result(Err(e.to_string()));
+                self.count_batch_outcome(batch, outcome.is_ok());
+                self.finish_audit(audit, outcome);
+                return;
+            }
+        };
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let job_command = command.clone();
+        let guard = self.command_guard(command);
+        let mut record = audit.0.clone();
+        std::thread::spawn(move || {
+            Self::inspect_audit_source(&*first, &mut record);
+            let mut skipped = Vec::new();
+            let result = Self::run_backend_command(&*first, &*second, &job_command, guard, &mut skipped);
+            let _ = sender.send((record, result.map_err(|e| e.to_string()), skipped));
+        });
+        self.filepane_backend_jobs
+            .push(BackendJob {
+                command: command.clone(),
+                audit,
+                batch,
+                receiver,
+            });
     }
 
-    fn copy_directory(&self, source: &std::path::Path, destination: &std::path::Path) -> std::io::Result<()> {
-        std::fs::create_dir_all(destination)?;
-        for entry in std::fs::read_dir(source)? {
-            let entry = entry?;
-            let file_type = entry.file_type()?;
-            let source_path = entry.path();
-            let dest_path = destination.join(entry.file_name());
+    /// Counts a finished job towards its batch and logs the summary once
+    /// the whole batch is done.
+    fn count_batch_outcome(&mut self, batch: Option<u64>, succeeded: bool) {
+        let Some(index) = self.filepane_copy_batches.iter().position(|b| Some(b.id) == batch) else {
+            return;
+        };
+        let entry = &mut self.filepane_copy_batches[index];
+        if succeeded {
+            entry.succeeded += 1;
+        } else {
+            entry.failed += 1;
+        }
+        if entry.succeeded + entry.failed < entry.total {
+            return;
+        }
+        let entry = self.filepane_copy_batches.remove(index);
+        if entry.failed == 0 {
+            self.add_log(format!("✅ Batch copy finished: {} of {} copied", entry.succeeded, entry.total));
+        } else {
+            self.add_log_at(
+                LogLevel::Warn,
+                format!(
+                    "⚠️ Batch copy finished: {} of {} copied, {} failed",
+                    entry.succeeded, entry.total, entry.failed
+                ),
+            );
+        }
+    }
 
-            if file_type.is_dir() {
-                self.copy_directory(&source_path, &dest_path)?;
-            } else {
-                std::fs::copy(&source_path, &dest_path)?;
+    pub(crate) fn poll_backend_jobs(&mut self, ctx: &egui::Context) {
+        let mut index = 0;
+        while index < self.filepane_backend_jobs.len() {
+            let (record, result, skipped) = match self.filepane_backend_jobs[index].receiver.try_recv() {
+                Ok((record, result, skipped)) => (Some(record), result, skipped),
+                Err(std::sync::mpsc::TryRecvError::Empty) => {
+                    index += 1;
+                    continue;
+                }
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    (None, Err("worker thread stopped".to_string()), Vec::new())
+                }
+            };
+            let job = self.filepane_backend_jobs.remove(index);
+            let (started_record, started) = job.audit;
+            for (path, skip) in skipped {
+                self.log_traversal_skip(&path, skip);
             }
+            let outcome = self.report_backend_result(result);
+            self.count_batch_outcome(job.batch, outcome.is_ok());
+            self.finish_audit((record.unwrap_or(started_record), started), outcome);
+            self.invalidate_remote_listings();
+            self.invalidate_dir_sizes(&job.command);
+        }
+        if !self.filepane_backend_jobs.is_empty() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
         }
-        Ok(())
     }
 
-    fn real_move_file(&mut self, source: &str, destination: &str) {
-        let source_path = std::path::Path::new(source);
-        let dest_path = std::path::Path::new(destination).join(
-            source_path.file_name().unwrap_or_default()
-        );
+    /// Performs `command` with `first` serving its source (or only) path
+    /// and `second` its destination, returning a summary for the log.
+    fn run_backend_command(
+        first: &dyn FileSystemBackend,
+        second: &dyn FileSystemBackend,
+        command: &FilepaneCommand,
+        mut guard: Option<TraversalGuard>,
+        skipped: &mut Vec<(std::path::PathBuf, TraversalSkip)>,
+    ) -> std::io::Result<String> {
+        match command {
+            FilepaneCommand::CopyFile { source, destination, speed_limit } => {
+                let dest_path = Self::destination_entry(source, destination);
+                let bytes_per_sec = speed_limit.map(|mb| mb * 1024.0 * 1024.0);
+                let bytes = Self::transfer_tree(first, source, second, &dest_path, bytes_per_sec, &mut guard, skipped)?;
+                Ok(format!("Copied {} bytes to {}", bytes, dest_path))
+            }
+            FilepaneCommand::MoveFile { source, destination } => {
+                let dest_path = Self::destination_entry(source, destination);
+                let same_server = SftpTarget::parse(source).map(|(target, _)| target)
+                    == SftpTarget::parse(&dest_path).map(|(target, _)| target);
+                let renamed = if same_server { first.rename(source, &dest_path) } else {
+                    Err(std::io::ErrorKind::CrossesDevices.into())
+                };
+                match renamed {
+                    Ok(()) => {}
+                    // Across filesystems or servers: copy, then delete.
+                    Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+                        Self::transfer_tree(first, source, second, &dest_path, None, &mut guard, skipped)?;
+                        Self::remove_backend_tree(first, source, &mut None)?;
+                    }
+                    Err(e) => return Err(e),
+                }
+                Ok(format!("Moved to {}", dest_path))
+            }
+            FilepaneCommand::DeleteFile { path } => {
+                Self::remove_backend_tree(first, path, &mut None)?;
+                Ok(format!("Deleted {}", path))
+            }
+            FilepaneCommand::CreateDirectory { path } => {
+                first.create_dir_all(path)?;
+                Ok(format!("Created directory {}", path))
+            }
+            FilepaneCommand::RenameFile { old_path, new_path } => {
+                first.rename(old_path, new_path)?;
+                Ok(format!("Renamed to {}", new_path))
+            }
+            FilepaneCommand::CreateHardlink { target, link_path, replace } => {
+                first.hard_link(target, link_path, *replace)?;
+                Ok(format!("Hardlinked {}", link_path))
+            }
+            FilepaneCommand::CreateSymlink { target, link_path } => {
+                // The link stores the target as the server will see it.
+                let target = SftpTarget::parse(target)
+                    .map(|(_, remote)| remote)
+                    .unwrap_or_else(|| target.clone());
+                first.symlink(&target, link_path)?;
+                Ok(format!("Created symlink {}", link_path))
+            }
+            other => {
+                Err(
+                    std::io::Error::new(
+                        std::io::ErrorKind::Unsupported,
+                        format!("{:?} is not a filesystem operation", other),
+                    ),
+                )
+            }
+        }
+    }
 
-        self.add_log(format!("✂️ MOVE: Starting move operation"));
-        self.add_log(format!("   Source: {}", source));
-        self.add_log(format!("   Destination: {}", dest_path.display()));
+    /// `destination` is a directory; the entry keeps the source's name.
+    pub(crate) fn destination_entry(source: &str, destination: &str) -> String {
+        let name = std::path::Path::new(&source.replace('\\', "/"))
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        format!("{}/{}", destination.trim_end_matches(['/', '\\']), name)
+    }
 
-        match std::fs::rename(source, &dest_path) {
-            Ok(_) => {
-                self.add_log(format!("✅ Successfully moved to {}", dest_path.display()));
+    /// Copies a file, link or directory tree between two backends and
+    /// returns the number of bytes written. Links are recreated rather than
+    /// followed, so the walk cannot loop; a `guard` additionally keeps a
+    /// local walk on one filesystem, recording what it skipped. File data
+    /// moves at no more than `bytes_per_sec` when given.
+    pub(crate) fn transfer_tree(
+        from: &dyn FileSystemBackend,
+        source: &str,
+        to: &dyn FileSystemBackend,
+        destination: &str,
+        bytes_per_sec: Option<f64>,
+        guard: &mut Option<TraversalGuard>,
+        skipped: &mut Vec<(std::path::PathBuf, TraversalSkip)>,
+    ) -> std::io::Result<u64> {
+        let info = from.stat(source)?;
+        if info.is_symlink() {
+            to.symlink(&from.read_link(source)?, destination)?;
+            return Ok(0);
+        }
+        if !info.is_directory {
+            let mut reader = std::io::BufReader::with_capacity(64 * 1024, from.open_read(source)?);
+            let mut writer = to.create_file(destination)?;
+            let bytes = copy_limited(&mut reader, &mut writer, bytes_per_sec)?;
+            writer.flush()?;
+            return Ok(bytes);
+        }
+        if let Some(guard) = guard.as_mut() {
+            if let Err(skip) = guard.enter(std::path::Path::new(source)) {
+                skipped.push((std::path::PathBuf::from(source), skip));
+                return Ok(0);
             }
-            Err(e) => {
-                self.add_log(format!("❌ Move failed: {}", e));
+        }
+        to.create_dir_all(destination)?;
+        let mut total = 0;
+        for entry in from.read_dir(source)? {
+            let entry_destination = format!(
+                "{}/{}", destination.trim_end_matches(['/', '\\']), entry.name
+            );
+            total += Self::transfer_tree(from, &entry.path, to, &entry_destination, bytes_per_sec, guard, skipped)?;
+        }
+        Ok(total)
+    }
+
+    /// Deletes a tree without following symlinks. With a `guard`, mount
+    /// points below `path` are refused so another filesystem is never
+    /// emptied by accident.
+    fn remove_backend_tree(
+        backend: &dyn FileSystemBackend,
+        path: &str,
+        guard: &mut Option<TraversalGuard>,
+    ) -> std::io::Result<()> {
+        let info = backend.stat(path)?;
+        if !info.is_directory || info.is_symlink() {
+            return backend.remove_file(path);
+        }
+        if let Some(guard) = guard.as_mut() {
+            if guard.enter(std::path::Path::new(path)).is_err() {
+                return Err(std::io::Error::other(format!("refusing to delete {}", path)));
             }
         }
+        for entry in backend.read_dir(path)? {
+            Self::remove_backend_tree(backend, &entry.path, guard)?;
+        }
+        backend.remove_dir(path)
     }
 
-    fn real_delete_file(&mut self, path: &str) {
+    /// Walks a local tree the way a one-filesystem copy would, collecting
+    /// every directory it would have to skip.
+    #[cfg(not(target_os = "windows"))]
+    fn find_untransferable(
+        path: &str,
+        guard: &mut TraversalGuard,
+        skipped: &mut Vec<(std::path::PathBuf, TraversalSkip)>,
+    ) -> std::io::Result<()> {
+        let metadata = std::fs::symlink_metadata(path)?;
+        if !metadata.is_dir() {
+            return Ok(());
+        }
+        if let Err(skip) = guard.enter(std::path::Path::new(path)) {
+            skipped.push((std::path::PathBuf::from(path), skip));
+            return Ok(());
+        }
+        for entry in std::fs::read_dir(path)? {
+            Self::find_untransferable(&entry?.path().to_string_lossy(), guard, skipped)?;
+        }
+        Ok(())
+    }
+
+    /// Whether the local tree `copy` holds the same entries, link targets
+    /// and file contents as `original`.
+    #[cfg(not(target_os = "windows"))]
+    fn same_tree(original: &str, copy: &str) -> std::io::Result<bool> {
+        let original_metadata = std::fs::symlink_metadata(original)?;
+        let copy_metadata = std::fs::symlink_metadata(copy)?;
+        if original_metadata.file_type().is_symlink() {
+            return Ok(copy_metadata.file_type().is_symlink()
+                && std::fs::read_link(original)? == std::fs::read_link(copy)?);
+        }
+        if original_metadata.is_dir() != copy_metadata.is_dir() {
+            return Ok(false);
+        }
+        if !original_metadata.is_dir() {
+            return Ok(original_metadata.len() == copy_metadata.len() && same_contents(original, copy)?);
+        }
+        let names = |dir: &str| -> std::io::Result<std::collections::BTreeSet<std::ffi::OsString>> {
+            std::fs::read_dir(dir)?.map(|entry| entry.map(|entry| entry.file_name())).collect()
+        };
+        let entries = names(original)?;
+        if entries != names(copy)? {
+            return Ok(false);
+        }
+        for name in entries {
+            let original = std::path::Path::new(original).join(&name);
+            let copy = std::path::Path::new(copy).join(&name);
+            if !Self::same_tree(&original.to_string_lossy(), &copy.to_string_lossy())? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    pub(crate) fn log_traversal_skip(&mut self, path: &std::path::Path, skip: TraversalSkip) {
+        match skip {
+            TraversalSkip::Cycle => {
+                self.add_log(format!("🔁 Skipped {}: already visited (link cycle)", path.display()));
+            }
+            TraversalSkip::OtherFilesystem => {
+                self.add_log(format!("🧱 Skipped {}: on another filesystem", path.display()));
+            }
+            TraversalSkip::Unreadable(kind) => {
+                self.add_log_at(
+                    LogLevel::Warn,
+                    format!("⚠️ Skipped {}: cannot read its metadata ({})", path.display(), kind),
+                );
+            }
+        }
+    }
+
+    fn real_delete_file(&mut self, path: &str) -> Result<(), String> {
         self.add_log(format!("🗑️ DELETE: Starting delete operation"));
         self.add_log(format!("   Path: {}", path));
 
@@ -307,14 +706,16 @@ impl ProcessManagerApp {
                 };
                 self.filepane_trash_items.push(trash_item);
                 self.add_log(format!("✅ Moved to trash: {}", trash_path));
+                Ok(())
             }
             Err(e) => {
-                self.add_log(format!("❌ Delete failed: {}", e));
+                self.add_log_at(LogLevel::Error, format!("❌ Delete failed: {}", e));
+                Err(e.to_string())
             }
         }
     }
 
-    fn move_to_trash(&self, path: &str) -> Result<String, Box<dyn std::error::Error>> {
+    fn move_to_trash(&mut self, path: &str) -> Result<String, Box<dyn std::error::Error>> {
         let source_path = std::path::Path::new(path);
 
         #[cfg(target_os = "windows")]
@@ -368,40 +769,49 @@ impl ProcessManagerApp {
                 .unwrap_or_default()
                 .as_secs();
 
-            let trash_name = format!("{}_{}", timestamp, file_name);
-            let trash_path = std::path::Path::new(&trash_dir).join(trash_name);
-
-            std::fs::rename(path, &trash_path)?;
-            Ok(trash_path.to_string_lossy().to_string())
-        }
-    }
-
-    fn real_create_directory(&mut self, path: &str) {
-        self.add_log(format!("📁 CREATE: Creating directory"));
-        self.add_log(format!("   Path: {}", path));
-
-        match std::fs::create_dir_all(path) {
-            Ok(_) => {
-                self.add_log(format!("✅ Successfully created directory: {}", path));
+            // Several files can be trashed within the same second.
+            let mut trash_path = std::path::Path::new(&trash_dir).join(format!("{}_{}", timestamp, file_name));
+            let mut attempt = 1;
+            while trash_path.symlink_metadata().is_ok() {
+                trash_path = std::path::Path::new(&trash_dir)
+                    .join(format!("{}_{}_{}", timestamp, attempt, file_name));
+                attempt += 1;
             }
-            Err(e) => {
-                self.add_log(format!("❌ Create directory failed: {}", e));
-            }
-        }
-    }
 
-    fn real_rename_file(&mut self, old_path: &str, new_path: &str) {
-        self.add_log(format!("🏷️ RENAME: Starting rename operation"));
-        self.add_log(format!("   From: {}", old_path));
-        self.add_log(format!("   To: {}", new_path));
-
-        match std::fs::rename(old_path, new_path) {
-            Ok(_) => {
-                self.add_log(format!("✅ Successfully renamed to {}", new_path));
-            }
-            Err(e) => {
-                self.add_log(format!("❌ Rename failed: {}", e));
+            let trash_path = trash_path.to_string_lossy().to_string();
+            match LocalBackend.rename(path, &trash_path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+                    // The trash lives on another filesystem: copy, verify,
+                    // then delete. Anything the copy would have to leave
+                    // behind (a nested mount, an unreadable directory) stops
+                    // the move up front rather than half-deleting the source.
+                    let mut skipped = Vec::new();
+                    Self::find_untransferable(path, &mut TraversalGuard::new(source_path, true), &mut skipped)?;
+                    if let Some((skipped_path, skip)) = skipped.into_iter().next() {
+                        self.log_traversal_skip(&skipped_path, skip);
+                        return Err(format!(
+                            "cannot move {} to the trash on another filesystem: {} could not be copied",
+                            path, skipped_path.display()
+                        ).into());
+                    }
+                    let mut skipped = Vec::new();
+                    let mut guard = Some(TraversalGuard::new(source_path, true));
+                    let copied = Self::transfer_tree(&LocalBackend, path, &LocalBackend, &trash_path, None, &mut guard, &mut skipped)
+                        .and_then(|_| Self::same_tree(path, &trash_path));
+                    if !matches!(copied, Ok(true)) || !skipped.is_empty() {
+                        let _ = Self::remove_backend_tree(&LocalBackend, &trash_path, &mut None);
+                        return Err(match copied {
+                            Err(e) => format!("copy to trash failed: {}", e),
+                            Ok(_) => format!("copy of {} in the trash does not match; source kept", path),
+                        }.into());
+                    }
+                    let mut guard = Some(TraversalGuard::new(source_path, true));
+                    Self::remove_backend_tree(&LocalBackend, path, &mut guard)?;
+                }
+                Err(e) => return Err(e.into()),
             }
+            Ok(trash_path)
         }
     }
 
@@ -417,14 +827,14 @@ impl ProcessManagerApp {
                     true
                 }
                 Err(e) => {
-                    self.add_log(format!("❌ Restore failed: {}", e));
+                    self.add_log_at(LogLevel::Error, format!("❌ Restore failed: {}", e));
                     // Put it back in trash if restore failed
                     self.filepane_trash_items.push(trash_item);
                     false
                 }
             }
         } else {
-            self.add_log("❌ No items in trash to restore".to_string());
+            self.add_log_at(LogLevel::Error, "❌ No items in trash to restore".to_string());
             false
         }
     }
@@ -442,7 +852,7 @@ impl ProcessManagerApp {
                     self.add_log("✅ File opened successfully".to_string());
                 }
                 Err(e) => {
-                    self.add_log(format!("❌ Failed to open file: {}", e));
+                    self.add_log_at(LogLevel::Error, format!("❌ Failed to open file: {}", e));
                 }
             }
         }
@@ -453,7 +863,7 @@ impl ProcessManagerApp {
                     self.add_log("✅ File opened successfully".to_string());
                 }
                 Err(e) => {
-                    self.add_log(format!("❌ Failed to open file: {}", e));
+                    self.add_log_at(LogLevel::Error, format!("❌ Failed to open file: {}", e));
                 }
             }
         }
@@ -464,7 +874,7 @@ impl ProcessManagerApp {
                     self.add_log("✅ File opened successfully".to_string());
                 }
                 Err(e) => {
-                    self.add_log(format!("❌ Failed to open file: {}", e));
+                    self.add_log_at(LogLevel::Error, format!("❌ Failed to open file: {}", e));
                 }
             }
         }
@@ -485,9 +895,10 @@ impl ProcessManagerApp {
         match self.save_conversation_history(&conversation_text) {
             Ok(message) => {
                 self.add_log(format!("✅ {}", message));
+                self.prune_history_files();
             }
             Err(error) => {
-                self.add_log(format!("❌ {}", error));
+                self.add_log_at(LogLevel::Error, format!("❌ {}", error));
             }
         }
     }
@@ -16,11 +16,7 @@ impl ProcessManagerApp {
         let now = chrono::Local::now();
         let timestamp = now.format("%Y%m%d_%H%M%S").to_string();
         let filename = format!("history_{}.txt", timestamp);
-        let winshell_path = std::env::current_exe()
-            .ok()
-            .and_then(|path| path.parent().map(|p| p.to_path_buf()))
-            .unwrap_or_else(|| std::path::PathBuf::from("."));
-        let file_path = winshell_path.join(&filename);
+        let file_path = Self::history_directory().join(&filename);
         let mut content = format!(
             "WinShell Conversation History\n\
             =============================\n\
@@ -0,0 +1,692 @@
+use crate::ws::LogLevel;
+use crate::ws::AutostartEntry;
+use crate::ws::AutostartSource;
+use crate::ws::CustomProgram;
+use crate::ws::ViewMode;
+// # ProcessManagerApp - show_autostart_view_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Disabling never deletes anything: XDG entries get `Hidden=true` in a
+// per-user copy, systemd units are disabled through systemctl, and Windows
+// entries are marked in the `StartupApproved` keys Task Manager uses, so
+// every change can be undone from here or from the desktop's own tools.
+
+use std::path::{Path, PathBuf};
+use super::processmanagerapp_type::ProcessManagerApp;
+
+#[cfg(target_os = "linux")]
+fn user_autostart_directory() -> PathBuf {
+    match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(config) if !config.is_empty() => PathBuf::from(config).join("autostart"),
+        _ => dirs::home_dir().unwrap_or_default().join(".config/autostart"),
+    }
+}
+
+/// User directory first; a file there hides the same file name in the
+/// system directories.
+#[cfg(target_os = "linux")]
+fn autostart_directories() -> Vec<PathBuf> {
+    let system = std::env::var("XDG_CONFIG_DIRS")
+        .ok()
+        .filter(|dirs| !dirs.is_empty())
+        .unwrap_or_else(|| "/etc/xdg".to_string());
+    std::iter::once(user_autostart_directory())
+        .chain(system.split(':').filter(|dir| !dir.is_empty()).map(|dir| Path::new(dir).join("autostart")))
+        .collect()
+}
+
+/// Quotes a word for a desktop entry `Exec` line.
+#[cfg(target_os = "linux")]
+fn quote_exec_word(word: &str) -> String {
+    let word = word.replace('%', "%%");
+    if word.is_empty() || word.chars().any(|c| c.is_whitespace() || "\"'\\`$;&|<>()*?#~".contains(c)) {
+        let mut quoted = String::from("\"");
+        for ch in word.chars() {
+            if matches!(ch, '"' | '`' | '$' | '\\') {
+                quoted.push('\\');
+            }
+            quoted.push(ch);
+        }
+        quoted.push('"');
+        quoted
+    } else {
+        word
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn scan_xdg_autostart(entries: &mut Vec<AutostartEntry>) {
+    let mut seen = std::collections::HashSet::new();
+    for directory in autostart_directories() {
+        let Ok(read_dir) = std::fs::read_dir(&directory) else {
+            continue;
+        };
+        let mut files: Vec<PathBuf> = read_dir
+            .flatten()
+            .map(|item| item.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "desktop"))
+            .collect();
+        files.sort();
+        for path in files {
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            if !seen.insert(file_name.clone()) {
+                continue;
+            }
+            let Ok(content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let mut fields = std::collections::HashMap::new();
+            let mut in_entry = false;
+            for line in content.lines() {
+                let line = line.trim();
+                if line.starts_with('[') {
+                    in_entry = line == "[Desktop Entry]";
+                } else if let Some((key, value)) = line.split_once('=').filter(|_| in_entry) {
+                    fields.entry(key.trim().to_string()).or_insert_with(|| value.trim().to_string());
+                }
+            }
+            let field = |key: &str| fields.get(key).map(String::as_str).unwrap_or("");
+            let command = crate::ws::split_desktop_exec(field("Exec"))
+                .iter()
+                .map(|word| if word.contains(' ') { format!("\"{}\"", word) } else { word.clone() })
+                .collect::<Vec<_>>()
+                .join(" ");
+            entries
+                .push(AutostartEntry {
+                    name: if field("Name").is_empty() { file_name.clone() } else { field("Name").to_string() },
+                    command,
+                    location: path.to_string_lossy().to_string(),
+                    source: AutostartSource::XdgAutostart,
+                    enabled: field("Hidden") != "true" && field("X-GNOME-Autostart-enabled") != "false",
+                    main_pid: None,
+                });
+        }
+    }
+}
+
+/// Enabled and disabled user units that can be enabled for the session;
+/// static and masked units are not autostart entries.
+#[cfg(target_os = "linux")]
+fn scan_systemd_user_units(entries: &mut Vec<AutostartEntry>) -> Result<(), String> {
+    #[derive(serde::Deserialize)]
+    struct UnitFile {
+        unit_file: String,
+        state: String,
+    }
+    let listing = crate::ws::systemctl(true, &["list-unit-files", "--type=service", "--output=json"])?;
+    let units: Vec<UnitFile> = serde_json::from_str(&listing)
+        .map_err(|e| format!("unexpected systemctl output: {}", e))?;
+    let units: Vec<UnitFile> = units
+        .into_iter()
+        .filter(|unit| unit.state.starts_with("enabled") || unit.state == "disabled")
+        .filter(|unit| !unit.unit_file.contains("@."))
+        .collect();
+    if units.is_empty() {
+        return Ok(());
+    }
+    let mut args = vec!["show", "--property=Id,ExecStart,MainPID", "--"];
+    args.extend(units.iter().map(|unit| unit.unit_file.as_str()));
+    let details = crate::ws::systemctl(true, &args)?;
+    let mut by_name = std::collections::HashMap::new();
+    for block in details.split("\n\n") {
+        let properties: std::collections::HashMap<&str, &str> = block
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .collect();
+        if let Some(id) = properties.get("Id") {
+            by_name.insert(id.to_string(), properties);
+        }
+    }
+    for unit in units {
+        let properties = by_name.get(&unit.unit_file);
+        // ExecStart looks like `{ path=/usr/bin/x ; argv[]=/usr/bin/x -a ; ... }`.
+        let command = properties
+            .and_then(|properties| properties.get("ExecStart"))
+            .and_then(|exec| exec.split("argv[]=").nth(1))
+            .map(|argv| argv.split(" ;").next().unwrap_or("").trim().to_string())
+            .unwrap_or_default();
+        entries
+            .push(AutostartEntry {
+                name: unit.unit_file.trim_end_matches(".service").to_string(),
+                command,
+                location: unit.unit_file.clone(),
+                source: AutostartSource::SystemdUser,
+                enabled: unit.state.starts_with("enabled"),
+                main_pid: properties
+                    .and_then(|properties| properties.get("MainPID"))
+                    .and_then(|pid| pid.parse().ok())
+                    .filter(|pid| *pid != 0),
+            });
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn scan_autostart() -> (Vec<AutostartEntry>, Vec<String>) {
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    scan_xdg_autostart(&mut entries);
+    if let Err(e) = scan_systemd_user_units(&mut entries) {
+        errors.push(format!("systemd user units: {}", e));
+    }
+    (entries, errors)
+}
+
+/// Writes `Hidden=` and `X-GNOME-Autostart-enabled=` into the user's copy
+/// of the entry, creating the copy from a system entry when needed.
+#[cfg(target_os = "linux")]
+fn set_xdg_enabled(entry: &AutostartEntry, enabled: bool) -> Result<(), String> {
+    let source = Path::new(&entry.location);
+    let directory = user_autostart_directory();
+    let target = directory.join(source.file_name().ok_or("entry has no file name")?);
+    let content = std::fs::read_to_string(source).map_err(|e| e.to_string())?;
+    let mut lines = Vec::new();
+    let mut in_entry = false;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_entry = trimmed == "[Desktop Entry]";
+            lines.push(line.to_string());
+            if in_entry {
+                lines.push(format!("Hidden={}", !enabled));
+                lines.push(format!("X-GNOME-Autostart-enabled={}", enabled));
+            }
+            continue;
+        }
+        if in_entry && (trimmed.starts_with("Hidden=") || trimmed.starts_with("X-GNOME-Autostart-enabled=")) {
+            continue;
+        }
+        lines.push(line.to_string());
+    }
+    std::fs::create_dir_all(&directory).map_err(|e| e.to_string())?;
+    std::fs::write(&target, lines.join("\n") + "\n").map_err(|e| e.to_string())
+}
+
+#[cfg(target_os = "linux")]
+fn set_autostart_enabled(entry: &AutostartEntry, enabled: bool) -> Result<(), String> {
+    match entry.source {
+        AutostartSource::SystemdUser => {
+            crate::ws::systemctl(true, &[if enabled { "enable" } else { "disable" }, "--", &entry.location])
+                .map(|_| ())
+        }
+        _ => set_xdg_enabled(entry, enabled),
+    }
+}
+
+/// Creates `~/.config/autostart/<name>.desktop` running the profile.
+#[cfg(target_os = "linux")]
+fn add_autostart(profile: &CustomProgram) -> Result<String, String> {
+    let file_stem: String = profile
+        .name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+        .collect();
+    let directory = user_autostart_directory();
+    let path = directory.join(format!("{}.desktop", file_stem));
+    if path.exists() {
+        return Err(format!("{} already exists", path.display()));
+    }
+    let args = shlex::split(&profile.args).ok_or("the arguments have an unbalanced quote")?;
+    let mut words = Vec::new();
+    if !profile.env.is_empty() {
+        words.push("env".to_string());
+        words.extend(profile.env.iter().map(|(key, value)| quote_exec_word(&format!("{}={}", key, value))));
+    }
+    words.push(quote_exec_word(&profile.path));
+    words.extend(args.iter().map(|arg| quote_exec_word(arg)));
+    let mut content = format!(
+        "[Desktop Entry]\nType=Application\nName={}\nExec={}\nX-GNOME-Autostart-enabled=true\n",
+        profile.name, words.join(" ")
+    );
+    if !profile.working_dir.is_empty() {
+        content.push_str(&format!("Path={}\n", profile.working_dir));
+    }
+    std::fs::create_dir_all(&directory).map_err(|e| e.to_string())?;
+    std::fs::write(&path, content).map_err(|e| e.to_string())?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(windows)]
+const RUN_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Run";
+#[cfg(windows)]
+const APPROVED_RUN_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\StartupApproved\\Run";
+#[cfg(windows)]
+const APPROVED_FOLDER_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\StartupApproved\\StartupFolder";
+
+#[cfg(windows)]
+fn wide(text: &str) -> Vec<u16> {
+    text.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+#[cfg(windows)]
+fn registry_root(machine: bool) -> windows::Win32::System::Registry::HKEY {
+    use windows::Win32::System::Registry::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+    if machine { HKEY_LOCAL_MACHINE } else { HKEY_CURRENT_USER }
+}
+
+/// Values of a registry key as (name, raw data) pairs.
+#[cfg(windows)]
+fn registry_values(machine: bool, path: &str) -> Vec<(String, Vec<u8>)> {
+    use windows::core::{PCWSTR, PWSTR};
+    use windows::Win32::Foundation::ERROR_MORE_DATA;
+    use windows::Win32::System::Registry::{RegCloseKey, RegEnumValueW, RegOpenKeyExW, HKEY, KEY_READ};
+    let mut values = Vec::new();
+    let path = wide(path);
+    unsafe {
+        let mut key = HKEY::default();
+        if RegOpenKeyExW(registry_root(machine), PCWSTR::from_raw(path.as_ptr()), 0, KEY_READ, &mut key)
+            .is_err()
+        {
+            return values;
+        }
+        // Value names are at most 16383 characters; data has no fixed limit,
+        // so a value that does not fit is retried with the size it reported.
+        let mut name = vec![0u16; 16384];
+        let mut data = vec![0u8; 4096];
+        let mut index = 0;
+        loop {
+            let mut name_len = name.len() as u32;
+            let mut data_len = data.len() as u32;
+            let result = RegEnumValueW(
+                key,
+                index,
+                PWSTR::from_raw(name.as_mut_ptr()),
+                &mut name_len,
+                None,
+                None,
+                Some(data.as_mut_ptr()),
+                Some(&mut data_len),
+            );
+            if result == ERROR_MORE_DATA {
+                data.resize((data_len as usize).max(data.len() * 2), 0);
+                continue;
+            }
+            if result.is_err() {
+                break;
+            }
+            values.push((String::from_utf16_lossy(&name[..name_len as usize]), data[..data_len as usize].to_vec()));
+            index += 1;
+        }
+        let _ = RegCloseKey(key);
+    }
+    values
+}
+
+#[cfg(windows)]
+fn registry_string(data: &[u8]) -> String {
+    let units: Vec<u16> = data.chunks_exact(2).map(|pair| u16::from_le_bytes([pair[0], pair[1]])).collect();
+    let end = units.iter().position(|unit| *unit == 0).unwrap_or(units.len());
+    String::from_utf16_lossy(&units[..end])
+}
+
+#[cfg(windows)]
+fn registry_set(
+    machine: bool,
+    path: &str,
+    name: &str,
+    kind: windows::Win32::System::Registry::REG_VALUE_TYPE,
+    data: &[u8],
+) -> Result<(), String> {
+    use windows::core::PCWSTR;
+    use windows::Win32::System::Registry::{
+        RegCloseKey, RegCreateKeyExW, RegSetValueExW, HKEY, KEY_WRITE, REG_OPTION_NON_VOLATILE,
+    };
+    let path = wide(path);
+    let name = wide(name);
+    unsafe {
+        let mut key = HKEY::default();
+        RegCreateKeyExW(
+            registry_root(machine),
+            PCWSTR::from_raw(path.as_ptr()),
+            0,
+            PCWSTR::null(),
+            REG_OPTION_NON_VOLATILE,
+            KEY_WRITE,
+            None,
+            &mut key,
+            None,
+        )
+            .ok()
+            .map_err(|e| e.to_string())?;
+        let result = RegSetValueExW(key, PCWSTR::from_raw(name.as_ptr()), 0, kind, Some(data))
+            .ok()
+            .map_err(|e| e.to_string());
+        let _ = RegCloseKey(key);
+        result
+    }
+}
+
+/// `StartupApproved` values start with an even byte when enabled and an
+/// odd one when disabled; a missing value means enabled.
+#[cfg(windows)]
+fn approved_states(machine: bool, path: &str) -> std::collections::HashMap<String, bool> {
+    registry_values(machine, path)
+        .into_iter()
+        .map(|(name, data)| (name, data.first().is_none_or(|flag| flag % 2 == 0)))
+        .collect()
+}
+
+#[cfg(windows)]
+fn scan_autostart() -> (Vec<AutostartEntry>, Vec<String>) {
+    let mut entries = Vec::new();
+    for machine in [false, true] {
+        let approved = approved_states(machine, APPROVED_RUN_KEY);
+        for (name, data) in registry_values(machine, RUN_KEY) {
+            entries
+                .push(AutostartEntry {
+                    enabled: approved.get(&name).copied().unwrap_or(true),
+                    command: registry_string(&data),
+                    location: format!(
+                        "{}\\{}\\{}", if machine { "HKLM" } else { "HKCU" }, RUN_KEY, name
+                    ),
+                    source: AutostartSource::RunKey { machine },
+                    name,
+                    main_pid: None,
+                });
+        }
+        let folder_variable = if machine { "ProgramData" } else { "APPDATA" };
+        let Some(base) = std::env::var_os(folder_variable) else {
+            continue;
+        };
+        let folder = PathBuf::from(base).join("Microsoft\\Windows\\Start Menu\\Programs\\Startup");
+        let approved = approved_states(machine, APPROVED_FOLDER_KEY);
+        let Ok(read_dir) = std::fs::read_dir(&folder) else {
+            continue;
+        };
+        for item in read_dir.flatten() {
+            let path = item.path();
+            let file_name = item.file_name().to_string_lossy().to_string();
+            if file_name.eq_ignore_ascii_case("desktop.ini") {
+                continue;
+            }
+            let command = if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("lnk")) {
+                match crate::ws::resolve_shortcut(&path) {
+                    Some((target, args, _)) if args.is_empty() => format!("\"{}\"", target),
+                    Some((target, args, _)) => format!("\"{}\" {}", target, args),
+                    None => format!("\"{}\"", path.display()),
+                }
+            } else {
+                format!("\"{}\"", path.display())
+            };
+            entries
+                .push(AutostartEntry {
+                    name: path.file_stem().unwrap_or_default().to_string_lossy().to_string(),
+                    command,
+                    location: path.to_string_lossy().to_string(),
+                    source: AutostartSource::StartupFolder { machine },
+                    enabled: approved.get(&file_name).copied().unwrap_or(true),
+                    main_pid: None,
+                });
+        }
+    }
+    (entries, Vec::new())
+}
+
+#[cfg(windows)]
+fn set_autostart_enabled(entry: &AutostartEntry, enabled: bool) -> Result<(), String> {
+    use windows::Win32::System::Registry::REG_BINARY;
+    let (machine, key, value) = match entry.source {
+        AutostartSource::RunKey { machine } => (machine, APPROVED_RUN_KEY, entry.name.clone()),
+        AutostartSource::StartupFolder { machine } => {
+            let file_name = Path::new(&entry.location).file_name().unwrap_or_default();
+            (machine, APPROVED_FOLDER_KEY, file_name.to_string_lossy().to_string())
+        }
+        _ => return Err("not a Windows startup entry".to_string()),
+    };
+    let mut data = [0u8; 12];
+    data[0] = if enabled { 2 } else { 3 };
+    registry_set(machine, key, &value, REG_BINARY, &data)
+}
+
+/// Adds a value under the current user's Run key, refusing to replace one
+/// that is already there.
+#[cfg(windows)]
+fn add_autostart(profile: &CustomProgram) -> Result<String, String> {
+    use windows::Win32::System::Registry::REG_SZ;
+    let location = format!("HKCU\\{}\\{}", RUN_KEY, profile.name);
+    if registry_values(false, RUN_KEY).iter().any(|(name, _)| name.eq_ignore_ascii_case(&profile.name)) {
+        return Err(format!("{} already exists", location));
+    }
+    let command = if profile.args.is_empty() {
+        format!("\"{}\"", profile.path)
+    } else {
+        format!("\"{}\" {}", profile.path, profile.args)
+    };
+    let data: Vec<u8> = wide(&command).iter().flat_map(|unit| unit.to_le_bytes()).collect();
+    registry_set(false, RUN_KEY, &profile.name, REG_SZ, &data)?;
+    Ok(location)
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn scan_autostart() -> (Vec<AutostartEntry>, Vec<String>) {
+    (Vec::new(), vec!["startup entries are not supported on this platform".to_string()])
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn set_autostart_enabled(_entry: &AutostartEntry, _enabled: bool) -> Result<(), String> {
+    Err("startup entries are not supported on this platform".to_string())
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn add_autostart(_profile: &CustomProgram) -> Result<String, String> {
+    Err("startup entries are not supported on this platform".to_string())
+}
+
+impl ProcessManagerApp {
+    pub(crate) fn refresh_autostart(&mut self) {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(scan_autostart());
+        });
+        self.autostart.loader = Some(receiver);
+    }
+    /// The running process for an entry: its main PID when the service
+    /// manager knows it, otherwise a process with the same executable name.
+    fn autostart_process(&self, entry: &AutostartEntry) -> Option<u32> {
+        if let Some(pid) = entry.main_pid {
+            return self.processes.iter().any(|p| p.pid == pid).then_some(pid);
+        }
+        let program = entry.program();
+        let program = Path::new(&program).file_name()?.to_string_lossy().to_lowercase();
+        if program.is_empty() {
+            return None;
+        }
+        let stem = program.trim_end_matches(".exe");
+        self.processes
+            .iter()
+            .find(|process| {
+                let exe_name = process
+                    .exe_path
+                    .as_deref()
+                    .and_then(|path| Path::new(path).file_name())
+                    .map(|name| name.to_string_lossy().to_lowercase());
+                exe_name.as_deref() == Some(program.as_str())
+                    || process.name.to_lowercase().trim_end_matches(".exe") == stem
+            })
+            .map(|process| process.pid)
+    }
+    fn toggle_autostart(&mut self, index: usize) {
+        let Some(entry) = self.autostart.entries.get(index).cloned() else {
+            return;
+        };
+        let enabled = !entry.enabled;
+        match set_autostart_enabled(&entry, enabled) {
+            Ok(()) => {
+                self.add_log(
+                    format!("🚦 {} startup entry '{}'", if enabled { "Enabled" } else { "Disabled" }, entry.name),
+                );
+                self.refresh_autostart();
+            }
+            Err(e) => self.add_log_at(LogLevel::Error, format!("❌ Could not change startup entry '{}': {}", entry.name, e)),
+        }
+    }
+    fn add_profile_to_autostart(&mut self, index: usize) {
+        let Some(profile) = self.custom_programs.get(index).cloned() else {
+            return;
+        };
+        match add_autostart(&profile) {
+            Ok(location) => {
+                self.add_log(format!("🚦 '{}' now starts at login ({})", profile.name, location));
+                self.refresh_autostart();
+            }
+            Err(e) => self.add_log_at(LogLevel::Error, format!("❌ Could not add '{}' to startup: {}", profile.name, e)),
+        }
+    }
+    /// Opens an entry in the New view's launch form, where it can be
+    /// adjusted and saved as a profile.
+    fn edit_autostart_entry(&mut self, entry: &AutostartEntry) {
+        let program = entry.program();
+        let args = entry
+            .command
+            .trim()
+            .strip_prefix(&format!("\"{}\"", program))
+            .or_else(|| entry.command.trim().strip_prefix(&program))
+            .unwrap_or("")
+            .trim()
+            .to_string();
+        self.edit_launch_profile(&CustomProgram {
+            name: entry.name.clone(),
+            path: program,
+            args,
+            ..CustomProgram::default()
+        });
+        self.view_mode = ViewMode::New;
+    }
+    pub(crate) fn show_autostart_view(&mut self, ui: &mut egui::Ui) {
+        if !self.autostart.loaded && self.autostart.loader.is_none() {
+            self.refresh_autostart();
+        }
+        if let Some(loader) = &self.autostart.loader {
+            match loader.try_recv() {
+                Ok((entries, errors)) => {
+                    self.autostart.entries = entries;
+                    self.autostart.errors = errors;
+                    self.autostart.loader = None;
+                    self.autostart.loaded = true;
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => {
+                    ui.ctx().request_repaint_after(std::time::Duration::from_millis(100));
+                }
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    self.autostart.loader = None;
+                    self.autostart.loaded = true;
+                }
+            }
+        }
+        let mut add = None;
+        ui.horizontal(|ui| {
+            ui.heading("🚦 Startup");
+            ui.separator();
+            if self.autostart.loader.is_some() {
+                ui.spinner();
+            } else if ui.button("🔄 Refresh").clicked() {
+                self.refresh_autostart();
+            }
+            ui.separator();
+            ui.label("Add at login:");
+            let selected = self
+                .autostart
+                .add_program
+                .and_then(|index| self.custom_programs.get(index))
+                .map_or("choose profile", |program| program.name.as_str());
+            egui::ComboBox::from_id_salt("autostart_add_program")
+                .selected_text(selected)
+                .show_ui(
+                    ui,
+                    |ui| {
+                        for (index, program) in self.custom_programs.iter().enumerate() {
+                            ui.selectable_value(&mut self.autostart.add_program, Some(index), &program.name);
+                        }
+                    },
+                );
+            if ui
+                .add_enabled(self.autostart.add_program.is_some(), egui::Button::new("➕ Add"))
+                .clicked()
+            {
+                add = self.autostart.add_program.take();
+            }
+            if self.custom_programs.is_empty() {
+                ui.weak("(save a launch profile in 🚀 New first)");
+            }
+        });
+        for error in &self.autostart.errors {
+            ui.colored_label(egui::Color32::YELLOW, format!("⚠️ {}", error));
+        }
+        ui.separator();
+        let filter_lower = self.search_filter.to_lowercase();
+        let mut toggle = None;
+        let mut edit = None;
+        let mut show_process = None;
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(
+                ui,
+                |ui| {
+                    egui::Grid::new("autostart_grid")
+                        .striped(true)
+                        .num_columns(6)
+                        .show(
+                            ui,
+                            |ui| {
+                                for header in ["Enabled", "Name", "Source", "Command", "Running", ""] {
+                                    ui.strong(header);
+                                }
+                                ui.end_row();
+                                for (index, entry) in self.autostart.entries.iter().enumerate() {
+                                    if !filter_lower.is_empty()
+                                        && !entry.name.to_lowercase().contains(&filter_lower)
+                                        && !entry.command.to_lowercase().contains(&filter_lower)
+                                    {
+                                        continue;
+                                    }
+                                    let mut enabled = entry.enabled;
+                                    if ui.checkbox(&mut enabled, "").changed() {
+                                        toggle = Some(index);
+                                    }
+                                    ui.label(&entry.name).on_hover_text(&entry.location);
+                                    ui.label(entry.source.label());
+                                    ui.label(&entry.command);
+                                    match self.autostart_process(entry) {
+                                        Some(pid) => {
+                                            if ui
+                                                .link(format!("🟢 {}", pid))
+                                                .on_hover_text("Show in Processes")
+                                                .clicked()
+                                            {
+                                                show_process = Some(pid);
+                                            }
+                                        }
+                                        None => {
+                                            ui.weak("—");
+                                        }
+                                    }
+                                    if ui
+                                        .small_button("✏")
+                                        .on_hover_text("Open in the launch form")
+                                        .clicked()
+                                    {
+                                        edit = Some(entry.clone());
+                                    }
+                                    ui.end_row();
+                                }
+                            },
+                        );
+                },
+            );
+        if let Some(index) = add {
+            self.add_profile_to_autostart(index);
+        }
+        if let Some(index) = toggle {
+            self.toggle_autostart(index);
+        }
+        if let Some(entry) = edit {
+            self.edit_autostart_entry(&entry);
+        }
+        if let Some(pid) = show_process {
+            self.selected_pid = Some(pid);
+            self.search_filter.clear();
+            self.view_mode = ViewMode::Processes;
+        }
+    }
+}
@@ -0,0 +1,703 @@
+use crate::ws::DiskUsageMessage;
+use crate::ws::DiskUsageNode;
+use crate::ws::DiskUsageReport;
+use crate::ws::TraversalGuard;
+use crate::ws::ViewMode;
+// # ProcessManagerApp - show_disk_usage_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Directory trees are scanned on a background thread; the finished report
+// also feeds `filepane_dir_sizes` so the Filepane listing shows folder sizes.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Sender, TryRecvError};
+use std::sync::Arc;
+use super::processmanagerapp_type::ProcessManagerApp;
+
+const MAX_TOP_FILES: usize = 100;
+const PROGRESS_EVERY_FILES: u64 = 500;
+const TREEMAP_HEIGHT: f32 = 320.0;
+const TREEMAP_COLORS: &[egui::Color32] = &[
+    egui::Color32::from_rgb(70, 130, 180),
+    egui::Color32::from_rgb(60, 160, 110),
+    egui::Color32::from_rgb(200, 140, 60),
+    egui::Color32::from_rgb(170, 90, 160),
+    egui::Color32::from_rgb(190, 80, 80),
+    egui::Color32::from_rgb(90, 160, 170),
+    egui::Color32::from_rgb(150, 150, 70),
+    egui::Color32::from_rgb(110, 110, 190),
+];
+
+struct ScanProgress {
+    scanned_files: u64,
+    scanned_bytes: u64,
+    unreadable_entries: usize,
+    largest_files: Vec<(String, u64)>,
+    guard: TraversalGuard,
+}
+
+impl ProcessManagerApp {
+    pub fn open_disk_usage(&mut self) {
+        if self.filepane_active_tab >= self.filepane_tabs.len() {
+            return;
+        }
+        if !self.disk_usage.is_running {
+            let panel = if self.filepane_swap_columns { 1 } else { 0 };
+            let tab = &self.filepane_tabs[self.filepane_active_tab];
+            self.disk_usage.source_panel = panel;
+            self.disk_usage.root_path = if panel == 0 {
+                tab.left_path.clone()
+            } else {
+                tab.right_path.clone()
+            };
+        }
+        self.disk_usage.is_open = true;
+    }
+    pub(crate) fn show_disk_usage(&mut self, ctx: &egui::Context) {
+        self.poll_disk_usage();
+        if self.disk_usage.is_running {
+            ctx.request_repaint();
+        }
+        let mut open = self.disk_usage.is_open;
+        let mut jump_to: Option<(String, Option<String>)> = None;
+        egui::Window::new("📊 Disk Usage")
+            .open(&mut open)
+            .default_size([860.0, 640.0])
+            .min_width(600.0)
+            .resizable(true)
+            .show(
+                ctx,
+                |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("Directory:");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.disk_usage.root_path)
+                                .desired_width(360.0),
+                        );
+                        if self.filepane_active_tab < self.filepane_tabs.len() {
+                            let tab = &self.filepane_tabs[self.filepane_active_tab];
+                            if ui.button("Left").clicked() {
+                                self.disk_usage.root_path = tab.left_path.clone();
+                                self.disk_usage.source_panel = 0;
+                            }
+                            if ui.button("Right").clicked() {
+                                self.disk_usage.root_path = tab.right_path.clone();
+                                self.disk_usage.source_panel = 1;
+                            }
+                        }
+                        ui.separator();
+                        if self.disk_usage.is_running {
+                            if ui.button("⏹ Stop").clicked() {
+                                if let Some(flag) = &self.disk_usage.cancel_flag {
+                                    flag.store(true, Ordering::Relaxed);
+                                }
+                            }
+                            ui.spinner();
+                            ui.label(
+                                format!(
+                                    "{} files, {}", self.disk_usage.scanned_files, self
+                                    .format_file_size(self.disk_usage.scanned_bytes)
+                                ),
+                            );
+                        } else if ui.button("▶ Scan").clicked() {
+                            self.start_disk_usage_scan();
+                        }
+                    });
+                    if let Some(error) = &self.disk_usage.error_msg {
+                        ui.colored_label(egui::Color32::RED, format!("❌ {}", error));
+                    }
+                    let Some(report) = self.disk_usage.report.as_ref() else {
+                        if !self.disk_usage.is_running {
+                            ui.label("Scan a directory to see where the space goes");
+                        }
+                        return;
+                    };
+                    let focus_node = Self::disk_usage_node_at(
+                        &report.root,
+                        &self.disk_usage.focus,
+                    );
+                    let mut new_focus = None;
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add_enabled(
+                                !self.disk_usage.focus.is_empty(),
+                                egui::Button::new("⬆ Up"),
+                            )
+                            .clicked()
+                        {
+                            let mut focus = self.disk_usage.focus.clone();
+                            focus.pop();
+                            new_focus = Some(focus);
+                        }
+                        ui.label(
+                            egui::RichText::new(
+                                    format!(
+                                        "{}  ·  {}  ·  {} files", focus_node.path, self
+                                        .format_file_size(focus_node.size), focus_node
+                                        .file_count
+                                    ),
+                                )
+                                .strong(),
+                        );
+                        if ui.button("📂 Open in Filepane").clicked() {
+                            jump_to = Some((focus_node.path.clone(), None));
+                        }
+                    });
+                    ui.label(
+                        egui::RichText::new(
+                                format!(
+                                    "Scanned in {:.1}s{}  ·  Click a block to drill down, right-click to open it in the Filepane",
+                                    report.elapsed.as_secs_f32(), if report
+                                    .unreadable_entries > 0 { format!(" ({} unreadable entries)",
+                                    report.unreadable_entries) } else { String::new() }
+                                ),
+                            )
+                            .small(),
+                    );
+                    let (rect, _) = ui
+                        .allocate_exact_size(
+                            egui::vec2(ui.available_width(), TREEMAP_HEIGHT),
+                            egui::Sense::hover(),
+                        );
+                    let children_total: u64 = focus_node
+                        .children
+                        .iter()
+                        .map(|c| c.size)
+                        .sum();
+                    let loose_files = focus_node.size.saturating_sub(children_total);
+                    let mut values: Vec<u64> = focus_node
+                        .children
+                        .iter()
+                        .map(|c| c.size)
+                        .collect();
+                    values.push(loose_files);
+                    let rects = Self::layout_treemap(&values, rect);
+                    let painter = ui.painter_at(rect);
+                    for (index, block) in rects.iter().enumerate() {
+                        if block.width() < 1.0 || block.height() < 1.0 {
+                            continue;
+                        }
+                        let is_files_block = index == focus_node.children.len();
+                        let (label, size) = if is_files_block {
+                            ("(files)".to_string(), loose_files)
+                        } else {
+                            let child = &focus_node.children[index];
+                            (child.name.clone(), child.size)
+                        };
+                        let color = if is_files_block {
+                            egui::Color32::from_gray(110)
+                        } else {
+                            TREEMAP_COLORS[index % TREEMAP_COLORS.len()]
+                        };
+                        let response = ui
+                            .interact(
+                                *block,
+                                ui.id().with(("treemap_block", index)),
+                                egui::Sense::click(),
+                            );
+                        let fill = if response.hovered() {
+                            color.gamma_multiply(1.3)
+                        } else {
+                            color
+                        };
+                        painter.rect_filled(block.shrink(1.0), 2.0, fill);
+                        if block.width() > 60.0 && block.height() > 18.0 {
+                            painter
+                                .with_clip_rect(block.shrink(3.0))
+                                .text(
+                                    block.left_top() + egui::vec2(4.0, 3.0),
+                                    egui::Align2::LEFT_TOP,
+                                    format!("{}\n{}", label, self.format_file_size(size)),
+                                    egui::FontId::proportional(12.0),
+                                    egui::Color32::WHITE,
+                                );
+                        }
+                        if is_files_block {
+                            response
+                                .on_hover_text(
+                                    format!("Files directly in this folder: {}", self.format_file_size(size)),
+                                );
+                            continue;
+                        }
+                        let child = &focus_node.children[index];
+                        if response.clicked() && !child.children.is_empty() {
+                            let mut focus = self.disk_usage.focus.clone();
+                            focus.push(index);
+                            new_focus = Some(focus);
+                        }
+                        if response.secondary_clicked() {
+                            jump_to = Some((child.path.clone(), None));
+                        }
+                        response
+                            .on_hover_text(
+                                format!(
+                                    "{}\n{} · {} files", child.path, self.format_file_size(child
+                                    .size), child.file_count
+                                ),
+                            );
+                    }
+                    ui.add_space(6.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Top N:");
+                        ui.add(
+                            egui::Slider::new(&mut self.disk_usage.top_n, 5..=MAX_TOP_FILES),
+                        );
+                    });
+                    let top_n = self.disk_usage.top_n;
+                    ui.columns(
+                        2,
+                        |columns| {
+                            columns[0].label(egui::RichText::new("📁 Largest folders").strong());
+                            egui::ScrollArea::vertical()
+                                .id_salt("disk_usage_dirs")
+                                .auto_shrink([false, false])
+                                .show(
+                                    &mut columns[0],
+                                    |ui| {
+                                        for child in focus_node.children.iter().take(top_n) {
+                                            let percent = if focus_node.size > 0 {
+                                                child.size as f64 * 100.0 / focus_node.size as f64
+                                            } else {
+                                                0.0
+                                            };
+                                            let response = ui
+                                                .selectable_label(
+                                                    false,
+                                                    format!(
+                                                        "{:>10}  {:>5.1}%  {}", self.format_file_size(child
+                                                        .size), percent, child.name
+                                                    ),
+                                                );
+                                            if response.double_clicked() {
+                                                jump_to = Some((child.path.clone(), None));
+                                            }
+                                        }
+                                    },
+                                );
+                            columns[1].label(egui::RichText::new("📄 Largest files").strong());
+                            egui::ScrollArea::vertical()
+                                .id_salt("disk_usage_files")
+                                .auto_shrink([false, false])
+                                .show(
+                                    &mut columns[1],
+                                    |ui| {
+                                        for (path, size) in report.largest_files.iter().take(top_n) {
+                                            let response = ui
+                                                .selectable_label(
+                                                    false,
+                                                    format!("{:>10}  {}", self.format_file_size(* size), path),
+                                                )
+                                                .on_hover_text("Double-click to show in the Filepane");
+                                            if response.double_clicked() {
+                                                let file_path = std::path::Path::new(path);
+                                                if let Some(parent) = file_path.parent() {
+                                                    jump_to = Some((
+                                                        parent.to_string_lossy().to_string(),
+                                                        file_path
+                                                            .file_name()
+                                                            .map(|n| n.to_string_lossy().to_string()),
+                                                    ));
+                                                }
+                                            }
+                                        }
+                                    },
+                                );
+                        },
+                    );
+                    if let Some(focus) = new_focus {
+                        self.disk_usage.focus = focus;
+                    }
+                },
+            );
+        if let Some((directory, selected_name)) = jump_to {
+            self.jump_to_disk_usage_path(directory, selected_name);
+        }
+        if !open {
+            if let Some(flag) = &self.disk_usage.cancel_flag {
+                flag.store(true, Ordering::Relaxed);
+            }
+        }
+        self.disk_usage.is_open = open;
+    }
+    fn jump_to_disk_usage_path(&mut self, directory: String, selected_name: Option<String>) {
+        if self.filepane_active_tab >= self.filepane_tabs.len() {
+            return;
+        }
+        let tab = &mut self.filepane_tabs[self.filepane_active_tab];
+        let selection: Vec<String> = selected_name.into_iter().collect();
+        if self.disk_usage.source_panel == 0 {
+            tab.left_path = directory;
+            tab.selected_left = selection;
+            tab.left_checkboxes.clear();
+        } else {
+            tab.right_path = directory;
+            tab.selected_right = selection;
+            tab.right_checkboxes.clear();
+        }
+        self.view_mode = ViewMode::Filepane;
+    }
+    fn start_disk_usage_scan(&mut self) {
+        let root = std::path::PathBuf::from(self.disk_usage.root_path.trim());
+        if !root.is_dir() {
+            self.disk_usage.error_msg = Some(
+                format!("Not a directory: {}", root.display()),
+            );
+            return;
+        }
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let worker_cancel = cancel_flag.clone();
+        let one_filesystem = self.filepane_one_filesystem;
+        std::thread::spawn(move || {
+            Self::run_disk_usage_scan(root, sender, worker_cancel, one_filesystem);
+        });
+        self.disk_usage.error_msg = None;
+        self.disk_usage.receiver = Some(receiver);
+        self.disk_usage.cancel_flag = Some(cancel_flag);
+        self.disk_usage.is_running = true;
+        self.disk_usage.scanned_files = 0;
+        self.disk_usage.scanned_bytes = 0;
+        self.add_log(format!("📊 USAGE: Scanning {}", self.disk_usage.root_path));
+    }
+    fn poll_disk_usage(&mut self) {
+        let mut finished = None;
+        if let Some(receiver) = &self.disk_usage.receiver {
+            loop {
+                match receiver.try_recv() {
+                    Ok(DiskUsageMessage::Progress { scanned_files, scanned_bytes }) => {
+                        self.disk_usage.scanned_files = scanned_files;
+                        self.disk_usage.scanned_bytes = scanned_bytes;
+                    }
+                    Ok(DiskUsageMessage::Finished(result)) => {
+                        finished = Some(result);
+                        break;
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        finished = Some(Err("Scan thread stopped unexpectedly".to_string()));
+                        break;
+                    }
+                }
+            }
+        }
+        let Some(result) = finished else {
+            return;
+        };
+        self.disk_usage.is_running = false;
+        self.disk_usage.receiver = None;
+        self.disk_usage.cancel_flag = None;
+        match result {
+            Ok(report) => {
+                self.record_disk_usage_sizes(&report.root);
+                self.add_log(
+                    format!(
+                        "📊 USAGE: {} uses {} in {} files", report.root.path, self
+                        .format_file_size(report.root.size), report.root.file_count
+                    ),
+                );
+                self.disk_usage.focus.clear();
+                self.disk_usage.report = Some(report);
+            }
+            Err(e) => {
+                self.add_log(format!("📊 USAGE: {}", e));
+                self.disk_usage.error_msg = Some(e);
+            }
+        }
+    }
+    fn record_disk_usage_sizes(&mut self, node: &DiskUsageNode) {
+        self.filepane_dir_sizes.insert(node.path.clone(), node.size);
+        for child in &node.children {
+            self.record_disk_usage_sizes(child);
+        }
+    }
+    fn disk_usage_node_at<'a>(root: &'a DiskUsageNode, focus: &[usize]) -> &'a DiskUsageNode {
+        let mut node = root;
+        for &index in focus {
+            match node.children.get(index) {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+        node
+    }
+    fn run_disk_usage_scan(
+        root: std::path::PathBuf,
+        sender: Sender<DiskUsageMessage>,
+        cancel_flag: Arc<AtomicBool>,
+        one_filesystem: bool,
+    ) {
+        let started = std::time::Instant::now();
+        let mut progress = ScanProgress {
+            scanned_files: 0,
+            scanned_bytes: 0,
+            unreadable_entries: 0,
+            largest_files: Vec::new(),
+            guard: TraversalGuard::new(&root, one_filesystem),
+        };
+        let node = Self::scan_disk_usage_node(&root, &sender, &cancel_flag, &mut progress);
+        if cancel_flag.load(Ordering::Relaxed) {
+            let _ = sender.send(DiskUsageMessage::Finished(Err("Scan cancelled".to_string())));
+            return;
+        }
+        progress.largest_files.sort_by(|a, b| b.1.cmp(&a.1));
+        progress.largest_files.truncate(MAX_TOP_FILES);
+        let report = DiskUsageReport {
+            root: node,
+            largest_files: progress.largest_files,
+            unreadable_entries: progress.unreadable_entries,
+            elapsed: started.elapsed(),
+        };
+        let _ = sender.send(DiskUsageMessage::Finished(Ok(report)));
+    }
+    fn scan_disk_usage_node(
+        path: &std::path::Path,
+        sender: &Sender<DiskUsageMessage>,
+        cancel_flag: &AtomicBool,
+        progress: &mut ScanProgress,
+    ) -> DiskUsageNode {
+        let mut node = DiskUsageNode {
+            name: path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.to_string_lossy().to_string()),
+            path: path.to_string_lossy().to_string(),
+            size: 0,
+            file_count: 0,
+            children: Vec::new(),
+        };
+        if progress.guard.enter(path).is_err() {
+            return node;
+        }
+        let Ok(entries) = std::fs::read_dir(path) else {
+            progress.unreadable_entries += 1;
+            return node;
+        };
+        for entry in entries {
+            if cancel_flag.load(Ordering::Relaxed) {
+                return node;
+            }
+            let Ok(entry) = entry else {
+                progress.unreadable_entries += 1;
+                continue;
+            };
+            // Symlinks are counted by their own size and never followed.
+            let Ok(metadata) = entry.metadata() else {
+                progress.unreadable_entries += 1;
+                continue;
+            };
+            if metadata.is_dir() {
+                let child = Self::scan_disk_usage_node(
+                    &entry.path(),
+                    sender,
+                    cancel_flag,
+                    progress,
+                );
+                node.size += child.size;
+                node.file_count += child.file_count;
+                node.children.push(child);
+            } else {
+                // Extra hard links to an already counted file take no space.
+                let size = if progress.guard.first_link(&entry.path(), &metadata) {
+                    metadata.len()
+                } else {
+                    0
+                };
+                node.size += size;
+                node.file_count += 1;
+                progress.scanned_files += 1;
+                progress.scanned_bytes += size;
+                progress.largest_files.push((entry.path().to_string_lossy().to_string(), size));
+                if progress.largest_files.len() >= MAX_TOP_FILES * 2 {
+                    progress.largest_files.sort_by(|a, b| b.1.cmp(&a.1));
+                    progress.largest_files.truncate(MAX_TOP_FILES);
+                }
+                if progress.scanned_files % PROGRESS_EVERY_FILES == 0 {
+                    let _ = sender
+                        .send(DiskUsageMessage::Progress {
+                            scanned_files: progress.scanned_files,
+                            scanned_bytes: progress.scanned_bytes,
+                        });
+                }
+            }
+        }
+        node.children.sort_by(|a, b| b.size.cmp(&a.size));
+        node
+    }
+    /// Squarified treemap layout. `values` must be sorted largest first for
+    /// good aspect ratios; zero values get an empty rectangle.
+    fn layout_treemap(values: &[u64], rect: egui::Rect) -> Vec<egui::Rect> {
+        let total: u64 = values.iter().sum();
+        let mut rects = vec![egui::Rect::NOTHING; values.len()];
+        if total == 0 || rect.area() <= 0.0 {
+            return rects;
+        }
+        let scale = rect.area() as f64 / total as f64;
+        let items: Vec<(usize, f64)> = values
+            .iter()
+            .enumerate()
+            .filter(|(_, &v)| v > 0)
+            .map(|(i, &v)| (i, v as f64 * scale))
+            .collect();
+        let worst_ratio = |row: &[(usize, f64)], side: f64| -> f64 {
+            let sum: f64 = row.iter().map(|(_, a)| a).sum();
+            let max = row.iter().map(|(_, a)| *a).fold(f64::MIN, f64::max);
+            let min = row.iter().map(|(_, a)| *a).fold(f64::MAX, f64::min);
+            let side_sq = side * side;
+            let sum_sq = sum * sum;
+            (side_sq * max / sum_sq).max(sum_sq / (side_sq * min))
+        };
+        let mut remaining = rect;
+        let mut start = 0;
+        while start < items.len() {
+            let side = remaining.width().min(remaining.height()) as f64;
+            let mut end = start + 1;
+            let mut best = worst_ratio(&items[start..end], side);
+            while end < items.len() {
+                let candidate = worst_ratio(&items[start..end + 1], side);
+                if candidate > best {
+                    break;
+                }
+                best = candidate;
+                end += 1;
+            }
+            let row = &items[start..end];
+            let row_area: f64 = row.iter().map(|(_, a)| a).sum();
+            if remaining.width() >= remaining.height() {
+                let thickness = (row_area / remaining.height() as f64) as f32;
+                let mut y = remaining.top();
+                for (index, area) in row {
+                    let height = (*area / thickness as f64) as f32;
+                    rects[*index] = egui::Rect::from_min_size(
+                        egui::pos2(remaining.left(), y),
+                        egui::vec2(thickness, height),
+                    );
+                    y += height;
+                }
+                remaining.min.x += thickness;
+            } else {
+                let thickness = (row_area / remaining.width() as f64) as f32;
+                let mut x = remaining.left();
+                for (index, area) in row {
+                    let width = (*area / thickness as f64) as f32;
+                    rects[*index] = egui::Rect::from_min_size(
+                        egui::pos2(x, remaining.top()),
+                        egui::vec2(width, thickness),
+                    );
+                    x += width;
+                }
+                remaining.min.y += thickness;
+            }
+            start = end;
+        }
+        rects
+    }
+    /// "Calculate sizes" for the directories listed in both panels of the
+    /// active tab; results arrive through `filepane_size_receiver`.
+    pub fn calculate_directory_sizes(&mut self) {
+        if self.filepane_active_tab >= self.filepane_tabs.len() {
+            return;
+        }
+        let tab = &self.filepane_tabs[self.filepane_active_tab];
+        let mut directories = Vec::new();
+        for panel_path in [&tab.left_path, &tab.right_path] {
+            if let Ok(entries) = std::fs::read_dir(panel_path) {
+                for entry in entries.flatten() {
+                    if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                        directories.push(entry.path());
+                    }
+                }
+            }
+        }
+        directories.sort();
+        directories.dedup();
+        if directories.is_empty() {
+            self.add_log("No directories to size in the current panels".to_string());
+            return;
+        }
+        self.add_log(format!("📏 Calculating sizes of {} directories", directories.len()));
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let one_filesystem = self.filepane_one_filesystem;
+        std::thread::spawn(move || {
+            let cancel_flag = AtomicBool::new(false);
+            let (progress_sender, _progress_receiver) = std::sync::mpsc::channel();
+            for directory in directories {
+                let mut progress = ScanProgress {
+                    scanned_files: 0,
+                    scanned_bytes: 0,
+                    unreadable_entries: 0,
+                    largest_files: Vec::new(),
+                    guard: TraversalGuard::new(&directory, one_filesystem),
+                };
+                let node = Self::scan_disk_usage_node(
+                    &directory,
+                    &progress_sender,
+                    &cancel_flag,
+                    &mut progress,
+                );
+                if sender.send((node.path, node.size)).is_err() {
+                    return;
+                }
+            }
+        });
+        self.filepane_size_receiver = Some(receiver);
+    }
+    pub(crate) fn poll_directory_sizes(&mut self, ctx: &egui::Context) {
+        let mut disconnected = false;
+        if let Some(receiver) = &self.filepane_size_receiver {
+            loop {
+                match receiver.try_recv() {
+                    Ok((path, size)) => {
+                        self.filepane_dir_sizes.insert(path, size);
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        disconnected = true;
+                        break;
+                    }
+                }
+            }
+            ctx.request_repaint();
+        }
+        if disconnected {
+            self.filepane_size_receiver = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_treemap_fills_the_rect() {
+        let rect = egui::Rect::from_min_size(egui::pos2(10.0, 20.0), egui::vec2(400.0, 300.0));
+        let values = [500, 250, 120, 80, 40, 9, 1, 0];
+        let total: u64 = values.iter().sum();
+        let rects = ProcessManagerApp::layout_treemap(&values, rect);
+        assert_eq!(rects.len(), values.len());
+        let mut covered = 0.0;
+        for (value, tile) in values.iter().zip(&rects) {
+            if *value == 0 {
+                assert_eq!(*tile, egui::Rect::NOTHING);
+                continue;
+            }
+            assert!(tile.width() >= 0.0 && tile.height() >= 0.0, "{:?}", tile);
+            assert!(rect.expand(0.01).contains_rect(*tile), "{:?}", tile);
+            let expected = rect.area() * *value as f32 / total as f32;
+            assert!((tile.area() - expected).abs() < 0.5, "{} vs {}", tile.area(), expected);
+            covered += tile.area();
+        }
+        assert!((covered - rect.area()).abs() < 1.0, "{} vs {}", covered, rect.area());
+    }
+
+    #[test]
+    fn layout_treemap_empty_inputs() {
+        let rect = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(100.0, 100.0));
+        assert!(ProcessManagerApp::layout_treemap(&[], rect).is_empty());
+        assert_eq!(ProcessManagerApp::layout_treemap(&[0, 0], rect), vec![egui::Rect::NOTHING; 2]);
+        let flat = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(100.0, 0.0));
+        assert_eq!(ProcessManagerApp::layout_treemap(&[5], flat), vec![egui::Rect::NOTHING]);
+    }
+}
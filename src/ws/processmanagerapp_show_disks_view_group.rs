@@ -0,0 +1,301 @@
+use crate::ws::BlockDeviceStats;
+use crate::ws::SortColumn;
+use crate::ws::ViewMode;
+// # ProcessManagerApp - show_disks_view_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Device throughput is sampled on every data refresh, whichever view is
+// open, so the graphs already have history when the Disks view is first
+// shown. The mount list is only re-read while the view is visible, and on
+// a background thread, since statting a stale network mount can block.
+
+use std::time::Instant;
+use super::processmanagerapp_type::ProcessManagerApp;
+
+/// Cumulative (read, written) bytes per whole disk from `/proc/diskstats`.
+#[cfg(target_os = "linux")]
+fn read_diskstats() -> Vec<(String, u64, u64)> {
+    // Sector counts in diskstats are always in 512-byte units.
+    const SECTOR: u64 = 512;
+    let Ok(content) = std::fs::read_to_string("/proc/diskstats") else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let name = *fields.get(2)?;
+            let read_sectors: u64 = fields.get(5)?.parse().ok()?;
+            let written_sectors: u64 = fields.get(9)?.parse().ok()?;
+            Some((name.to_string(), read_sectors * SECTOR, written_sectors * SECTOR))
+        })
+        .filter(|(name, _, _)| {
+            !name.starts_with("loop") && !name.starts_with("ram")
+                && std::path::Path::new("/sys/block").join(name).exists()
+        })
+        .collect()
+}
+
+/// Per-device counters are not available here; the total is the sum of
+/// the per-process counters instead.
+#[cfg(not(target_os = "linux"))]
+fn read_diskstats() -> Vec<(String, u64, u64)> {
+    Vec::new()
+}
+
+impl ProcessManagerApp {
+    pub(crate) fn refresh_disk_io(&mut self) {
+        let now = Instant::now();
+        let elapsed = self.start_time.elapsed().as_secs_f64();
+        let interval = self
+            .disks
+            .sampled_at
+            .map(|sampled| now.duration_since(sampled).as_secs_f64())
+            .filter(|seconds| *seconds > 0.0);
+        let rate = |current: u64, previous: u64| match interval {
+            Some(seconds) => (current.saturating_sub(previous) as f64 / seconds) as u64,
+            None => 0,
+        };
+        let counters = read_diskstats();
+        let mut devices = Vec::with_capacity(counters.len());
+        for (name, read_total, write_total) in counters {
+            let mut device = self
+                .disks
+                .devices
+                .iter()
+                .position(|device| device.name == name)
+                .map(|index| self.disks.devices.swap_remove(index))
+                .unwrap_or_else(|| BlockDeviceStats {
+                    name,
+                    read_total,
+                    write_total,
+                    ..BlockDeviceStats::default()
+                });
+            device.read_rate = rate(read_total, device.read_total);
+            device.write_rate = rate(write_total, device.write_total);
+            device.read_total = read_total;
+            device.write_total = write_total;
+            device.push_history(elapsed);
+            devices.push(device);
+        }
+        devices.sort_by(|a, b| a.name.cmp(&b.name));
+        self.disks.devices = devices;
+        let total = &mut self.disks.total;
+        if self.disks.devices.is_empty() {
+            total.read_rate = self.processes.iter().map(|p| p.disk_read_rate).sum();
+            total.write_rate = self.processes.iter().map(|p| p.disk_write_rate).sum();
+        } else {
+            total.read_rate = self.disks.devices.iter().map(|d| d.read_rate).sum();
+            total.write_rate = self.disks.devices.iter().map(|d| d.write_rate).sum();
+        }
+        total.push_history(elapsed);
+        self.disks.sampled_at = Some(now);
+        self.poll_disk_list();
+        if self.view_mode == ViewMode::Disks && self.disks.loader.is_none() {
+            self.refresh_disk_list();
+        }
+    }
+    fn refresh_disk_list(&mut self) {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(sysinfo::Disks::new_with_refreshed_list());
+        });
+        self.disks.loader = Some(receiver);
+    }
+    fn poll_disk_list(&mut self) {
+        let Some(loader) = &self.disks.loader else {
+            return;
+        };
+        match loader.try_recv() {
+            Ok(disks) => {
+                self.disks.disks = disks;
+                self.disks.loader = None;
+                self.disks.loaded = true;
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.disks.loader = None;
+                self.disks.loaded = true;
+            }
+        }
+    }
+    fn show_throughput_graph(ui: &mut egui::Ui, stats: &BlockDeviceStats) {
+        use egui_plot::{Legend, Line, Plot, PlotPoints};
+        const MB: f64 = 1024.0 * 1024.0;
+        let read: PlotPoints = stats.history.iter().map(|(x, read, _)| [*x, *read / MB]).collect();
+        let write: PlotPoints = stats.history.iter().map(|(x, _, write)| [*x, *write / MB]).collect();
+        Plot::new(("disk_plot", &stats.name))
+            .height(150.0)
+            .legend(Legend::default())
+            .y_axis_label("MB/s")
+            .show(
+                ui,
+                |plot_ui| {
+                    plot_ui
+                        .line(
+                            Line::new(read)
+                                .name("Read")
+                                .color(egui::Color32::from_rgb(100, 180, 255)),
+                        );
+                    plot_ui
+                        .line(
+                            Line::new(write)
+                                .name("Write")
+                                .color(egui::Color32::from_rgb(255, 160, 80)),
+                        );
+                },
+            );
+    }
+    pub(crate) fn show_disks_view(&mut self, ui: &mut egui::Ui) {
+        if !self.disks.loaded && self.disks.loader.is_none() {
+            self.refresh_disk_list();
+        }
+        self.poll_disk_list();
+        if self.disks.loader.is_some() {
+            ui.ctx().request_repaint_after(std::time::Duration::from_millis(200));
+        }
+        let mut show_process = None;
+        let mut sort_by = None;
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(
+                ui,
+                |ui| {
+                    ui.horizontal(|ui| {
+                        ui.heading("💽 Mounts");
+                        if !self.disks.loaded {
+                            ui.spinner();
+                        }
+                    });
+                    egui::Grid::new("disks_mounts_grid")
+                        .striped(true)
+                        .num_columns(6)
+                        .show(
+                            ui,
+                            |ui| {
+                                for header in ["Mount", "Device", "Type", "Used", "Free", "Size"] {
+                                    ui.strong(header);
+                                }
+                                ui.end_row();
+                                for disk in self.disks.disks.list() {
+                                    let total = disk.total_space();
+                                    let available = disk.available_space();
+                                    let used = total.saturating_sub(available);
+                                    let fraction = if total > 0 { used as f32 / total as f32 } else { 0.0 };
+                                    let mut mount = disk.mount_point().display().to_string();
+                                    if disk.is_removable() {
+                                        mount.push_str(" ⏏");
+                                    }
+                                    if disk.is_read_only() {
+                                        mount.push_str(" 🔒");
+                                    }
+                                    ui.label(mount);
+                                    ui.label(disk.name().to_string_lossy()).on_hover_text(disk.kind().to_string());
+                                    ui.label(disk.file_system().to_string_lossy());
+                                    let fill = if fraction > 0.9 {
+                                        egui::Color32::from_rgb(200, 60, 60)
+                                    } else if fraction > 0.75 {
+                                        egui::Color32::from_rgb(200, 160, 40)
+                                    } else {
+                                        egui::Color32::from_rgb(60, 140, 200)
+                                    };
+                                    ui.add(
+                                        egui::ProgressBar::new(fraction)
+                                            .desired_width(160.0)
+                                            .fill(fill)
+                                            .text(format!("{:.0}%", fraction * 100.0)),
+                                    );
+                                    ui.label(Self::format_memory(available));
+                                    ui.label(Self::format_memory(total));
+                                    ui.end_row();
+                                }
+                            },
+                        );
+                    ui.add_space(10.0);
+                    ui.separator();
+                    ui.heading("📈 Throughput");
+                    ui.horizontal_wrapped(|ui| {
+                        let total = &self.disks.total;
+                        let label = format!(
+                            "{}  ⬇ {}/s ⬆ {}/s", total.name, Self::format_memory(total.read_rate),
+                            Self::format_memory(total.write_rate)
+                        );
+                        if ui.selectable_label(self.disks.selected_device.is_none(), label).clicked() {
+                            self.disks.selected_device = None;
+                        }
+                        for device in &self.disks.devices {
+                            let label = format!(
+                                "{}  ⬇ {}/s ⬆ {}/s", device.name, Self::format_memory(device.read_rate),
+                                Self::format_memory(device.write_rate)
+                            );
+                            let selected = self.disks.selected_device.as_deref() == Some(device.name.as_str());
+                            if ui.selectable_label(selected, label).clicked() {
+                                self.disks.selected_device = Some(device.name.clone());
+                            }
+                        }
+                    });
+                    let stats = self
+                        .disks
+                        .selected_device
+                        .as_ref()
+                        .and_then(|name| self.disks.devices.iter().find(|d| &d.name == name))
+                        .unwrap_or(&self.disks.total);
+                    Self::show_throughput_graph(ui, stats);
+                    ui.add_space(10.0);
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        ui.heading("⚙ Top Processes by I/O");
+                        if ui.small_button("Sort process list by Read/s").clicked() {
+                            sort_by = Some(SortColumn::DiskRead);
+                        }
+                        if ui.small_button("by Write/s").clicked() {
+                            sort_by = Some(SortColumn::DiskWrite);
+                        }
+                    });
+                    let mut busiest: Vec<_> = self
+                        .processes
+                        .iter()
+                        .filter(|p| p.disk_read_rate + p.disk_write_rate > 0)
+                        .collect();
+                    busiest.sort_by_key(|p| std::cmp::Reverse(p.disk_read_rate + p.disk_write_rate));
+                    if busiest.is_empty() {
+                        ui.weak("No process is reading or writing right now");
+                    }
+                    egui::Grid::new("disks_process_grid")
+                        .striped(true)
+                        .num_columns(4)
+                        .show(
+                            ui,
+                            |ui| {
+                                for process in busiest.into_iter().take(15) {
+                                    if ui.link(process.pid.to_string()).on_hover_text("Show in Processes").clicked() {
+                                        show_process = Some(process.pid);
+                                    }
+                                    ui.horizontal(|ui| {
+                                        let icon = self.process_icons.get(&process.name, process.exe_path.as_deref());
+                                        Self::show_process_icon(ui, icon, &process.name, 16.0);
+                                        ui.label(&process.name);
+                                    });
+                                    ui.label(format!("⬇ {}/s", Self::format_memory(process.disk_read_rate)));
+                                    ui.label(format!("⬆ {}/s", Self::format_memory(process.disk_write_rate)));
+                                    ui.end_row();
+                                }
+                            },
+                        );
+                },
+            );
+        if let Some(pid) = show_process {
+            self.selected_pid = Some(pid);
+            self.search_filter.clear();
+            self.view_mode = ViewMode::Processes;
+        }
+        if let Some(column) = sort_by {
+            self.sort_column = column;
+            self.sort_ascending = false;
+            self.sort_processes();
+            self.current_page = 0;
+            self.view_mode = ViewMode::Processes;
+        }
+    }
+}
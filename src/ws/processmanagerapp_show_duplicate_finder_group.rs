@@ -0,0 +1,555 @@
+use crate::ws::ChecksumAlgorithm;
+use crate::ws::DuplicateFile;
+use crate::ws::DuplicateGroup;
+use crate::ws::DuplicateScanMessage;
+use crate::ws::DuplicateStrategy;
+use crate::ws::FilepaneCommand;
+use crate::ws::same_contents;
+// # ProcessManagerApp - show_duplicate_finder_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Candidates are narrowed by size, then by a CRC32 of the first block, and
+// only the survivors are hashed in full with the selected algorithm. Files
+// with equal hashes are then compared byte for byte, so a hash collision can
+// never lead to trashing or hardlinking a file that differs.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Sender, TryRecvError};
+use std::sync::Arc;
+use super::processmanagerapp_type::ProcessManagerApp;
+
+const HEAD_BLOCK_BYTES: u64 = 4 * 1024;
+
+impl ProcessManagerApp {
+    pub fn open_duplicate_finder(&mut self) {
+        if self.filepane_active_tab < self.filepane_tabs.len() && !self.duplicate_finder.is_running
+        {
+            self.duplicate_finder.algorithm = self
+                .filepane_tabs[self.filepane_active_tab]
+                .checksum_algorithm
+                .clone();
+        }
+        self.duplicate_finder.is_open = true;
+    }
+    pub(crate) fn show_duplicate_finder(&mut self, ctx: &egui::Context) {
+        self.poll_duplicate_finder();
+        if self.duplicate_finder.is_running {
+            ctx.request_repaint();
+        }
+        let mut open = self.duplicate_finder.is_open;
+        let mut resolve: Option<(usize, DuplicateStrategy)> = None;
+        let (left_path, right_path) = self
+            .filepane_tabs
+            .get(self.filepane_active_tab)
+            .map(|tab| (tab.left_path.clone(), tab.right_path.clone()))
+            .unwrap_or_default();
+        egui::Window::new("🧬 Duplicate Finder")
+            .open(&mut open)
+            .default_size([820.0, 600.0])
+            .min_width(600.0)
+            .resizable(true)
+            .show(
+                ctx,
+                |ui| {
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut self.duplicate_finder.scan_left, "Left")
+                            .on_hover_text(&left_path);
+                        ui.checkbox(&mut self.duplicate_finder.scan_right, "Right")
+                            .on_hover_text(&right_path);
+                        ui.checkbox(&mut self.duplicate_finder.recursive, "Recursive");
+                        ui.separator();
+                        ui.label("Min size:");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.duplicate_finder.min_size)
+                                .hint_text("e.g. 1M")
+                                .desired_width(70.0),
+                        );
+                        ui.separator();
+                        ui.label("Hash:");
+                        for algorithm in [
+                            ChecksumAlgorithm::MD5,
+                            ChecksumAlgorithm::SHA1,
+                            ChecksumAlgorithm::SHA256,
+                            ChecksumAlgorithm::CRC32,
+                        ] {
+                            let selected = self.duplicate_finder.algorithm == algorithm;
+                            if ui.selectable_label(selected, algorithm.name()).clicked() {
+                                self.duplicate_finder.algorithm = algorithm;
+                            }
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        if self.duplicate_finder.is_running {
+                            if ui.button("⏹ Stop").clicked() {
+                                if let Some(flag) = &self.duplicate_finder.cancel_flag {
+                                    flag.store(true, Ordering::Relaxed);
+                                }
+                            }
+                            ui.spinner();
+                            ui.label(
+                                format!(
+                                    "{}: {}/{}", self.duplicate_finder.stage, self
+                                    .duplicate_finder.progress_done, self.duplicate_finder
+                                    .progress_total
+                                ),
+                            );
+                        } else if ui.button("▶ Find Duplicates").clicked() {
+                            let mut roots = Vec::new();
+                            if self.duplicate_finder.scan_left {
+                                roots.push(left_path.clone());
+                            }
+                            if self.duplicate_finder.scan_right {
+                                roots.push(right_path.clone());
+                            }
+                            self.start_duplicate_scan(roots);
+                        }
+                        let reclaimable: u64 = self
+                            .duplicate_finder
+                            .groups
+                            .iter()
+                            .map(|g| g.reclaimable())
+                            .sum();
+                        if !self.duplicate_finder.groups.is_empty() {
+                            ui.separator();
+                            ui.label(
+                                egui::RichText::new(
+                                        format!(
+                                            "{} group(s) · {} reclaimable", self.duplicate_finder
+                                            .groups.len(), self.format_file_size(reclaimable)
+                                        ),
+                                    )
+                                    .strong(),
+                            );
+                        }
+                    });
+                    if let Some(error) = &self.duplicate_finder.error_msg {
+                        ui.colored_label(egui::Color32::RED, format!("❌ {}", error));
+                    }
+                    ui.separator();
+                    egui::ScrollArea::vertical()
+                        .auto_shrink([false, false])
+                        .show(
+                            ui,
+                            |ui| {
+                                for group_index in 0..self.duplicate_finder.groups.len() {
+                                    let group = &self.duplicate_finder.groups[group_index];
+                                    let header = format!(
+                                        "{} × {} · {} reclaimable · {} {}", group.files.len(), self
+                                        .format_file_size(group.size), self.format_file_size(group
+                                        .reclaimable()), self.duplicate_finder.algorithm.name(), &
+                                        group.hash[..group.hash.len().min(12)]
+                                    );
+                                    egui::CollapsingHeader::new(header)
+                                        .id_salt(("duplicate_group", group_index))
+                                        .default_open(group_index < 10)
+                                        .show(
+                                            ui,
+                                            |ui| {
+                                                let group = &mut self.duplicate_finder.groups[group_index];
+                                                for file_index in 0..group.files.len() {
+                                                    let file = &group.files[file_index];
+                                                    let modified: chrono::DateTime<chrono::Local> = file
+                                                        .modified
+                                                        .into();
+                                                    ui.horizontal(|ui| {
+                                                        ui.radio_value(
+                                                                &mut group.keep_index,
+                                                                file_index,
+                                                                "",
+                                                            )
+                                                            .on_hover_text("Keep this copy");
+                                                        ui.label(
+                                                            egui::RichText::new(
+                                                                    modified.format("%Y-%m-%d %H:%M").to_string(),
+                                                                )
+                                                                .monospace(),
+                                                        );
+                                                        ui.label(&group.files[file_index].path);
+                                                    });
+                                                }
+                                                ui.horizontal(|ui| {
+                                                    if ui
+                                                        .button("🗑️ Trash others")
+                                                        .on_hover_text("Move every copy except the kept one to trash")
+                                                        .clicked()
+                                                    {
+                                                        resolve = Some((group_index, DuplicateStrategy::Trash));
+                                                    }
+                                                    if ui
+                                                        .button("🔗 Hardlink others")
+                                                        .on_hover_text("Replace other copies with hardlinks to the kept one")
+                                                        .clicked()
+                                                    {
+                                                        resolve = Some((group_index, DuplicateStrategy::Hardlink));
+                                                    }
+                                                    if ui
+                                                        .button("🕒 Keep newest")
+                                                        .on_hover_text("Trash every copy except the most recently modified")
+                                                        .clicked()
+                                                    {
+                                                        resolve = Some((group_index, DuplicateStrategy::KeepNewest));
+                                                    }
+                                                });
+                                            },
+                                        );
+                                }
+                                if self.duplicate_finder.groups.is_empty()
+                                    && !self.duplicate_finder.is_running
+                                {
+                                    ui.label("No duplicate groups");
+                                }
+                            },
+                        );
+                },
+            );
+        if let Some((group_index, strategy)) = resolve {
+            self.resolve_duplicate_group(group_index, strategy);
+        }
+        if !open {
+            if let Some(flag) = &self.duplicate_finder.cancel_flag {
+                flag.store(true, Ordering::Relaxed);
+            }
+        }
+        self.duplicate_finder.is_open = open;
+    }
+    /// Turns a duplicate group into a batch command and routes it through the
+    /// regular Filepane confirmation dialog.
+    fn resolve_duplicate_group(&mut self, group_index: usize, strategy: DuplicateStrategy) {
+        let Some(group) = self.duplicate_finder.groups.get_mut(group_index) else {
+            return;
+        };
+        if strategy == DuplicateStrategy::KeepNewest {
+            if let Some((newest, _)) = group
+                .files
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, file)| file.modified)
+            {
+                group.keep_index = newest;
+            }
+        }
+        let keep_path = group.files[group.keep_index].path.clone();
+        let others: Vec<String> = group
+            .files
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != group.keep_index)
+            .map(|(_, file)| file.path.clone())
+            .collect();
+        let commands: Vec<FilepaneCommand> = others
+            .iter()
+            .map(|path| {
+                if strategy == DuplicateStrategy::Hardlink {
+                    FilepaneCommand::CreateHardlink {
+                        target: keep_path.clone(),
+                        link_path: path.clone(),
+                        replace: true,
+                    }
+                } else {
+                    FilepaneCommand::DeleteFile {
+                        path: path.clone(),
+                    }
+                }
+            })
+            .collect();
+        let verb = if strategy == DuplicateStrategy::Hardlink {
+            "replace with hardlinks"
+        } else {
+            "move to trash"
+        };
+        let message = format!(
+            "Keep:\n\n{}\n\nand {} {} duplicate(s):\n\n{}", keep_path, verb, others.len(),
+            others.join("\n")
+        );
+        self.request_file_operation_confirmation(
+            &FilepaneCommand::Batch { commands },
+            message,
+        );
+    }
+    /// Drops copies that are gone or have become hardlinks of another copy
+    /// in their group, and groups with nothing left to resolve. Run after a
+    /// confirmed file operation.
+    pub(crate) fn prune_duplicate_groups(&mut self) {
+        for group in &mut self.duplicate_finder.groups {
+            let keep_path = group.files.get(group.keep_index).map(|file| file.path.clone());
+            let mut remaining: Vec<DuplicateFile> = Vec::new();
+            for file in group.files.drain(..) {
+                if !std::path::Path::new(&file.path).exists() {
+                    continue;
+                }
+                if !remaining.iter().any(|other| Self::same_file(&other.path, &file.path)) {
+                    remaining.push(file);
+                }
+            }
+            group.keep_index = remaining
+                .iter()
+                .position(|file| Some(&file.path) == keep_path.as_ref())
+                .unwrap_or(0);
+            group.files = remaining;
+        }
+        self.duplicate_finder.groups.retain(|group| group.files.len() > 1);
+    }
+    #[cfg(unix)]
+    fn same_file(first: &str, second: &str) -> bool {
+        use std::os::unix::fs::MetadataExt;
+        let id = |path: &str| std::fs::metadata(path).ok().map(|m| (m.dev(), m.ino()));
+        matches!((id(first), id(second)), (Some(a), Some(b)) if a == b)
+    }
+    #[cfg(windows)]
+    fn same_file(first: &str, second: &str) -> bool {
+        use std::os::windows::io::AsRawHandle;
+        use windows::Win32::Foundation::HANDLE;
+        use windows::Win32::Storage::FileSystem::{
+            GetFileInformationByHandle, BY_HANDLE_FILE_INFORMATION,
+        };
+        let id = |path: &str| {
+            let file = std::fs::File::open(path).ok()?;
+            let mut info = BY_HANDLE_FILE_INFORMATION::default();
+            unsafe { GetFileInformationByHandle(HANDLE(file.as_raw_handle()), &mut info) }.ok()?;
+            Some((info.dwVolumeSerialNumber, info.nFileIndexHigh, info.nFileIndexLow))
+        };
+        matches!((id(first), id(second)), (Some(a), Some(b)) if a == b)
+    }
+    #[cfg(not(any(unix, windows)))]
+    fn same_file(_first: &str, _second: &str) -> bool {
+        false
+    }
+    fn start_duplicate_scan(&mut self, roots: Vec<String>) {
+        self.duplicate_finder.error_msg = None;
+        if roots.is_empty() {
+            self.duplicate_finder.error_msg = Some(
+                "Select at least one panel to scan".to_string(),
+            );
+            return;
+        }
+        let min_size = match Self::parse_size_input(&self.duplicate_finder.min_size) {
+            Ok(size) => size.unwrap_or(0),
+            Err(e) => {
+                self.duplicate_finder.error_msg = Some(format!("Invalid minimum size: {}", e));
+                return;
+            }
+        };
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let worker_cancel = cancel_flag.clone();
+        let recursive = self.duplicate_finder.recursive;
+        let algorithm = self.duplicate_finder.algorithm.clone();
+        self.add_log(format!("🧬 DUPLICATES: Scanning {}", roots.join(", ")));
+        std::thread::spawn(move || {
+            let result = Self::find_duplicates(
+                &roots,
+                recursive,
+                min_size,
+                &algorithm,
+                &sender,
+                &worker_cancel,
+            );
+            let _ = sender.send(DuplicateScanMessage::Finished(result));
+        });
+        self.duplicate_finder.groups.clear();
+        self.duplicate_finder.receiver = Some(receiver);
+        self.duplicate_finder.cancel_flag = Some(cancel_flag);
+        self.duplicate_finder.is_running = true;
+        self.duplicate_finder.stage = "Listing files".to_string();
+        self.duplicate_finder.progress_done = 0;
+        self.duplicate_finder.progress_total = 0;
+    }
+    fn poll_duplicate_finder(&mut self) {
+        let mut finished = None;
+        if let Some(receiver) = &self.duplicate_finder.receiver {
+            loop {
+                match receiver.try_recv() {
+                    Ok(DuplicateScanMessage::Progress { stage, done, total }) => {
+                        self.duplicate_finder.stage = stage;
+                        self.duplicate_finder.progress_done = done;
+                        self.duplicate_finder.progress_total = total;
+                    }
+                    Ok(DuplicateScanMessage::Finished(result)) => {
+                        finished = Some(result);
+                        break;
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        finished = Some(Err("Scan thread stopped unexpectedly".to_string()));
+                        break;
+                    }
+                }
+            }
+        }
+        let Some(result) = finished else {
+            return;
+        };
+        self.duplicate_finder.is_running = false;
+        self.duplicate_finder.receiver = None;
+        self.duplicate_finder.cancel_flag = None;
+        match result {
+            Ok(groups) => {
+                let reclaimable: u64 = groups.iter().map(|g| g.reclaimable()).sum();
+                self.add_log(
+                    format!(
+                        "🧬 DUPLICATES: {} group(s), {} reclaimable", groups.len(), self
+                        .format_file_size(reclaimable)
+                    ),
+                );
+                self.duplicate_finder.groups = groups;
+            }
+            Err(e) => {
+                self.add_log(format!("🧬 DUPLICATES: {}", e));
+                self.duplicate_finder.error_msg = Some(e);
+            }
+        }
+    }
+    fn find_duplicates(
+        roots: &[String],
+        recursive: bool,
+        min_size: u64,
+        algorithm: &ChecksumAlgorithm,
+        sender: &Sender<DuplicateScanMessage>,
+        cancel_flag: &AtomicBool,
+    ) -> Result<Vec<DuplicateGroup>, String> {
+        let cancelled = || {
+            if cancel_flag.load(Ordering::Relaxed) {
+                Err("Scan cancelled".to_string())
+            } else {
+                Ok(())
+            }
+        };
+        let mut by_size: HashMap<u64, Vec<DuplicateFile>> = HashMap::new();
+        let mut seen_paths = HashSet::new();
+        #[cfg(unix)]
+        let mut seen_inodes = HashSet::new();
+        let mut stack: Vec<std::path::PathBuf> = roots.iter().map(std::path::PathBuf::from).collect();
+        let mut listed = 0u64;
+        while let Some(dir) = stack.pop() {
+            cancelled()?;
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                if metadata.is_dir() {
+                    if recursive {
+                        stack.push(entry.path());
+                    }
+                    continue;
+                }
+                if !metadata.is_file() || metadata.len() < min_size.max(1) {
+                    continue;
+                }
+                let path = entry.path();
+                let canonical = std::fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
+                if !seen_paths.insert(canonical) {
+                    continue;
+                }
+                // Files that are already hardlinks of each other share storage.
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::MetadataExt;
+                    if !seen_inodes.insert((metadata.dev(), metadata.ino())) {
+                        continue;
+                    }
+                }
+                by_size
+                    .entry(metadata.len())
+                    .or_default()
+                    .push(DuplicateFile {
+                        path: path.to_string_lossy().to_string(),
+                        modified: metadata.modified().unwrap_or(std::time::UNIX_EPOCH),
+                    });
+                listed += 1;
+                if listed % 500 == 0 {
+                    let _ = sender
+                        .send(DuplicateScanMessage::Progress {
+                            stage: "Listing files".to_string(),
+                            done: listed,
+                            total: listed,
+                        });
+                }
+            }
+        }
+        let size_groups: Vec<(u64, Vec<DuplicateFile>)> = by_size
+            .into_iter()
+            .filter(|(_, files)| files.len() > 1)
+            .collect();
+        let total: u64 = size_groups.iter().map(|(_, files)| files.len() as u64).sum();
+        let mut done = 0u64;
+        let mut groups = Vec::new();
+        for (size, files) in size_groups {
+            let mut by_head: HashMap<u32, Vec<DuplicateFile>> = HashMap::new();
+            for file in files {
+                cancelled()?;
+                done += 1;
+                let _ = sender
+                    .send(DuplicateScanMessage::Progress {
+                        stage: "Comparing".to_string(),
+                        done,
+                        total,
+                    });
+                if let Ok(head) = Self::head_block_crc(&file.path) {
+                    by_head.entry(head).or_default().push(file);
+                }
+            }
+            for candidates in by_head.into_values().filter(|files| files.len() > 1) {
+                let mut by_hash: HashMap<String, Vec<DuplicateFile>> = HashMap::new();
+                for file in candidates {
+                    cancelled()?;
+                    if let Ok(hash) = algorithm.hash_file(std::path::Path::new(&file.path)) {
+                        by_hash.entry(hash).or_default().push(file);
+                    }
+                }
+                for (hash, files) in by_hash.into_iter().filter(|(_, files)| files.len() > 1) {
+                    for mut files in Self::split_identical(files, &cancelled)? {
+                        if files.len() > 1 {
+                            files.sort_by(|a, b| a.path.cmp(&b.path));
+                            groups.push(DuplicateGroup {
+                                size,
+                                hash: hash.clone(),
+                                files,
+                                keep_index: 0,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        groups.sort_by(|a, b| b.reclaimable().cmp(&a.reclaimable()));
+        Ok(groups)
+    }
+    /// Splits files whose hashes matched into sets with identical bytes.
+    /// Files that cannot be read are left out.
+    fn split_identical(
+        files: Vec<DuplicateFile>,
+        cancelled: &dyn Fn() -> Result<(), String>,
+    ) -> Result<Vec<Vec<DuplicateFile>>, String> {
+        let mut sets: Vec<Vec<DuplicateFile>> = Vec::new();
+        'files: for file in files {
+            cancelled()?;
+            let mut matched = None;
+            for (index, set) in sets.iter().enumerate() {
+                match same_contents(&set[0].path, &file.path) {
+                    Ok(true) => {
+                        matched = Some(index);
+                        break;
+                    }
+                    Ok(false) => {}
+                    Err(_) => continue 'files,
+                }
+            }
+            match matched {
+                Some(index) => sets[index].push(file),
+                None => sets.push(vec![file]),
+            }
+        }
+        Ok(sets)
+    }
+    fn head_block_crc(path: &str) -> std::io::Result<u32> {
+        let mut head = Vec::new();
+        std::fs::File::open(path)?.take(HEAD_BLOCK_BYTES).read_to_end(&mut head)?;
+        Ok(crc32fast::hash(&head))
+    }
+}
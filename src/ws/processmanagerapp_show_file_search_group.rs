@@ -0,0 +1,716 @@
+use crate::ws::FileSearchAction;
+use crate::ws::FileSearchMessage;
+use crate::ws::FileSearchResult;
+use crate::ws::FilepaneCommand;
+use crate::ws::ViewMode;
+// # ProcessManagerApp - show_file_search_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Searches run on a background thread and stream `FileSearchMessage`s back
+// through a channel that the window drains every frame.
+
+use std::io::{BufRead, BufReader, Read};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Sender, TryRecvError};
+use std::sync::Arc;
+use super::processmanagerapp_type::ProcessManagerApp;
+
+const MAX_SEARCH_RESULTS: usize = 10_000;
+const PROGRESS_EVERY_FILES: usize = 200;
+const BINARY_SNIFF_BYTES: u64 = 8 * 1024;
+const MATCH_SNIPPET_CHARS: usize = 200;
+/// Only this much of a line is matched; the rest of a longer line (minified
+/// or generated text) is skipped rather than buffered.
+const MAX_LINE_BYTES: usize = 64 * 1024;
+
+struct SearchCriteria {
+    name_regex: Option<regex::Regex>,
+    content_regex: Option<regex::bytes::Regex>,
+    min_size: Option<u64>,
+    max_size: Option<u64>,
+    modified_after: Option<std::time::SystemTime>,
+    modified_before: Option<std::time::SystemTime>,
+    include_directories: bool,
+}
+
+impl ProcessManagerApp {
+    pub fn open_file_search(&mut self) {
+        if self.filepane_active_tab >= self.filepane_tabs.len() {
+            return;
+        }
+        if !self.file_search.is_running {
+            let panel = if self.filepane_swap_columns { 1 } else { 0 };
+            let tab = &self.filepane_tabs[self.filepane_active_tab];
+            self.file_search.source_panel = panel;
+            self.file_search.root_path = if panel == 0 {
+                tab.left_path.clone()
+            } else {
+                tab.right_path.clone()
+            };
+        }
+        self.file_search.is_open = true;
+    }
+    pub(crate) fn show_file_search(&mut self, ctx: &egui::Context) {
+        self.poll_file_search();
+        if self.file_search.is_running {
+            ctx.request_repaint();
+        }
+        let mut open = self.file_search.is_open;
+        let mut action: Option<(FileSearchAction, usize)> = None;
+        egui::Window::new("🔎 Find Files")
+            .open(&mut open)
+            .default_size([760.0, 560.0])
+            .min_width(600.0)
+            .resizable(true)
+            .show(
+                ctx,
+                |ui| {
+                    egui::Grid::new("file_search_criteria")
+                        .num_columns(2)
+                        .spacing([8.0, 4.0])
+                        .show(
+                            ui,
+                            |ui| {
+                                ui.label("Search in:");
+                                ui.horizontal(|ui| {
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut self.file_search.root_path)
+                                            .desired_width(380.0),
+                                    );
+                                    if self.filepane_active_tab < self.filepane_tabs.len() {
+                                        let tab = &self.filepane_tabs[self.filepane_active_tab];
+                                        if ui.button("Left").clicked() {
+                                            self.file_search.root_path = tab.left_path.clone();
+                                            self.file_search.source_panel = 0;
+                                        }
+                                        if ui.button("Right").clicked() {
+                                            self.file_search.root_path = tab.right_path.clone();
+                                            self.file_search.source_panel = 1;
+                                        }
+                                    }
+                                });
+                                ui.end_row();
+                                ui.label("Name:");
+                                ui.horizontal(|ui| {
+                                    ui.add(
+                                        egui::TextEdit::singleline(
+                                                &mut self.file_search.name_pattern,
+                                            )
+                                            .hint_text(
+                                                if self.file_search.use_regex {
+                                                    "regex, e.g. ^report_\\d+\\.csv$"
+                                                } else {
+                                                    "glob, e.g. *.log"
+                                                },
+                                            )
+                                            .desired_width(260.0),
+                                    );
+                                    ui.checkbox(&mut self.file_search.use_regex, "Regex");
+                                    ui.checkbox(
+                                        &mut self.file_search.case_sensitive,
+                                        "Case sensitive",
+                                    );
+                                });
+                                ui.end_row();
+                                ui.label("Containing:");
+                                ui.add(
+                                    egui::TextEdit::singleline(
+                                            &mut self.file_search.content_pattern,
+                                        )
+                                        .hint_text("text inside files (grep-like)")
+                                        .desired_width(380.0),
+                                );
+                                ui.end_row();
+                                ui.label("Size:");
+                                ui.horizontal(|ui| {
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut self.file_search.min_size)
+                                            .hint_text("min, e.g. 10K")
+                                            .desired_width(100.0),
+                                    );
+                                    ui.label("to");
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut self.file_search.max_size)
+                                            .hint_text("max, e.g. 5M")
+                                            .desired_width(100.0),
+                                    );
+                                });
+                                ui.end_row();
+                                ui.label("Modified:");
+                                ui.horizontal(|ui| {
+                                    ui.add(
+                                        egui::TextEdit::singleline(
+                                                &mut self.file_search.modified_after,
+                                            )
+                                            .hint_text("after YYYY-MM-DD")
+                                            .desired_width(120.0),
+                                    );
+                                    ui.label("to");
+                                    ui.add(
+                                        egui::TextEdit::singleline(
+                                                &mut self.file_search.modified_before,
+                                            )
+                                            .hint_text("before YYYY-MM-DD")
+                                            .desired_width(120.0),
+                                    );
+                                    ui.checkbox(
+                                        &mut self.file_search.include_directories,
+                                        "Include directories",
+                                    );
+                                });
+                                ui.end_row();
+                            },
+                        );
+                    ui.horizontal(|ui| {
+                        if self.file_search.is_running {
+                            if ui.button("⏹ Stop").clicked() {
+                                self.cancel_file_search();
+                            }
+                            ui.spinner();
+                        } else if ui.button("▶ Search").clicked() {
+                            self.start_file_search();
+                        }
+                        let elapsed = self
+                            .file_search
+                            .started_at
+                            .map(|t| t.elapsed().as_secs_f32())
+                            .unwrap_or(0.0);
+                        ui.label(
+                            format!(
+                                "Scanned {} dirs, {} files · {} match(es) · {:.1}s", self
+                                .file_search.scanned_dirs, self.file_search.scanned_files,
+                                self.file_search.results.len(), elapsed
+                            ),
+                        );
+                    });
+                    if let Some(error) = &self.file_search.error_msg {
+                        ui.colored_label(egui::Color32::RED, format!("❌ {}", error));
+                    }
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        if let Some(index) = self.file_search.selected_result {
+                            if ui.button("📂 Jump to").clicked() {
+                                action = Some((FileSearchAction::Jump, index));
+                            }
+                            if ui.button("👁 View").clicked() {
+                                action = Some((FileSearchAction::View, index));
+                            }
+                            if ui.button("📋 Copy Path").clicked() {
+                                action = Some((FileSearchAction::CopyPath, index));
+                            }
+                            if ui
+                                .button("⏭ Copy to other panel")
+                                .on_hover_text("Copy this result to the opposite panel")
+                                .clicked()
+                            {
+                                action = Some((FileSearchAction::Copy, index));
+                            }
+                            if ui.button("🗑️ Delete").clicked() {
+                                action = Some((FileSearchAction::Delete, index));
+                            }
+                        } else {
+                            ui.label("Select a result to jump to it or operate on it");
+                        }
+                    });
+                    ui.separator();
+                    let row_height = ui.text_style_height(&egui::TextStyle::Body) + 4.0;
+                    let result_count = self.file_search.results.len();
+                    egui::ScrollArea::both()
+                        .auto_shrink([false, false])
+                        .show_rows(
+                            ui,
+                            row_height,
+                            result_count,
+                            |ui, row_range| {
+                                for index in row_range {
+                                    let result = &self.file_search.results[index];
+                                    let is_selected = self.file_search.selected_result
+                                        == Some(index);
+                                    let modified: chrono::DateTime<chrono::Local> = result
+                                        .modified
+                                        .into();
+                                    let mut text = format!(
+                                        "{} {}  ·  {}  ·  {}", if result.is_directory { "📁" }
+                                        else { "📄" }, result.path, if result.is_directory {
+                                        String::new() } else { self.format_file_size(result
+                                        .size) }, modified.format("%Y-%m-%d %H:%M")
+                                    );
+                                    if let Some((line_number, line)) = &result.matched_line {
+                                        text.push_str(&format!("  ·  {}: {}", line_number, line));
+                                    }
+                                    let response = ui
+                                        .selectable_label(
+                                            is_selected,
+                                            egui::RichText::new(text).monospace(),
+                                        );
+                                    if response.clicked() {
+                                        self.file_search.selected_result = Some(index);
+                                    }
+                                    if response.double_clicked() {
+                                        action = Some((FileSearchAction::Jump, index));
+                                    }
+                                }
+                            },
+                        );
+                },
+            );
+        if let Some((kind, index)) = action {
+            self.handle_file_search_action(kind, index, ctx);
+        }
+        if !open && self.file_search.is_running {
+            self.cancel_file_search();
+        }
+        self.file_search.is_open = open && self.file_search.is_open;
+    }
+    fn handle_file_search_action(
+        &mut self,
+        kind: FileSearchAction,
+        index: usize,
+        ctx: &egui::Context,
+    ) {
+        let Some(result) = self.file_search.results.get(index).cloned() else {
+            return;
+        };
+        match kind {
+            FileSearchAction::Jump => self.jump_to_search_result(&result),
+            FileSearchAction::View => {
+                if result.is_directory {
+                    self.jump_to_search_result(&result);
+                } else if let Some((line_number, _)) = result.matched_line {
+                    self.open_file_viewer_at_line(&result.path, line_number);
+                } else {
+                    self.open_file_viewer(&result.path);
+                }
+            }
+            FileSearchAction::CopyPath => {
+                ctx.copy_text(result.path.clone());
+            }
+            FileSearchAction::Copy => {
+                if self.filepane_active_tab >= self.filepane_tabs.len() {
+                    return;
+                }
+                let tab = &self.filepane_tabs[self.filepane_active_tab];
+                let destination = if self.file_search.source_panel == 0 {
+                    tab.right_path.clone()
+                } else {
+                    tab.left_path.clone()
+                };
+                let command = FilepaneCommand::CopyFile {
+                    source: result.path.clone(),
+                    destination: destination.clone(),
+                    speed_limit: None,
+                };
+                self.request_file_operation_confirmation(
+                    &command,
+                    format!(
+                        "Are you sure you want to copy:\n\n{}\n\nto:\n\n{}", result.path,
+                        destination
+                    ),
+                );
+            }
+            FileSearchAction::Delete => {
+                let command = FilepaneCommand::DeleteFile {
+                    path: result.path.clone(),
+                };
+                self.request_file_operation_confirmation(
+                    &command,
+                    format!(
+                        "⚠️ DANGER: Are you sure you want to delete:\n\n{}\n\n{}", result.path,
+                        Self::delete_consequence(std::slice::from_ref(&result.path))
+                    ),
+                );
+            }
+        }
+    }
+    fn jump_to_search_result(&mut self, result: &FileSearchResult) {
+        if self.filepane_active_tab >= self.filepane_tabs.len() {
+            return;
+        }
+        let parent = std::path::Path::new(&result.path)
+            .parent()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.file_search.root_path.clone());
+        let tab = &mut self.filepane_tabs[self.filepane_active_tab];
+        if self.file_search.source_panel == 0 {
+            tab.left_path = parent;
+            tab.selected_left = vec![result.name.clone()];
+            tab.left_checkboxes.clear();
+        } else {
+            tab.right_path = parent;
+            tab.selected_right = vec![result.name.clone()];
+            tab.right_checkboxes.clear();
+        }
+        self.view_mode = ViewMode::Filepane;
+    }
+    fn start_file_search(&mut self) {
+        self.cancel_file_search();
+        self.file_search.error_msg = None;
+        let root = std::path::PathBuf::from(self.file_search.root_path.trim());
+        if !root.is_dir() {
+            self.file_search.error_msg = Some(
+                format!("Not a directory: {}", root.display()),
+            );
+            return;
+        }
+        let criteria = match self.build_search_criteria() {
+            Ok(criteria) => criteria,
+            Err(e) => {
+                self.file_search.error_msg = Some(e);
+                return;
+            }
+        };
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let worker_cancel = cancel_flag.clone();
+        std::thread::spawn(move || {
+            Self::run_file_search(root, criteria, sender, worker_cancel);
+        });
+        self.file_search.results.clear();
+        self.file_search.selected_result = None;
+        self.file_search.scanned_dirs = 0;
+        self.file_search.scanned_files = 0;
+        self.file_search.receiver = Some(receiver);
+        self.file_search.cancel_flag = Some(cancel_flag);
+        self.file_search.is_running = true;
+        self.file_search.started_at = Some(std::time::Instant::now());
+        self.add_log(format!("🔎 FIND: Searching in {}", self.file_search.root_path));
+    }
+    fn cancel_file_search(&mut self) {
+        if let Some(flag) = &self.file_search.cancel_flag {
+            flag.store(true, Ordering::Relaxed);
+        }
+    }
+    fn poll_file_search(&mut self) {
+        let mut finished = None;
+        if let Some(receiver) = &self.file_search.receiver {
+            loop {
+                match receiver.try_recv() {
+                    Ok(FileSearchMessage::Found(result)) => {
+                        if self.file_search.results.len() < MAX_SEARCH_RESULTS {
+                            self.file_search.results.push(result);
+                        }
+                    }
+                    Ok(FileSearchMessage::Progress { scanned_dirs, scanned_files }) => {
+                        self.file_search.scanned_dirs = scanned_dirs;
+                        self.file_search.scanned_files = scanned_files;
+                    }
+                    Ok(FileSearchMessage::Finished { cancelled }) => {
+                        finished = Some(cancelled);
+                        break;
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        finished = Some(true);
+                        break;
+                    }
+                }
+            }
+        }
+        if self.file_search.is_running
+            && self.file_search.results.len() >= MAX_SEARCH_RESULTS
+        {
+            self.cancel_file_search();
+            self.file_search.error_msg = Some(
+                format!("Stopped after {} results - narrow the search", MAX_SEARCH_RESULTS),
+            );
+        }
+        if let Some(cancelled) = finished {
+            self.file_search.is_running = false;
+            self.file_search.receiver = None;
+            self.file_search.cancel_flag = None;
+            self.add_log(
+                format!(
+                    "🔎 FIND: {} with {} match(es) after scanning {} files", if
+                    cancelled { "Stopped" } else { "Finished" }, self.file_search
+                    .results.len(), self.file_search.scanned_files
+                ),
+            );
+        }
+    }
+    fn build_search_criteria(&self) -> Result<SearchCriteria, String> {
+        let case_flag = if self.file_search.case_sensitive { "" } else { "(?i)" };
+        let name = self.file_search.name_pattern.trim();
+        let name_regex = if name.is_empty() {
+            None
+        } else {
+            let pattern = if self.file_search.use_regex {
+                name.to_string()
+            } else {
+                Self::glob_to_regex(name)
+            };
+            Some(
+                regex::Regex::new(&format!("{}{}", case_flag, pattern))
+                    .map_err(|e| format!("Invalid name pattern: {}", e))?,
+            )
+        };
+        let content = &self.file_search.content_pattern;
+        let content_regex = if content.is_empty() {
+            None
+        } else {
+            let pattern = if self.file_search.use_regex {
+                content.to_string()
+            } else {
+                regex::escape(content)
+            };
+            Some(
+                regex::bytes::Regex::new(&format!("{}{}", case_flag, pattern))
+                    .map_err(|e| format!("Invalid content pattern: {}", e))?,
+            )
+        };
+        Ok(SearchCriteria {
+            name_regex,
+            content_regex,
+            min_size: Self::parse_size_input(&self.file_search.min_size)
+                .map_err(|e| format!("Invalid minimum size: {}", e))?,
+            max_size: Self::parse_size_input(&self.file_search.max_size)
+                .map_err(|e| format!("Invalid maximum size: {}", e))?,
+            modified_after: Self::parse_date_input(&self.file_search.modified_after)
+                .map_err(|e| format!("Invalid 'after' date: {}", e))?,
+            modified_before: Self::parse_date_input(&self.file_search.modified_before)
+                .map_err(|e| format!("Invalid 'before' date: {}", e))?,
+            include_directories: self.file_search.include_directories,
+        })
+    }
+    /// Converts a shell-style glob (`*`, `?`) into an anchored regex. A glob
+    /// without wildcards matches anywhere in the name.
+    fn glob_to_regex(glob: &str) -> String {
+        if !glob.contains('*') && !glob.contains('?') {
+            return regex::escape(glob);
+        }
+        let mut pattern = String::from("^");
+        for c in glob.chars() {
+            match c {
+                '*' => pattern.push_str(".*"),
+                '?' => pattern.push('.'),
+                _ => pattern.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        pattern.push('$');
+        pattern
+    }
+    /// Parses sizes such as `512`, `10K`, `1.5MB` or `2G` (binary units).
+    pub fn parse_size_input(input: &str) -> Result<Option<u64>, String> {
+        let input = input.trim().to_uppercase();
+        if input.is_empty() {
+            return Ok(None);
+        }
+        let number = input.trim_end_matches('B');
+        let (digits, multiplier) = match number.chars().last() {
+            Some('K') => (&number[..number.len() - 1], 1024.0),
+            Some('M') => (&number[..number.len() - 1], 1024.0 * 1024.0),
+            Some('G') => (&number[..number.len() - 1], 1024.0 * 1024.0 * 1024.0),
+            Some('T') => (&number[..number.len() - 1], 1024.0 * 1024.0 * 1024.0 * 1024.0),
+            _ => (number, 1.0),
+        };
+        digits
+            .trim()
+            .parse::<f64>()
+            .map(|value| Some((value * multiplier) as u64))
+            .map_err(|_| format!("'{}'", input))
+    }
+    fn parse_date_input(input: &str) -> Result<Option<std::time::SystemTime>, String> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Ok(None);
+        }
+        let date = chrono::NaiveDate::parse_from_str(input, "%Y-%m-%d")
+            .map_err(|_| format!("'{}' (expected YYYY-MM-DD)", input))?;
+        let local = date
+            .and_hms_opt(0, 0, 0)
+            .and_then(|dt| dt.and_local_timezone(chrono::Local).earliest())
+            .ok_or_else(|| format!("'{}'", input))?;
+        Ok(Some(local.into()))
+    }
+    fn run_file_search(
+        root: std::path::PathBuf,
+        criteria: SearchCriteria,
+        sender: Sender<FileSearchMessage>,
+        cancel_flag: Arc<AtomicBool>,
+    ) {
+        let mut stack = vec![root];
+        let mut scanned_dirs = 0;
+        let mut scanned_files = 0;
+        while let Some(dir) = stack.pop() {
+            if cancel_flag.load(Ordering::Relaxed) {
+                let _ = sender.send(FileSearchMessage::Finished { cancelled: true });
+                return;
+            }
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            scanned_dirs += 1;
+            for entry in entries.flatten() {
+                if cancel_flag.load(Ordering::Relaxed) {
+                    break;
+                }
+                // DirEntry::metadata does not follow symlinks, so linked
+                // directories are listed but never descended into.
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                let path = entry.path();
+                let is_directory = metadata.is_dir();
+                if is_directory {
+                    stack.push(path.clone());
+                    if !criteria.include_directories
+                        || criteria.content_regex.is_some()
+                    {
+                        continue;
+                    }
+                } else {
+                    scanned_files += 1;
+                    if scanned_files % PROGRESS_EVERY_FILES == 0 {
+                        let _ = sender
+                            .send(FileSearchMessage::Progress {
+                                scanned_dirs,
+                                scanned_files,
+                            });
+                    }
+                }
+                let name = entry.file_name().to_string_lossy().to_string();
+                if let Some(regex) = &criteria.name_regex {
+                    if !regex.is_match(&name) {
+                        continue;
+                    }
+                }
+                let size = if is_directory { 0 } else { metadata.len() };
+                if criteria.min_size.map_or(false, |min| size < min)
+                    || criteria.max_size.map_or(false, |max| size > max)
+                {
+                    continue;
+                }
+                let modified = metadata.modified().unwrap_or(std::time::UNIX_EPOCH);
+                if criteria.modified_after.map_or(false, |after| modified < after)
+                    || criteria.modified_before.map_or(false, |before| modified >= before)
+                {
+                    continue;
+                }
+                let matched_line = match &criteria.content_regex {
+                    Some(regex) => {
+                        match Self::find_content_match(&path, regex) {
+                            Some(found) => Some(found),
+                            None => continue,
+                        }
+                    }
+                    None => None,
+                };
+                let result = FileSearchResult {
+                    path: path.to_string_lossy().to_string(),
+                    name,
+                    size,
+                    modified,
+                    is_directory,
+                    matched_line,
+                };
+                if sender.send(FileSearchMessage::Found(result)).is_err() {
+                    return;
+                }
+            }
+        }
+        let _ = sender
+            .send(FileSearchMessage::Progress {
+                scanned_dirs,
+                scanned_files,
+            });
+        let cancelled = cancel_flag.load(Ordering::Relaxed);
+        let _ = sender.send(FileSearchMessage::Finished { cancelled });
+    }
+    /// Returns the first matching line (1-based number and snippet), skipping
+    /// files that look binary.
+    fn find_content_match(
+        path: &std::path::Path,
+        regex: &regex::bytes::Regex,
+    ) -> Option<(usize, String)> {
+        let mut file = std::fs::File::open(path).ok()?;
+        let mut head = Vec::new();
+        (&mut file).take(BINARY_SNIFF_BYTES).read_to_end(&mut head).ok()?;
+        if head.contains(&0) {
+            return None;
+        }
+        let mut reader = BufReader::new(std::io::Cursor::new(head).chain(file));
+        let mut line = Vec::new();
+        let mut line_number = 0;
+        loop {
+            line.clear();
+            let read = (&mut reader)
+                .take(MAX_LINE_BYTES as u64)
+                .read_until(b'\n', &mut line)
+                .ok()?;
+            if read == 0 {
+                return None;
+            }
+            line_number += 1;
+            if line.last() == Some(&b'\n') {
+                line.pop();
+            } else if read == MAX_LINE_BYTES {
+                Self::skip_rest_of_line(&mut reader)?;
+            }
+            if regex.is_match(&line) {
+                let snippet: String = String::from_utf8_lossy(&line)
+                    .trim()
+                    .chars()
+                    .take(MATCH_SNIPPET_CHARS)
+                    .collect();
+                return Some((line_number, snippet));
+            }
+        }
+    }
+    fn skip_rest_of_line(reader: &mut impl BufRead) -> Option<()> {
+        loop {
+            let buffer = reader.fill_buf().ok()?;
+            if buffer.is_empty() {
+                return Some(());
+            }
+            match buffer.iter().position(|&byte| byte == b'\n') {
+                Some(end) => {
+                    reader.consume(end + 1);
+                    return Some(());
+                }
+                None => {
+                    let length = buffer.len();
+                    reader.consume(length);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glob_matches(glob: &str, name: &str) -> bool {
+        regex::Regex::new(&ProcessManagerApp::glob_to_regex(glob)).unwrap().is_match(name)
+    }
+
+    #[test]
+    fn glob_to_regex_anchors_wildcards() {
+        assert!(glob_matches("*.rs", "main.rs"));
+        assert!(!glob_matches("*.rs", "main.rs.bak"));
+        assert!(glob_matches("file?.txt", "file1.txt"));
+        assert!(!glob_matches("file?.txt", "file10.txt"));
+        // Regex metacharacters in the glob are literal.
+        assert!(glob_matches("a+b(1).*", "a+b(1).log"));
+        assert!(!glob_matches("a+b(1).*", "aab1x.log"));
+        // Without wildcards the text may appear anywhere.
+        assert!(glob_matches("report", "annual-report-2024.pdf"));
+        assert!(glob_matches("v1.2", "app-v1.2.zip"));
+        assert!(!glob_matches("v1.2", "app-v1x2.zip"));
+    }
+
+    #[test]
+    fn parse_size_input_units() {
+        assert_eq!(ProcessManagerApp::parse_size_input(""), Ok(None));
+        assert_eq!(ProcessManagerApp::parse_size_input("  "), Ok(None));
+        assert_eq!(ProcessManagerApp::parse_size_input("512"), Ok(Some(512)));
+        assert_eq!(ProcessManagerApp::parse_size_input("10K"), Ok(Some(10 * 1024)));
+        assert_eq!(ProcessManagerApp::parse_size_input("10kb"), Ok(Some(10 * 1024)));
+        assert_eq!(ProcessManagerApp::parse_size_input("1.5MB"), Ok(Some(1536 * 1024)));
+        assert_eq!(ProcessManagerApp::parse_size_input(" 2 G "), Ok(Some(2 << 30)));
+        assert_eq!(ProcessManagerApp::parse_size_input("1T"), Ok(Some(1 << 40)));
+        assert!(ProcessManagerApp::parse_size_input("ten").is_err());
+        assert!(ProcessManagerApp::parse_size_input("5X").is_err());
+        assert!(ProcessManagerApp::parse_size_input("K").is_err());
+    }
+}
@@ -0,0 +1,774 @@
+use crate::ws::FileViewerIndexMessage;
+use crate::ws::FileViewerMode;
+use crate::ws::FileViewerState;
+use crate::ws::TextEncoding;
+// # ProcessManagerApp - show_file_viewer_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// The viewer never reads a whole file: text is paged by line and hex by byte
+// range, so multi-GB logs open as fast as small files. Jumping far ahead
+// (the last page, a distant line) needs every page offset up to the target;
+// those are found on a background thread while the current page stays
+// visible.
+
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use super::processmanagerapp_type::ProcessManagerApp;
+
+const TEXT_PAGE_LINES: usize = 500;
+const MAX_LINE_BYTES: usize = 16 * 1024;
+const HEX_BYTES_PER_ROW: u64 = 16;
+const HEX_PAGE_ROWS: u64 = 64;
+const ENCODING_SAMPLE_BYTES: u64 = 64 * 1024;
+/// Page offsets the indexer collects before handing them to the UI.
+const INDEX_BATCH_PAGES: usize = 64;
+const MAX_IMAGE_BYTES: u64 = 64 * 1024 * 1024;
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "bmp", "ico"];
+
+impl ProcessManagerApp {
+    pub fn open_file_viewer(&mut self, path: &str) {
+        let mut viewer = FileViewerState::default();
+        viewer.path = path.to_string();
+        viewer.is_open = true;
+        let is_image = std::path::Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| IMAGE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+            .unwrap_or(false);
+        match Self::inspect_file_for_viewer(path) {
+            Ok((file_size, encoding, is_binary)) => {
+                viewer.file_size = file_size;
+                viewer.encoding = encoding;
+                viewer.mode = if is_image {
+                    FileViewerMode::Image
+                } else if is_binary {
+                    FileViewerMode::Hex
+                } else {
+                    FileViewerMode::Text
+                };
+            }
+            Err(e) => {
+                viewer.error_msg = Some(format!("Cannot open file: {}", e));
+            }
+        }
+        let mode = viewer.mode;
+        self.file_viewer = viewer;
+        self.reload_file_viewer_page();
+        self.add_log(format!("👁 VIEW: Opened {} in {} mode", path, mode.name()));
+    }
+    pub fn open_file_viewer_at_line(&mut self, path: &str, line: usize) {
+        self.open_file_viewer(path);
+        if self.file_viewer.mode == FileViewerMode::Text && line > 0 {
+            self.file_viewer.goto_line_input = line.to_string();
+            self.file_viewer.highlight_line = Some(line);
+            self.load_file_viewer_text_page((line - 1) / TEXT_PAGE_LINES);
+        }
+    }
+    pub(crate) fn show_file_viewer(&mut self, ctx: &egui::Context) {
+        let mut open = self.file_viewer.is_open;
+        let file_name = std::path::Path::new(&self.file_viewer.path)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("")
+            .to_string();
+        if self.file_viewer.mode == FileViewerMode::Image
+            && self.file_viewer.image_texture.is_none()
+            && self.file_viewer.error_msg.is_none()
+        {
+            self.load_file_viewer_image(ctx);
+        }
+        egui::Window::new(format!("👁 {}", file_name))
+            .id(egui::Id::new("file_viewer_window"))
+            .open(&mut open)
+            .default_size([800.0, 600.0])
+            .min_width(500.0)
+            .min_height(300.0)
+            .resizable(true)
+            .show(
+                ctx,
+                |ui| {
+                    ui.horizontal(|ui| {
+                        for mode in [
+                            FileViewerMode::Text,
+                            FileViewerMode::Hex,
+                            FileViewerMode::Image,
+                        ] {
+                            if ui
+                                .selectable_label(self.file_viewer.mode == mode, mode.name())
+                                .clicked() && self.file_viewer.mode != mode
+                            {
+                                self.file_viewer.mode = mode;
+                                self.file_viewer.error_msg = None;
+                                self.reload_file_viewer_page();
+                            }
+                        }
+                        ui.separator();
+                        ui.label(
+                            format!(
+                                "Size: {}", self.format_file_size(self.file_viewer
+                                .file_size)
+                            ),
+                        );
+                        if self.file_viewer.mode == FileViewerMode::Text {
+                            ui.separator();
+                            let mut encoding = self.file_viewer.encoding;
+                            egui::ComboBox::from_id_salt("file_viewer_encoding")
+                                .selected_text(encoding.name())
+                                .show_ui(
+                                    ui,
+                                    |ui| {
+                                        for candidate in [
+                                            TextEncoding::Utf8,
+                                            TextEncoding::Utf8Bom,
+                                            TextEncoding::Utf16Le,
+                                            TextEncoding::Utf16Be,
+                                            TextEncoding::Latin1,
+                                        ] {
+                                            ui.selectable_value(
+                                                &mut encoding,
+                                                candidate,
+                                                candidate.name(),
+                                            );
+                                        }
+                                    },
+                                );
+                            if encoding != self.file_viewer.encoding {
+                                self.file_viewer.encoding = encoding;
+                                self.file_viewer.text_page = 0;
+                                self.file_viewer.text_page_offsets.clear();
+                                self.file_viewer.text_reached_eof = false;
+                                self.file_viewer.text_indexer = None;
+                                self.file_viewer.pending_page = None;
+                                self.reload_file_viewer_page();
+                            }
+                        }
+                        ui.with_layout(
+                            egui::Layout::right_to_left(egui::Align::Center),
+                            |ui| {
+                                if ui.button("📋 Copy Path").clicked() {
+                                    ui.ctx().copy_text(self.file_viewer.path.clone());
+                                }
+                            },
+                        );
+                    });
+                    ui.separator();
+                    if let Some(error) = &self.file_viewer.error_msg {
+                        ui.colored_label(egui::Color32::RED, format!("❌ {}", error));
+                        return;
+                    }
+                    match self.file_viewer.mode {
+                        FileViewerMode::Text => self.show_file_viewer_text(ui),
+                        FileViewerMode::Hex => self.show_file_viewer_hex(ui),
+                        FileViewerMode::Image => self.show_file_viewer_image(ui),
+                    }
+                },
+            );
+        self.file_viewer.is_open = open;
+        if !open {
+            self.file_viewer = FileViewerState::default();
+        }
+    }
+    fn show_file_viewer_text(&mut self, ui: &mut egui::Ui) {
+        let indexed_page_loaded = self.poll_file_viewer_index();
+        if self.file_viewer.pending_page.is_some() {
+            ui.ctx().request_repaint_after(std::time::Duration::from_millis(100));
+        }
+        let mut target_page = None;
+        ui.horizontal(|ui| {
+            let page = self.file_viewer.text_page;
+            if ui.add_enabled(page > 0, egui::Button::new("⏮ First")).clicked() {
+                target_page = Some(0);
+            }
+            if ui.add_enabled(page > 0, egui::Button::new("◀ Prev")).clicked() {
+                target_page = Some(page - 1);
+            }
+            let has_next = page + 1 < self.file_viewer.text_page_offsets.len()
+                || !self.file_viewer.text_reached_eof;
+            if ui.add_enabled(has_next, egui::Button::new("Next ▶")).clicked() {
+                target_page = Some(page + 1);
+            }
+            if ui
+                .add_enabled(has_next, egui::Button::new("⏭ Last"))
+                .on_hover_text("Finds the last page in the background")
+                .clicked()
+            {
+                target_page = Some(usize::MAX);
+            }
+            let first_line = page * TEXT_PAGE_LINES + 1;
+            let last_line = first_line + self.file_viewer.text_lines.len().saturating_sub(1);
+            if self.file_viewer.text_reached_eof {
+                ui.label(
+                    format!(
+                        "Lines {}-{} · Page {}/{}", first_line, last_line, page + 1, self
+                        .file_viewer.text_page_offsets.len()
+                    ),
+                );
+            } else {
+                ui.label(
+                    format!("Lines {}-{} · Page {}", first_line, last_line, page + 1),
+                );
+            }
+            if self.file_viewer.pending_page.is_some() {
+                ui.spinner();
+                ui.weak(
+                    format!("Indexing… {} pages", self.file_viewer.text_page_offsets.len()),
+                );
+            }
+            ui.separator();
+            ui.label("Go to line:");
+            let response = ui
+                .add(
+                    egui::TextEdit::singleline(&mut self.file_viewer.goto_line_input)
+                        .desired_width(80.0),
+                );
+            if ui.button("Go").clicked()
+                || (response.lost_focus()
+                    && ui.input(|i| i.key_pressed(egui::Key::Enter)))
+            {
+                match self.file_viewer.goto_line_input.trim().parse::<usize>() {
+                    Ok(line) if line > 0 => {
+                        self.file_viewer.highlight_line = Some(line);
+                        target_page = Some((line - 1) / TEXT_PAGE_LINES);
+                    }
+                    _ => {
+                        self.file_viewer.highlight_line = None;
+                    }
+                }
+            }
+        });
+        if let Some(page) = target_page {
+            self.load_file_viewer_text_page(page);
+        }
+        ui.separator();
+        let first_line = self.file_viewer.text_page * TEXT_PAGE_LINES + 1;
+        let number_width = (first_line + TEXT_PAGE_LINES).to_string().len();
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(
+                ui,
+                |ui| {
+                    for (i, line) in self.file_viewer.text_lines.iter().enumerate() {
+                        let line_number = first_line + i;
+                        let is_highlighted = self.file_viewer.highlight_line
+                            == Some(line_number);
+                        let response = ui
+                            .horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new(
+                                            format!("{:>width$}", line_number, width = number_width),
+                                        )
+                                        .monospace()
+                                        .color(egui::Color32::GRAY),
+                                );
+                                let text = egui::RichText::new(line).monospace();
+                                if is_highlighted {
+                                    ui.label(
+                                        text.background_color(egui::Color32::from_rgb(90, 80, 20)),
+                                    );
+                                } else {
+                                    ui.label(text);
+                                }
+                            })
+                            .response;
+                        if is_highlighted && (target_page.is_some() || indexed_page_loaded) {
+                            response.scroll_to_me(Some(egui::Align::Center));
+                        }
+                    }
+                    if self.file_viewer.text_lines.is_empty() {
+                        ui.label("(empty)");
+                    }
+                },
+            );
+    }
+    fn show_file_viewer_hex(&mut self, ui: &mut egui::Ui) {
+        let page_bytes = HEX_BYTES_PER_ROW * HEX_PAGE_ROWS;
+        let last_row = self.file_viewer.file_size.saturating_sub(1) / HEX_BYTES_PER_ROW
+            * HEX_BYTES_PER_ROW;
+        let last_page_offset = last_row
+            .saturating_sub((HEX_PAGE_ROWS - 1) * HEX_BYTES_PER_ROW);
+        let mut target_offset = None;
+        ui.horizontal(|ui| {
+            let offset = self.file_viewer.hex_offset;
+            if ui.add_enabled(offset > 0, egui::Button::new("⏮ Start")).clicked() {
+                target_offset = Some(0);
+            }
+            if ui.add_enabled(offset > 0, egui::Button::new("◀ Page")).clicked() {
+                target_offset = Some(offset.saturating_sub(page_bytes));
+            }
+            if ui
+                .add_enabled(offset < last_page_offset, egui::Button::new("Page ▶"))
+                .clicked()
+            {
+                target_offset = Some((offset + page_bytes).min(last_page_offset));
+            }
+            if ui
+                .add_enabled(offset < last_page_offset, egui::Button::new("⏭ End"))
+                .clicked()
+            {
+                target_offset = Some(last_page_offset);
+            }
+            ui.label(
+                format!(
+                    "Offset 0x{:08X} / 0x{:08X}", offset, self.file_viewer.file_size
+                ),
+            );
+            ui.separator();
+            ui.label("Go to offset:");
+            let response = ui
+                .add(
+                    egui::TextEdit::singleline(&mut self.file_viewer.hex_offset_input)
+                        .hint_text("0x1F00 or 7936")
+                        .desired_width(110.0),
+                );
+            if ui.button("Go").clicked()
+                || (response.lost_focus()
+                    && ui.input(|i| i.key_pressed(egui::Key::Enter)))
+            {
+                let input = self.file_viewer.hex_offset_input.trim().to_string();
+                let parsed = if let Some(hex) = input
+                    .strip_prefix("0x")
+                    .or_else(|| input.strip_prefix("0X"))
+                {
+                    u64::from_str_radix(hex, 16).ok()
+                } else {
+                    input.parse::<u64>().ok()
+                };
+                if let Some(value) = parsed {
+                    let row = value.min(last_row) / HEX_BYTES_PER_ROW * HEX_BYTES_PER_ROW;
+                    target_offset = Some(row);
+                }
+            }
+        });
+        if let Some(offset) = target_offset {
+            self.file_viewer.hex_offset = offset;
+            self.reload_file_viewer_page();
+        }
+        ui.separator();
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(
+                ui,
+                |ui| {
+                    for (row_index, row) in self
+                        .file_viewer
+                        .hex_bytes
+                        .chunks(HEX_BYTES_PER_ROW as usize)
+                        .enumerate()
+                    {
+                        let row_offset = self.file_viewer.hex_offset
+                            + row_index as u64 * HEX_BYTES_PER_ROW;
+                        let mut hex = String::new();
+                        for i in 0..HEX_BYTES_PER_ROW as usize {
+                            if i == 8 {
+                                hex.push(' ');
+                            }
+                            match row.get(i) {
+                                Some(byte) => hex.push_str(&format!("{:02X} ", byte)),
+                                None => hex.push_str("   "),
+                            }
+                        }
+                        let ascii: String = row
+                            .iter()
+                            .map(|&b| {
+                                if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' }
+                            })
+                            .collect();
+                        ui.horizontal(|ui| {
+                            ui.label(
+                                egui::RichText::new(format!("{:08X}", row_offset))
+                                    .monospace()
+                                    .color(egui::Color32::GRAY),
+                            );
+                            ui.label(egui::RichText::new(hex).monospace());
+                            ui.label(egui::RichText::new(ascii).monospace());
+                        });
+                    }
+                    if self.file_viewer.hex_bytes.is_empty() {
+                        ui.label("(empty)");
+                    }
+                },
+            );
+    }
+    fn show_file_viewer_image(&mut self, ui: &mut egui::Ui) {
+        if let Some(texture) = &self.file_viewer.image_texture {
+            let size = texture.size_vec2();
+            ui.label(format!("{} × {} px", size.x as u32, size.y as u32));
+            let available = ui.available_size();
+            let scale = (available.x / size.x).min(available.y / size.y).min(1.0);
+            ui.centered_and_justified(|ui| {
+                ui.add(egui::Image::new(texture).fit_to_exact_size(size * scale));
+            });
+        } else {
+            ui.label("Loading image...");
+        }
+    }
+    fn reload_file_viewer_page(&mut self) {
+        if self.file_viewer.error_msg.is_some() {
+            return;
+        }
+        match self.file_viewer.mode {
+            FileViewerMode::Text => {
+                let page = self.file_viewer.text_page;
+                self.load_file_viewer_text_page(page);
+            }
+            FileViewerMode::Hex => {
+                let page_bytes = HEX_BYTES_PER_ROW * HEX_PAGE_ROWS;
+                match Self::read_file_range(
+                    &self.file_viewer.path,
+                    self.file_viewer.hex_offset,
+                    page_bytes,
+                ) {
+                    Ok(bytes) => self.file_viewer.hex_bytes = bytes,
+                    Err(e) => {
+                        self.file_viewer.error_msg = Some(format!("Read failed: {}", e));
+                    }
+                }
+            }
+            FileViewerMode::Image => {}
+        }
+    }
+    fn load_file_viewer_text_page(&mut self, page: usize) {
+        let viewer = &mut self.file_viewer;
+        if viewer.text_page_offsets.is_empty() {
+            match Self::text_start(&viewer.path, viewer.encoding) {
+                Ok(start) => viewer.text_page_offsets.push(start),
+                Err(e) => {
+                    viewer.error_msg = Some(format!("Read failed: {}", e));
+                    return;
+                }
+            }
+        }
+        // Page offsets are discovered lazily. The next unknown page is read
+        // here; anything further is left to the background indexer.
+        if page > viewer.text_page_offsets.len() && !viewer.text_reached_eof {
+            viewer.pending_page = Some(page);
+            if viewer.text_indexer.is_none() {
+                let (sender, receiver) = std::sync::mpsc::channel();
+                let path = viewer.path.clone();
+                let encoding = viewer.encoding;
+                std::thread::spawn(move || {
+                    let message = match Self::index_text_pages(&path, encoding, &sender) {
+                        Ok(()) => FileViewerIndexMessage::Finished,
+                        Err(e) => FileViewerIndexMessage::Failed(e.to_string()),
+                    };
+                    let _ = sender.send(message);
+                });
+                viewer.text_indexer = Some(receiver);
+            }
+            return;
+        }
+        viewer.pending_page = None;
+        while viewer.text_page_offsets.len() <= page && !viewer.text_reached_eof {
+            let start = *viewer.text_page_offsets.last().unwrap_or(&0);
+            match Self::read_text_lines(
+                &viewer.path,
+                start,
+                viewer.encoding,
+                TEXT_PAGE_LINES,
+                false,
+            ) {
+                Ok((_, next_offset, at_eof)) => {
+                    if at_eof {
+                        viewer.text_reached_eof = true;
+                    } else {
+                        viewer.text_page_offsets.push(next_offset);
+                    }
+                }
+                Err(e) => {
+                    viewer.error_msg = Some(format!("Read failed: {}", e));
+                    return;
+                }
+            }
+        }
+        let page = page.min(viewer.text_page_offsets.len() - 1);
+        match Self::read_text_lines(
+            &viewer.path,
+            viewer.text_page_offsets[page],
+            viewer.encoding,
+            TEXT_PAGE_LINES,
+            true,
+        ) {
+            Ok((lines, next_offset, at_eof)) => {
+                viewer.text_page = page;
+                viewer.text_lines = lines;
+                if at_eof {
+                    viewer.text_reached_eof = true;
+                } else if viewer.text_page_offsets.len() == page + 1 {
+                    viewer.text_page_offsets.push(next_offset);
+                }
+            }
+            Err(e) => {
+                viewer.error_msg = Some(format!("Read failed: {}", e));
+            }
+        }
+    }
+    /// Merges page offsets from the indexer and loads the pending page once
+    /// it is known. Returns whether it was loaded this frame.
+    fn poll_file_viewer_index(&mut self) -> bool {
+        let viewer = &mut self.file_viewer;
+        let Some(receiver) = &viewer.text_indexer else {
+            return false;
+        };
+        let mut finished = false;
+        loop {
+            match receiver.try_recv() {
+                Ok(FileViewerIndexMessage::Pages { first, offsets }) => {
+                    let known = viewer.text_page_offsets.len();
+                    viewer.text_page_offsets.extend(offsets.into_iter().skip(known.saturating_sub(first)));
+                }
+                Ok(FileViewerIndexMessage::Finished) => {
+                    viewer.text_reached_eof = true;
+                    finished = true;
+                }
+                Ok(FileViewerIndexMessage::Failed(e)) => {
+                    viewer.error_msg = Some(format!("Read failed: {}", e));
+                    viewer.pending_page = None;
+                    finished = true;
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => break,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    finished = true;
+                    break;
+                }
+            }
+        }
+        if finished {
+            viewer.text_indexer = None;
+        }
+        match viewer.pending_page {
+            Some(page) if page < viewer.text_page_offsets.len() || viewer.text_reached_eof => {
+                self.load_file_viewer_text_page(page);
+                true
+            }
+            _ => false,
+        }
+    }
+    /// Runs on the indexer thread: finds the offset of every page of the
+    /// file and sends them in batches. Stops early once the viewer drops
+    /// the receiver.
+    fn index_text_pages(
+        path: &str,
+        encoding: TextEncoding,
+        sender: &std::sync::mpsc::Sender<FileViewerIndexMessage>,
+    ) -> std::io::Result<()> {
+        let start = Self::text_start(path, encoding)?;
+        let mut file = std::fs::File::open(path)?;
+        file.seek(SeekFrom::Start(start))?;
+        let mut reader = BufReader::with_capacity(256 * 1024, file);
+        let mut raw = Vec::new();
+        let mut offset = start;
+        let mut first = 0;
+        let mut batch = vec![start];
+        loop {
+            for _ in 0..TEXT_PAGE_LINES {
+                raw.clear();
+                let consumed = Self::read_raw_line(&mut reader, encoding, &mut raw)?;
+                if consumed == 0 {
+                    break;
+                }
+                offset += consumed;
+            }
+            if reader.fill_buf()?.is_empty() {
+                let _ = sender.send(FileViewerIndexMessage::Pages { first, offsets: batch });
+                return Ok(());
+            }
+            batch.push(offset);
+            if batch.len() >= INDEX_BATCH_PAGES {
+                let offsets = std::mem::take(&mut batch);
+                let count = offsets.len();
+                if sender.send(FileViewerIndexMessage::Pages { first, offsets }).is_err() {
+                    return Ok(());
+                }
+                first += count;
+            }
+        }
+    }
+    fn load_file_viewer_image(&mut self, ctx: &egui::Context) {
+        if self.file_viewer.file_size > MAX_IMAGE_BYTES {
+            self.file_viewer.error_msg = Some(
+                format!(
+                    "Image is larger than {} - use Hex mode instead",
+                    self.format_file_size(MAX_IMAGE_BYTES)
+                ),
+            );
+            return;
+        }
+        let decoded = image::ImageReader::open(&self.file_viewer.path)
+            .map_err(|e| e.to_string())
+            .and_then(|reader| reader.with_guessed_format().map_err(|e| e.to_string()))
+            .and_then(|reader| reader.decode().map_err(|e| e.to_string()));
+        match decoded {
+            Ok(image) => {
+                let rgba = image.to_rgba8();
+                let size = [rgba.width() as usize, rgba.height() as usize];
+                let color_image = egui::ColorImage::from_rgba_unmultiplied(
+                    size,
+                    rgba.as_raw(),
+                );
+                self.file_viewer.image_texture = Some(
+                    ctx
+                        .load_texture(
+                            format!("file_viewer_{}", self.file_viewer.path),
+                            color_image,
+                            egui::TextureOptions::LINEAR,
+                        ),
+                );
+            }
+            Err(e) => {
+                self.file_viewer.error_msg = Some(format!("Cannot decode image: {}", e));
+            }
+        }
+    }
+    /// Returns the file size, the detected text encoding and whether the
+    /// sampled content looks binary.
+    fn inspect_file_for_viewer(
+        path: &str,
+    ) -> std::io::Result<(u64, TextEncoding, bool)> {
+        let file_size = std::fs::metadata(path)?.len();
+        let sample = Self::read_file_range(path, 0, ENCODING_SAMPLE_BYTES)?;
+        let encoding = if sample.starts_with(&[0xEF, 0xBB, 0xBF]) {
+            TextEncoding::Utf8Bom
+        } else if sample.starts_with(&[0xFF, 0xFE]) {
+            TextEncoding::Utf16Le
+        } else if sample.starts_with(&[0xFE, 0xFF]) {
+            TextEncoding::Utf16Be
+        } else {
+            match std::str::from_utf8(&sample) {
+                Ok(_) => TextEncoding::Utf8,
+                // A multi-byte sequence cut off by the sample boundary is still UTF-8.
+                Err(e) if e.error_len().is_none() => TextEncoding::Utf8,
+                Err(_) => TextEncoding::Latin1,
+            }
+        };
+        let is_utf16 = matches!(encoding, TextEncoding::Utf16Le | TextEncoding::Utf16Be);
+        let is_binary = !is_utf16 && sample.contains(&0);
+        Ok((file_size, encoding, is_binary))
+    }
+    /// Where the text of `path` begins: past the encoding's byte order mark
+    /// if the file starts with it, otherwise at the first byte.
+    fn text_start(path: &str, encoding: TextEncoding) -> std::io::Result<u64> {
+        let bom = encoding.bom();
+        let head = Self::read_file_range(path, 0, bom.len() as u64)?;
+        Ok(if !bom.is_empty() && head == bom { bom.len() as u64 } else { 0 })
+    }
+    fn read_file_range(path: &str, offset: u64, length: u64) -> std::io::Result<Vec<u8>> {
+        let mut file = std::fs::File::open(path)?;
+        file.seek(SeekFrom::Start(offset))?;
+        let mut buffer = Vec::new();
+        file.take(length).read_to_end(&mut buffer)?;
+        Ok(buffer)
+    }
+    /// Reads up to `max_lines` lines starting at byte `start`. Returns the
+    /// decoded lines (only when `collect` is set), the byte offset just past
+    /// the last line read and whether the end of the file was reached.
+    fn read_text_lines(
+        path: &str,
+        start: u64,
+        encoding: TextEncoding,
+        max_lines: usize,
+        collect: bool,
+    ) -> std::io::Result<(Vec<String>, u64, bool)> {
+        let mut file = std::fs::File::open(path)?;
+        file.seek(SeekFrom::Start(start))?;
+        let mut reader = BufReader::with_capacity(256 * 1024, file);
+        let mut lines = Vec::new();
+        let mut offset = start;
+        let mut raw = Vec::new();
+        for _ in 0..max_lines {
+            raw.clear();
+            let consumed = Self::read_raw_line(&mut reader, encoding, &mut raw)?;
+            if consumed == 0 {
+                return Ok((lines, offset, true));
+            }
+            offset += consumed;
+            if collect {
+                let mut line = Self::decode_line(&raw, encoding);
+                if consumed > raw.len() as u64 {
+                    line.push_str(" …");
+                }
+                lines.push(line);
+            }
+        }
+        let at_eof = reader.fill_buf()?.is_empty();
+        Ok((lines, offset, at_eof))
+    }
+    /// Reads one raw line including its terminator, keeping at most
+    /// `MAX_LINE_BYTES` of it in `raw`. Returns the number of bytes consumed.
+    fn read_raw_line<R: BufRead>(
+        reader: &mut R,
+        encoding: TextEncoding,
+        raw: &mut Vec<u8>,
+    ) -> std::io::Result<u64> {
+        let mut consumed = 0u64;
+        match encoding {
+            TextEncoding::Utf16Le | TextEncoding::Utf16Be => {
+                let newline = if encoding == TextEncoding::Utf16Le {
+                    [0x0A, 0x00]
+                } else {
+                    [0x00, 0x0A]
+                };
+                let mut unit = [0u8; 2];
+                loop {
+                    match reader.read_exact(&mut unit) {
+                        Ok(()) => {}
+                        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+                        Err(e) => return Err(e),
+                    }
+                    consumed += 2;
+                    if raw.len() < MAX_LINE_BYTES {
+                        raw.extend_from_slice(&unit);
+                    }
+                    if unit == newline {
+                        break;
+                    }
+                }
+            }
+            _ => {
+                loop {
+                    let buffer = reader.fill_buf()?;
+                    if buffer.is_empty() {
+                        break;
+                    }
+                    let (chunk_len, found_newline) = match buffer
+                        .iter()
+                        .position(|&b| b == b'\n')
+                    {
+                        Some(pos) => (pos + 1, true),
+                        None => (buffer.len(), false),
+                    };
+                    let room = MAX_LINE_BYTES.saturating_sub(raw.len());
+                    raw.extend_from_slice(&buffer[..chunk_len.min(room)]);
+                    reader.consume(chunk_len);
+                    consumed += chunk_len as u64;
+                    if found_newline {
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(consumed)
+    }
+    fn decode_line(raw: &[u8], encoding: TextEncoding) -> String {
+        let text = match encoding {
+            TextEncoding::Utf8 | TextEncoding::Utf8Bom => {
+                String::from_utf8_lossy(raw).to_string()
+            }
+            TextEncoding::Latin1 => raw.iter().map(|&b| b as char).collect(),
+            TextEncoding::Utf16Le | TextEncoding::Utf16Be => {
+                let units = raw
+                    .chunks_exact(2)
+                    .map(|pair| {
+                        if encoding == TextEncoding::Utf16Le {
+                            u16::from_le_bytes([pair[0], pair[1]])
+                        } else {
+                            u16::from_be_bytes([pair[0], pair[1]])
+                        }
+                    });
+                char::decode_utf16(units)
+                    .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+                    .collect()
+            }
+        };
+        text.trim_end_matches(['\n', '\r']).replace('\t', "    ")
+    }
+}
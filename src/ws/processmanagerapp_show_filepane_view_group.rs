@@ -3,6 +3,8 @@ use crate::ws::ContextAction;
 use crate::ws::ChecksumAlgorithm;
 use crate::egui::Theme;
 use crate::ws::FilepaneCommand;
+use crate::ws::CopyBatch;
+use crate::ws::{is_remote_path, join_panel_path, parent_directory};
 // # ProcessManagerApp - show_filepane_view_group Methods
 //
 // This module contains method implementations for `ProcessManagerApp`.
@@ -33,6 +35,13 @@ impl ProcessManagerApp {
                 );
             self.filepane_active_tab = 0;
         }
+        self.poll_directory_sizes(ui.ctx());
+        self.poll_directory_watches(ui.ctx());
+        self.track_filepane_navigation(ui.ctx());
+        self.handle_filepane_keys();
+        self.show_filepane_keyboard_windows(ui.ctx());
+        self.show_quick_jump(ui.ctx());
+        self.show_sftp_connect(ui.ctx());
         ui.horizontal(|ui| {
             ui.heading("📂 Filepane");
             ui.with_layout(
@@ -46,6 +55,38 @@ impl ProcessManagerApp {
                             )
                             .hint_text("type to filter..."),
                     );
+                    if ui
+                        .button("🔎 Find")
+                        .on_hover_text("Recursively search from the current panel")
+                        .clicked()
+                    {
+                        self.open_file_search();
+                    }
+                    if ui
+                        .button("📊 Usage")
+                        .on_hover_text("Analyze disk usage of the current panel")
+                        .clicked()
+                    {
+                        self.open_disk_usage();
+                    }
+                    if ui
+                        .button("🧬 Duplicates")
+                        .on_hover_text("Find duplicate files in the panels")
+                        .clicked()
+                    {
+                        self.open_duplicate_finder();
+                    }
+                    if ui
+                        .button("📏 Sizes")
+                        .on_hover_text("Calculate sizes of the listed directories")
+                        .clicked()
+                    {
+                        self.calculate_directory_sizes();
+                    }
+                    ui.checkbox(&mut self.filepane_one_filesystem, "🧱 One FS")
+                        .on_hover_text(
+                            "Keep recursive copy, delete and size calculation on the starting filesystem",
+                        );
                     ui.separator();
                     if ui
                         .button("↔️ Swap")
@@ -66,6 +107,22 @@ impl ProcessManagerApp {
                             tab.right_checkboxes.clear();
                         }
                     }
+                    if ui
+                        .button("⌨️ Keys")
+                        .on_hover_text("Show keyboard shortcuts")
+                        .clicked()
+                    {
+                        self.filepane_keyboard.show_help = true;
+                    }
+                    self.show_filepane_jump_menus(ui);
+                    if ui
+                        .button("🌐 SFTP")
+                        .on_hover_text("Browse an SFTP server in the active panel")
+                        .clicked()
+                    {
+                        let panel = self.filepane_keyboard.active_panel;
+                        self.open_sftp_connect(panel);
+                    }
                     ui.separator();
                     if ui
                         .button("⏭ Copy→→")
@@ -198,7 +255,7 @@ impl ProcessManagerApp {
             };
             let first_panel_index = if self.filepane_swap_columns { 1 } else { 0 };
             let second_panel_index = if self.filepane_swap_columns { 0 } else { 1 };
-            ui.allocate_ui_with_layout(
+            let first_response = ui.allocate_ui_with_layout(
                 egui::vec2(ui.available_width() * 0.5, available_height),
                 egui::Layout::top_down(egui::Align::LEFT),
                 |ui| {
@@ -211,8 +268,16 @@ impl ProcessManagerApp {
                     );
                 },
             );
+            if self.filepane_keyboard.active_panel == first_panel_index {
+                ui.painter()
+                    .rect_stroke(
+                        first_response.response.rect,
+                        4.0,
+                        egui::Stroke::new(1.0, ui.visuals().selection.stroke.color),
+                    );
+            }
             ui.separator();
-            ui.allocate_ui_with_layout(
+            let second_response = ui.allocate_ui_with_layout(
                 egui::vec2(ui.available_width(), available_height),
                 egui::Layout::top_down(egui::Align::LEFT),
                 |ui| {
@@ -225,6 +290,14 @@ impl ProcessManagerApp {
                     );
                 },
             );
+            if self.filepane_keyboard.active_panel == second_panel_index {
+                ui.painter()
+                    .rect_stroke(
+                        second_response.response.rect,
+                        4.0,
+                        egui::Stroke::new(1.0, ui.visuals().selection.stroke.color),
+                    );
+            }
         });
         let mut context_actions = Vec::new();
         if self.show_context_menu {
@@ -277,12 +350,19 @@ impl ProcessManagerApp {
                                                         path: file_path.clone(),
                                                     });
                                             }
+                                            if ui.button("👁 View").clicked() {
+                                                context_actions
+                                                    .push(ContextAction::ViewFile {
+                                                        path: file_path.clone(),
+                                                    });
+                                            }
                                         }
                                         ui.separator();
                                         if ui.button("📋 Copy").clicked() {
                                             let command = FilepaneCommand::CopyFile {
                                                 source: file_path.clone(),
                                                 destination: dest_path.clone(),
+                                                speed_limit: Some(speed_limit),
                                             };
                                             self.request_file_operation_confirmation(
                                                 &command,
@@ -299,6 +379,31 @@ impl ProcessManagerApp {
                                                 format!("Are you sure you want to move:\n\n{}\n\nto:\n\n{}", file_name, dest_path)
                                             );
                                         }
+                                        let link_path = std::path::Path::new(&dest_path)
+                                            .join(&file_name)
+                                            .to_string_lossy()
+                                            .to_string();
+                                        if ui.button("🔗 Symlink in Other Panel").clicked() {
+                                            let command = FilepaneCommand::CreateSymlink {
+                                                target: file_path.clone(),
+                                                link_path: link_path.clone(),
+                                            };
+                                            self.request_file_operation_confirmation(
+                                                &command,
+                                                format!("Create a symbolic link:\n\n{}\n\npointing to:\n\n{}", link_path, file_path)
+                                            );
+                                        }
+                                        if !is_directory && ui.button("⛓ Hardlink in Other Panel").clicked() {
+                                            let command = FilepaneCommand::CreateHardlink {
+                                                target: file_path.clone(),
+                                                link_path: link_path.clone(),
+                                                replace: false,
+                                            };
+                                            self.request_file_operation_confirmation(
+                                                &command,
+                                                format!("Create a hard link:\n\n{}\n\nto:\n\n{}", link_path, file_path)
+                                            );
+                                        }
                                         ui.separator();
                                         if ui.button("🏷️ Rename").clicked() {
                                             // For now, just log - rename would need a dialog for new name
@@ -315,7 +420,10 @@ impl ProcessManagerApp {
                                             };
                                             self.request_file_operation_confirmation(
                                                 &command,
-                                                format!("⚠️ DANGER: Are you sure you want to permanently delete:\n\n{}\n\nThis will move the item to trash and can be undone.", file_name)
+                                                format!(
+                                                    "⚠️ DANGER: Are you sure you want to delete:\n\n{}\n\n{}", file_name,
+                                                    Self::delete_consequence(std::slice::from_ref(&file_path))
+                                                )
                                             );
                                         }
                                         ui.separator();
@@ -356,6 +464,9 @@ impl ProcessManagerApp {
                         ContextAction::OpenFile { path } => {
                             self.open_file_with_system(&path);
                         }
+                        ContextAction::ViewFile { path } => {
+                            self.open_file_viewer(&path);
+                        }
                         ContextAction::CopyFile { source, destination, speed_limit } => {
                             self.copy_files_with_limit(
                                 vec![source],
@@ -493,6 +604,7 @@ impl ProcessManagerApp {
         ui: &mut egui::Ui,
         panel_index: usize,
     ) {
+        let bookmarked = self.is_bookmarked(&self.filepane_panel_path(panel_index));
         let tab = &mut self.filepane_tabs[self.filepane_active_tab];
         let (path, should_navigate, should_toggle_all) = if panel_index == 0 {
             let mut new_path = tab.left_path.clone();
@@ -528,6 +640,30 @@ impl ProcessManagerApp {
                         if ui.button("🔄").on_hover_text("Refresh").clicked() {
                             should_navigate = Some("refresh".to_string());
                         }
+                        let star = if bookmarked { "★" } else { "☆" };
+                        if ui.button(star).on_hover_text("Toggle bookmark").clicked() {
+                            should_navigate = Some("bookmark".to_string());
+                        }
+                        if ui
+                            .add_enabled(
+                                !tab.left_history.forward.is_empty(),
+                                egui::Button::new("▶"),
+                            )
+                            .on_hover_text("Forward")
+                            .clicked()
+                        {
+                            should_navigate = Some("forward".to_string());
+                        }
+                        if ui
+                            .add_enabled(
+                                !tab.left_history.back.is_empty(),
+                                egui::Button::new("◀"),
+                            )
+                            .on_hover_text("Back")
+                            .clicked()
+                        {
+                            should_navigate = Some("back".to_string());
+                        }
                     },
                 );
             });
@@ -578,6 +714,30 @@ impl ProcessManagerApp {
                         if ui.button("🔄").on_hover_text("Refresh").clicked() {
                             should_navigate = Some("refresh".to_string());
                         }
+                        let star = if bookmarked { "★" } else { "☆" };
+                        if ui.button(star).on_hover_text("Toggle bookmark").clicked() {
+                            should_navigate = Some("bookmark".to_string());
+                        }
+                        if ui
+                            .add_enabled(
+                                !tab.right_history.forward.is_empty(),
+                                egui::Button::new("▶"),
+                            )
+                            .on_hover_text("Forward")
+                            .clicked()
+                        {
+                            should_navigate = Some("forward".to_string());
+                        }
+                        if ui
+                            .add_enabled(
+                                !tab.right_history.back.is_empty(),
+                                egui::Button::new("◀"),
+                            )
+                            .on_hover_text("Back")
+                            .clicked()
+                        {
+                            should_navigate = Some("back".to_string());
+                        }
                     },
                 );
             });
@@ -629,23 +789,45 @@ impl ProcessManagerApp {
             }
         }
         if let Some(action) = should_navigate {
+            match action.as_str() {
+                "back" => {
+                    self.filepane_go_back(panel_index);
+                    return;
+                }
+                "forward" => {
+                    self.filepane_go_forward(panel_index);
+                    return;
+                }
+                "bookmark" => {
+                    let panel_path = self.filepane_panel_path(panel_index);
+                    self.toggle_filepane_bookmark(&panel_path);
+                    return;
+                }
+                _ => {}
+            }
             let tab = &mut self.filepane_tabs[self.filepane_active_tab];
             if action == "parent" {
                 if panel_index == 0 {
-                    if let Some(parent) = std::path::Path::new(&tab.left_path).parent() {
-                        tab.left_path = parent.to_string_lossy().to_string();
+                    if let Some(parent) = parent_directory(&tab.left_path) {
+                        tab.left_path = parent;
                         tab.selected_left.clear();
                         tab.left_checkboxes.clear();
                     }
                 } else {
-                    if let Some(parent) = std::path::Path::new(&tab.right_path).parent()
-                    {
-                        tab.right_path = parent.to_string_lossy().to_string();
+                    if let Some(parent) = parent_directory(&tab.right_path) {
+                        tab.right_path = parent;
                         tab.selected_right.clear();
                         tab.right_checkboxes.clear();
                     }
                 }
-            } else if action == "refresh" {} else {
+            } else if action == "refresh" {
+                let panel_path = if panel_index == 0 {
+                    tab.left_path.clone()
+                } else {
+                    tab.right_path.clone()
+                };
+                self.invalidate_directory_listing(&panel_path);
+            } else {
                 if panel_index == 0 {
                     tab.left_path = action;
                     tab.selected_left.clear();
@@ -664,20 +846,19 @@ impl ProcessManagerApp {
         path: &str,
         panel_index: usize,
     ) {
-        let path_buf = std::path::Path::new(path);
+        let (listing, parent_info) = match self.directory_listing(path) {
+            Some((listing, parent)) => (Some(listing), parent),
+            None => (None, None),
+        };
         let tab = &mut self.filepane_tabs[self.filepane_active_tab];
         let filter = tab.filter.clone();
         let show_hidden = self.show_window_grid;
         let file_infos_with_display_names: Vec<(FileInfo, String)> = { Vec::new() };
-        if let Ok(entries) = std::fs::read_dir(path_buf) {
+        if let Some(entries) = listing {
             let mut file_infos: Vec<FileInfo> = Vec::new();
-            if let Some(parent) = path_buf.parent() {
-                if let Ok(parent_info) = FileInfo::new(parent.to_path_buf()) {
-                    file_infos.push(parent_info);
-                }
-            }
-            for entry in entries.flatten() {
-                if let Ok(file_info) = FileInfo::new(entry.path()) {
+            file_infos.extend(parent_info);
+            for file_info in entries {
+                {
                     if !filter.is_empty() {
                         if !file_info
                             .name
@@ -701,6 +882,14 @@ impl ProcessManagerApp {
                         _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
                     }
                 });
+            self.filepane_keyboard.visible[panel_index] = file_infos.clone();
+            let cursor_name = if self.filepane_keyboard.active_panel == panel_index {
+                self.filepane_keyboard.cursor[panel_index].clone()
+            } else {
+                None
+            };
+            let scroll_to_cursor = std::mem::take(&mut self.filepane_keyboard.scroll_to_cursor);
+            let mut clicked_name = None as Option<String>;
             let (current_selections, current_checkboxes) = if panel_index == 0 {
                 (tab.selected_left.clone(), tab.left_checkboxes.clone())
             } else {
@@ -712,11 +901,14 @@ impl ProcessManagerApp {
             let file_infos_with_sizes: Vec<(FileInfo, String)> = file_infos
                 .into_iter()
                 .map(|info| {
-                    let size_str = if info.is_directory {
-                        String::new()
+                    let known_size = if info.is_directory {
+                        self.filepane_dir_sizes.get(&info.path).copied()
                     } else {
+                        Some(info.size)
+                    };
+                    let size_str = if let Some(known_size) = known_size {
                         const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
-                        let mut size = info.size as f64;
+                        let mut size = known_size as f64;
                         let mut unit_index = 0;
                         while size >= 1024.0 && unit_index < UNITS.len() - 1 {
                             size /= 1024.0;
@@ -727,6 +919,8 @@ impl ProcessManagerApp {
                         } else {
                             format!("{:.1} {}", size, UNITS[unit_index])
                         }
+                    } else {
+                        String::new()
                     };
                     (info, size_str)
                 })
@@ -736,6 +930,8 @@ impl ProcessManagerApp {
                 .show(
                     ui,
                     |ui| {
+                        self.filepane_keyboard.page_rows = (ui.available_height() / 22.0)
+                            .max(1.0) as usize;
                         for (file_info, size_str) in &file_infos_with_sizes {
                             let is_selected = current_selections
                                 .contains(&file_info.name);
@@ -755,12 +951,31 @@ impl ProcessManagerApp {
                                             }
                                         }
                                     }
-                                    let icon = if file_info.is_directory {
+                                    let icon = if file_info.is_broken_link {
+                                        "⛔"
+                                    } else if file_info.is_symlink() {
+                                        "🔗"
+                                    } else if file_info.is_mount_point {
+                                        "💽"
+                                    } else if file_info.is_directory {
                                         "📁"
                                     } else {
                                         "📄"
                                     };
-                                    ui.label(icon);
+                                    let icon_response = ui.label(icon);
+                                    if let Some(target) = &file_info.symlink_target {
+                                        let state = if file_info.is_broken_link {
+                                            " (broken)"
+                                        } else {
+                                            ""
+                                        };
+                                        icon_response.on_hover_text(format!("→ {}{}", target, state));
+                                    } else if file_info.is_mount_point {
+                                        icon_response.on_hover_text("Mount point");
+                                    } else if file_info.hard_links > 1 {
+                                        icon_response
+                                            .on_hover_text(format!("{} hard links", file_info.hard_links));
+                                    }
                                     let checkbox_width = if tab.show_checkboxes {
                                         25.0
                                     } else {
@@ -778,7 +993,13 @@ impl ProcessManagerApp {
                                             "{}...", & file_info.name[..max_chars.saturating_sub(3)]
                                         )
                                     };
-                                    let name_label = if file_info.is_directory {
+                                    let name_label = if file_info.is_broken_link {
+                                        egui::RichText::new(display_name.clone())
+                                            .color(egui::Color32::from_rgb(220, 80, 80))
+                                            .strikethrough()
+                                    } else if file_info.is_symlink() {
+                                        egui::RichText::new(display_name.clone()).italics()
+                                    } else if file_info.is_directory {
                                         egui::RichText::new(display_name.clone()).strong()
                                     } else {
                                         egui::RichText::new(display_name.clone())
@@ -790,7 +1011,14 @@ impl ProcessManagerApp {
                                     {
                                         label_response.on_hover_text(&file_info.name);
                                     }
-                                    if !file_info.is_directory {
+                                    if let Some(target) = &file_info.symlink_target {
+                                        ui.label(
+                                            egui::RichText::new(format!("→ {}", target))
+                                                .weak()
+                                                .small(),
+                                        );
+                                    }
+                                    if !size_str.is_empty() {
                                         ui.with_layout(
                                             egui::Layout::right_to_left(egui::Align::Center),
                                             |ui| {
@@ -799,7 +1027,22 @@ impl ProcessManagerApp {
                                         );
                                     }
                                 });
+                            if cursor_name.as_ref() == Some(&file_info.name) {
+                                ui.painter()
+                                    .rect_stroke(
+                                        response.response.rect.expand(1.0),
+                                        2.0,
+                                        egui::Stroke::new(
+                                            1.5,
+                                            ui.visuals().selection.stroke.color,
+                                        ),
+                                    );
+                                if scroll_to_cursor {
+                                    response.response.scroll_to_me(None);
+                                }
+                            }
                             if response.response.clicked() {
+                                clicked_name = Some(file_info.name.clone());
                                 let shift_pressed = ui.input(|i| i.modifiers.shift);
                                 let ctrl_pressed = ui.input(|i| i.modifiers.ctrl);
                                 if shift_pressed {
@@ -843,6 +1086,10 @@ impl ProcessManagerApp {
                         }
                     },
                 );
+            if clicked_name.is_some() {
+                self.filepane_keyboard.active_panel = panel_index;
+                self.filepane_keyboard.cursor[panel_index] = clicked_name;
+            }
             let tab = &mut self.filepane_tabs[self.filepane_active_tab];
             if panel_index == 0 {
                 tab.selected_left = selections_to_update;
@@ -862,6 +1109,11 @@ impl ProcessManagerApp {
                     tab.right_checkboxes.clear();
                 }
             }
+        } else if is_remote_path(path) && !self.is_sftp_connected(path) {
+            ui.label("🌐 Not connected to this server");
+            if ui.button("🔌 Connect...").clicked() {
+                self.open_sftp_connect(panel_index);
+            }
         } else {
             ui.colored_label(egui::Color32::RED, "❌ Cannot access directory");
         }
@@ -870,10 +1122,10 @@ impl ProcessManagerApp {
         if self.filepane_active_tab < self.filepane_tabs.len() {
             let tab = &mut self.filepane_tabs[self.filepane_active_tab];
             let left_files: Vec<String> = tab.selected_left.iter()
-                .map(|name| format!("{}\\{}", tab.left_path, name))
+                .map(|name| join_panel_path(&tab.left_path, name))
                 .collect();
             let right_files: Vec<String> = tab.selected_right.iter()
-                .map(|name| format!("{}\\{}", tab.right_path, name))
+                .map(|name| join_panel_path(&tab.right_path, name))
                 .collect();
 
             let all_files: Vec<String> = left_files.iter().chain(right_files.iter()).cloned().collect();
@@ -905,6 +1157,7 @@ impl ProcessManagerApp {
                 let command = FilepaneCommand::CopyFile {
                     source: all_files[0].clone(),
                     destination: dest_path,
+                    speed_limit: None,
                 };
                 self.request_file_operation_confirmation(&command, message);
             } else {
@@ -917,10 +1170,10 @@ impl ProcessManagerApp {
         if self.filepane_active_tab < self.filepane_tabs.len() {
             let tab = &mut self.filepane_tabs[self.filepane_active_tab];
             let left_files: Vec<String> = tab.selected_left.iter()
-                .map(|name| format!("{}\\{}", tab.left_path, name))
+                .map(|name| join_panel_path(&tab.left_path, name))
                 .collect();
             let right_files: Vec<String> = tab.selected_right.iter()
-                .map(|name| format!("{}\\{}", tab.right_path, name))
+                .map(|name| join_panel_path(&tab.right_path, name))
                 .collect();
 
             let all_files: Vec<String> = left_files.iter().chain(right_files.iter()).cloned().collect();
@@ -962,7 +1215,13 @@ impl ProcessManagerApp {
     fn paste_files(&mut self) {
         self.add_log("Paste files (placeholder)".to_string());
     }
-    fn request_file_operation_confirmation(&mut self, command: &FilepaneCommand, message: String) {
+    pub(crate) fn request_file_operation_confirmation(&mut self, command: &FilepaneCommand, message: String) {
+        let message = match command {
+            FilepaneCommand::DeleteFile { path } if is_remote_path(path) => {
+                format!("{}\n\n🌐 Remote files are deleted permanently; the server has no trash.", message)
+            }
+            _ => message,
+        };
         self.filepane_confirm_action = Some(command.clone());
         self.filepane_confirm_message = message;
         self.filepane_show_confirm = true;
@@ -970,7 +1229,7 @@ impl ProcessManagerApp {
 
         // Store the pending operation for history tracking
         match command {
-            FilepaneCommand::CopyFile { source, destination } => {
+            FilepaneCommand::CopyFile { source, destination, .. } => {
                 self.filepane_pending_operation = Some(FileOperation {
                     operation_type: crate::ws::FileOperationType::Copy,
                     source_path: source.clone(),
@@ -1005,10 +1264,10 @@ impl ProcessManagerApp {
         if self.filepane_active_tab < self.filepane_tabs.len() {
             let tab = &mut self.filepane_tabs[self.filepane_active_tab];
             let left_files: Vec<String> = tab.selected_left.iter()
-                .map(|name| format!("{}\\{}", tab.left_path, name))
+                .map(|name| join_panel_path(&tab.left_path, name))
                 .collect();
             let right_files: Vec<String> = tab.selected_right.iter()
-                .map(|name| format!("{}\\{}", tab.right_path, name))
+                .map(|name| join_panel_path(&tab.right_path, name))
                 .collect();
 
             let all_files: Vec<String> = left_files.iter().chain(right_files.iter()).cloned().collect();
@@ -1026,8 +1285,8 @@ impl ProcessManagerApp {
                 .join("\n");
 
             let message = format!(
-                "⚠️ DANGER: Are you sure you want to delete {} item(s)?\n\n{}\n\nThis will move items to trash and can be undone.",
-                file_count, file_list
+                "⚠️ DANGER: Are you sure you want to delete {} item(s)?\n\n{}\n\n{}",
+                file_count, file_list, Self::delete_consequence(&all_files)
             );
 
             if file_count == 1 {
@@ -1043,7 +1302,7 @@ impl ProcessManagerApp {
         }
     }
     fn create_new_folder(&mut self) {
-        self.add_log("Create new folder (placeholder)".to_string());
+        self.filepane_keyboard.mkdir_name = Some(String::new());
     }
     fn calculate_checksum_for_selected(&mut self) {
         if self.filepane_active_tab >= self.filepane_tabs.len() {
@@ -1053,21 +1312,21 @@ impl ProcessManagerApp {
         let algorithm = tab.checksum_algorithm.clone();
         let mut files_to_check = Vec::new();
         for filename in &tab.left_checkboxes {
-            let path = format!("{}\\{}", tab.left_path, filename);
+            let path = join_panel_path(&tab.left_path, filename);
             files_to_check.push(path);
         }
         for filename in &tab.selected_left {
-            let path = format!("{}\\{}", tab.left_path, filename);
+            let path = join_panel_path(&tab.left_path, filename);
             if !files_to_check.contains(&path) {
                 files_to_check.push(path);
             }
         }
         for filename in &tab.right_checkboxes {
-            let path = format!("{}\\{}", tab.right_path, filename);
+            let path = join_panel_path(&tab.right_path, filename);
             files_to_check.push(path);
         }
         for filename in &tab.selected_right {
-            let path = format!("{}\\{}", tab.right_path, filename);
+            let path = join_panel_path(&tab.right_path, filename);
             if !files_to_check.contains(&path) {
                 files_to_check.push(path);
             }
@@ -1095,6 +1354,8 @@ impl ProcessManagerApp {
             }
         }
     }
+    /// Copies each file on a worker thread at no more than the tab's speed
+    /// limit; the log gets a summary once the whole batch has finished.
     fn copy_files_with_limit(
         &mut self,
         source_files: Vec<String>,
@@ -1104,168 +1365,25 @@ impl ProcessManagerApp {
         self.add_log(format!("📋 Starting batch copy operation for {} files", source_files.len()));
         self.add_log(format!("📍 Destination: {}", dest_path));
         self.add_log(format!("⚡ Speed limit: {} MB/s", speed_limit_mb_per_sec));
-
-        let mut successful_copies = 0;
-        let mut failed_copies = 0;
-        let mut total_bytes_copied = 0u64;
-        let batch_size = (speed_limit_mb_per_sec * 1024.0 * 1024.0) as u64;
-
-        for (index, file_path) in source_files.iter().enumerate() {
-            // Check if source exists
-            if !std::path::Path::new(file_path).exists() {
-                self.add_log(format!("❌ Source does not exist: {}", file_path));
-                failed_copies += 1;
-                continue;
-            }
-
-            // Get file info
-            let file_metadata = match std::fs::metadata(file_path) {
-                Ok(metadata) => metadata,
-                Err(e) => {
-                    self.add_log(format!("❌ Failed to get metadata for {}: {}", file_path, e));
-                    failed_copies += 1;
-                    continue;
-                }
-            };
-
-            let is_directory = file_metadata.is_dir();
-            let file_size = if is_directory { 0 } else { file_metadata.len() };
-
-            let dest_file = format!(
-                "{}\\{}", dest_path, std::path::Path::new(file_path).file_name()
-                .and_then(| name | name.to_str()).unwrap_or("unknown")
-            );
-
-            // Ensure destination directory exists
-            let dest_dir = std::path::Path::new(&dest_file).parent().unwrap_or_else(|| std::path::Path::new(dest_path));
-            if let Err(e) = std::fs::create_dir_all(dest_dir) {
-                self.add_log(format!("❌ Failed to create destination directory {}: {}", dest_dir.display(), e));
-                failed_copies += 1;
-                continue;
-            }
-
-            // Perform the actual copy operation
-            let copy_result = if is_directory {
-                self.copy_directory_recursive(file_path, &dest_file)
-            } else {
-                self.copy_file_with_progress(file_path, &dest_file, file_size)
+        let batch = self.filepane_next_batch_id;
+        self.filepane_next_batch_id += 1;
+        self.filepane_copy_batches
+            .push(CopyBatch {
+                id: batch,
+                total: source_files.len(),
+                succeeded: 0,
+                failed: 0,
+            });
+        for source in source_files {
+            let command = FilepaneCommand::CopyFile {
+                source,
+                destination: dest_path.to_string(),
+                speed_limit: Some(speed_limit_mb_per_sec),
             };
-
-            match copy_result {
-                Ok(bytes_copied) => {
-                    successful_copies += 1;
-                    total_bytes_copied += bytes_copied;
-                    self.add_log(
-                        format!(
-                            "✅ Copied {}/{}: {} -> {} ({} bytes)",
-                            index + 1, source_files.len(),
-                            std::path::Path::new(file_path).file_name()
-                                .and_then(|n| n.to_str()).unwrap_or("unknown"),
-                            std::path::Path::new(&dest_file).file_name()
-                                .and_then(|n| n.to_str()).unwrap_or("unknown"),
-                            bytes_copied
-                        ),
-                    );
-                }
-                Err(e) => {
-                    failed_copies += 1;
-                    self.add_log(format!("❌ Failed to copy {}: {}", file_path, e));
-                }
-            }
-
-            if index > 0 && index % 5 == 0 {
-                self.add_log(
-                    format!("⏸️ Speed limiting at {} MB/s (processed: {}/{})",
-                        speed_limit_mb_per_sec, successful_copies, source_files.len()),
-                );
-            }
-        }
-
-        // Summary
-        self.add_log(format!("📊 Copy operation completed:"));
-        self.add_log(format!("   ✅ Successful: {} files", successful_copies));
-        self.add_log(format!("   ❌ Failed: {} files", failed_copies));
-        self.add_log(format!("   📦 Total bytes: {}", self.format_bytes(total_bytes_copied)));
-
-        if failed_copies > 0 {
-            self.add_log(format!("⚠️ Some files failed to copy. Check the logs above for details."));
-        }
-    }
-
-    fn copy_file_with_progress(&mut self, source: &str, destination: &str, file_size: u64) -> std::io::Result<u64> {
-        use std::io::{Read, Write, BufReader, BufWriter};
-
-        let mut total_copied = 0u64;
-        let chunk_size = 64 * 1024; // 64KB chunks
-
-        let source_file = std::fs::File::open(source)?;
-        let dest_file = std::fs::File::create(destination)?;
-
-        let mut reader = BufReader::new(source_file);
-        let mut writer = BufWriter::new(dest_file);
-        let mut buffer = vec![0u8; chunk_size];
-
-        loop {
-            let bytes_read = reader.read(&mut buffer)?;
-            if bytes_read == 0 {
-                break;
-            }
-
-            writer.write_all(&buffer[..bytes_read])?;
-            total_copied += bytes_read as u64;
-        }
-
-        writer.flush()?;
-        Ok(total_copied)
-    }
-
-    fn copy_directory_recursive(&mut self, source: &str, destination: &str) -> std::io::Result<u64> {
-        let mut total_copied = 0u64;
-
-        // Create destination directory
-        std::fs::create_dir_all(destination)?;
-
-        // Read source directory
-        for entry in std::fs::read_dir(source)? {
-            let entry = entry?;
-            let path = entry.path();
-            let name = path.file_name().unwrap_or_default();
-            let dest_path = std::path::Path::new(destination).join(name);
-
-            if path.is_dir() {
-                // Recursively copy subdirectory
-                total_copied += self.copy_directory_recursive(path.to_str().unwrap(), dest_path.to_str().unwrap())?;
-            } else {
-                // Copy file
-                let metadata = std::fs::metadata(&path)?;
-                let file_size = metadata.len();
-                match self.copy_file_with_progress(path.to_str().unwrap(), dest_path.to_str().unwrap(), file_size) {
-                    Ok(copied) => total_copied += copied,
-                    Err(e) => {
-                        self.add_log(format!("❌ Failed to copy file {}: {}", path.display(), e));
-                    }
-                }
+            if let Some(audit) = self.begin_audit(&command) {
+                self.start_backend_job(&command, audit, Some(batch));
             }
         }
-
-        Ok(total_copied)
-    }
-
-    fn format_bytes(&self, bytes: u64) -> String {
-        const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
-        let mut size = bytes as f64;
-        let mut unit_index = 0;
-
-        while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-            size /= 1024.0;
-            unit_index += 1;
-        }
-
-        if unit_index == 0 {
-            format!("{} {}", bytes, UNITS[unit_index])
-        } else {
-            format!("{:.1} {}", size, UNITS[unit_index])
-        }
     }
 
     fn copy_files_to_opposite_panel(&mut self) {
@@ -1299,17 +1417,17 @@ impl ProcessManagerApp {
         };
         let mut source_files = Vec::new();
         for filename in &left_checkboxes {
-            source_files.push(format!("{}\\{}", source_path, filename));
+            source_files.push(join_panel_path(&source_path, filename));
         }
         for filename in &right_checkboxes {
-            source_files.push(format!("{}\\{}", source_path, filename));
+            source_files.push(join_panel_path(&source_path, filename));
         }
         if source_files.is_empty() {
             for filename in &selected_left {
-                source_files.push(format!("{}\\{}", source_path, filename));
+                source_files.push(join_panel_path(&source_path, filename));
             }
             for filename in &selected_right {
-                source_files.push(format!("{}\\{}", source_path, filename));
+                source_files.push(join_panel_path(&source_path, filename));
             }
         }
         if source_files.is_empty() {
@@ -1349,7 +1467,7 @@ impl ProcessManagerApp {
         };
         if let Some(command) = command {
             let log_message = match &command {
-                FilepaneCommand::CopyFile { source, destination } => {
+                FilepaneCommand::CopyFile { source, destination, .. } => {
                     format!("Undo: Copy {} -> {}", source, destination)
                 }
                 FilepaneCommand::MoveFile { source, destination } => {
@@ -1370,6 +1488,15 @@ impl ProcessManagerApp {
                 FilepaneCommand::CalculateChecksum { path, algorithm } => {
                     format!("Undo: {} checksum for {}", algorithm.name(), path)
                 }
+                FilepaneCommand::CreateHardlink { target, link_path, .. } => {
+                    format!("Undo: Hardlink {} -> {}", link_path, target)
+                }
+                FilepaneCommand::CreateSymlink { target, link_path } => {
+                    format!("Undo: Symlink {} -> {}", link_path, target)
+                }
+                FilepaneCommand::Batch { commands } => {
+                    format!("Undo: Batch of {} operations", commands.len())
+                }
             };
             self.add_log(log_message);
             let tab = &mut self.filepane_tabs[self.filepane_active_tab];
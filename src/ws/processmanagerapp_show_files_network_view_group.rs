@@ -310,13 +310,4 @@ impl ProcessManagerApp {
             }
         }
     }
-    pub fn add_log(&mut self, message: String) {
-        use chrono::Local;
-        let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
-        let log_entry = format!("{} - {}", timestamp, message);
-        self.logs.push(log_entry);
-        if self.logs.len() > 1000 {
-            self.logs.remove(0);
-        }
-    }
 }
@@ -145,7 +145,7 @@ impl ProcessManagerApp {
             );
         self.font_picker.is_open = open;
     }
-    pub fn save_config(&self) {
+    pub fn save_config(&mut self) {
         let config_path = Self::get_config_path();
         let mut content = String::new();
         content.push_str("[Settings]\n");
@@ -162,17 +162,70 @@ impl ProcessManagerApp {
         content.push_str(&format!("LiveDetailPercent={}\n", self.live_detail_percent));
         content
             .push_str(&format!("AttemptStartAsAdmin={}\n", self.attempt_start_as_admin));
+        content.push_str(&format!("LogToFile={}\n", self.log_view.settings.to_file));
+        content.push_str(&format!("LogFileMaxKb={}\n", self.log_view.settings.max_file_kb));
+        content
+            .push_str(
+                &format!(
+                    "LogLibraryLevel={}\n", self.log_view.settings.library_level.name()
+                ),
+            );
+        content
+            .push_str(
+                &format!(
+                    "HistoryRetentionDays={}\n",
+                    self.log_view.settings.history_retention_days
+                ),
+            );
         content.push_str("\n");
+        content.push_str("[Keymap]\n");
+        content.push_str(&self.filepane_keymap.to_config());
+        content.push_str("\n");
+        content.push_str("[Bookmarks]\n");
+        for bookmark in &self.filepane_bookmarks {
+            content.push_str(&format!("{}\n", bookmark.to_config_line()));
+        }
+        content.push_str("\n");
+        content.push_str("[RecentDirs]\n");
+        for path in &self.filepane_recent_dirs {
+            content.push_str(&format!("Path={}\n", path));
+        }
+        content.push_str("\n");
+        for layout in &self.window_layouts {
+            content.push_str("[Layout]\n");
+            content.push_str(&format!("Name={}\n", layout.name));
+            for rule in &layout.rules {
+                content.push_str(&format!("Window={}\n", rule.to_config()));
+            }
+            content.push_str("\n");
+        }
+        for group in &self.program_groups {
+            content.push_str("[Group]\n");
+            content.push_str(&format!("Name={}\n", group.name));
+            for step in &group.steps {
+                content.push_str(&format!("Step={}\n", step.to_config()));
+            }
+            content.push_str("\n");
+        }
         for program in &self.custom_programs {
             content.push_str("[Program]\n");
             content.push_str(&format!("Name={}\n", program.name));
             content.push_str(&format!("Path={}\n", program.path));
             content.push_str(&format!("Args={}\n", program.args));
             content.push_str(&format!("Admin={}\n", program.admin));
+            if !program.working_dir.is_empty() {
+                content.push_str(&format!("WorkingDir={}\n", program.working_dir));
+            }
+            for (key, value) in &program.env {
+                content.push_str(&format!("Env={}={}\n", key, value));
+            }
+            content.push_str(&format!("CaptureOutput={}\n", program.capture_output));
+            content.push_str(&format!("RestartOnCrash={}\n", program.restart_on_crash));
             content.push_str("\n");
         }
-        if let Err(e) = std::fs::write(&config_path, content) {
-            eprintln!("Failed to save config: {}", e);
+        match std::fs::write(&config_path, content) {
+            Ok(()) => self.filepane_recent_dirs_changed = false,
+            Err(e) => eprintln!("Failed to save config: {}", e),
         }
     }
 }
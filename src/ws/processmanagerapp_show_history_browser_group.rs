@@ -0,0 +1,419 @@
+use crate::ws::HistoryEntry;
+use crate::ws::HistoryExportFormat;
+use crate::ws::HistoryFile;
+use crate::ws::LogLevel;
+use crate::ws::LogRecord;
+// # ProcessManagerApp - show_history_browser_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Saved `history_<timestamp>.txt` files are parsed back into entries so past
+// sessions can be searched together and exported. Exports are written next
+// to the history files under an `export_` name, which keeps them out of
+// both the browser and retention pruning.
+
+use std::time::{Duration, SystemTime};
+use super::processmanagerapp_type::ProcessManagerApp;
+
+impl ProcessManagerApp {
+    /// Where history files and exports are written: next to the executable.
+    pub fn history_directory() -> std::path::PathBuf {
+        std::env::current_exe()
+            .ok()
+            .and_then(|path| path.parent().map(|p| p.to_path_buf()))
+            .unwrap_or_else(|| std::path::PathBuf::from("."))
+    }
+    fn history_file_paths() -> std::io::Result<Vec<std::path::PathBuf>> {
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(Self::history_directory())? {
+            let path = entry?.path();
+            let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+            if name.starts_with("history_") && name.ends_with(".txt") && path.is_file() {
+                paths.push(path);
+            }
+        }
+        Ok(paths)
+    }
+    pub(crate) fn load_history_files(&mut self) {
+        let browser = &mut self.history_browser;
+        browser.files.clear();
+        browser.selected.clear();
+        browser.selected_file = None;
+        browser.status = None;
+        let paths = match Self::history_file_paths() {
+            Ok(paths) => paths,
+            Err(e) => {
+                browser.status = Some(format!("❌ Cannot read history directory: {}", e));
+                return;
+            }
+        };
+        let mut unreadable = 0;
+        for path in paths {
+            match HistoryFile::load(&path) {
+                Ok(file) => browser.files.push(file),
+                Err(_) => unreadable += 1,
+            }
+        }
+        browser.files.sort_by(|a, b| b.session.cmp(&a.session));
+        if unreadable > 0 {
+            browser.status = Some(format!("⚠️ {} history file(s) could not be read", unreadable));
+        }
+    }
+    /// Deletes history files older than the retention setting and returns how
+    /// many were removed.
+    pub(crate) fn prune_history_files(&mut self) -> usize {
+        let days = self.log_view.settings.history_retention_days;
+        if days == 0 {
+            return 0;
+        }
+        let max_age = Duration::from_secs(days * 24 * 60 * 60);
+        let now = SystemTime::now();
+        let mut removed = 0;
+        for path in Self::history_file_paths().unwrap_or_default() {
+            let expired = std::fs::metadata(&path)
+                .and_then(|metadata| metadata.modified())
+                .is_ok_and(|modified| {
+                    now.duration_since(modified).is_ok_and(|age| age > max_age)
+                });
+            if !expired {
+                continue;
+            }
+            match std::fs::remove_file(&path) {
+                Ok(()) => removed += 1,
+                Err(e) => {
+                    self.add_log_at(LogLevel::Warn, 
+                        format!("⚠️ Could not remove old history {}: {}", path.display(), e),
+                    )
+                }
+            }
+        }
+        if removed > 0 {
+            self.add_log(
+                format!("🧹 Removed {} history file(s) older than {} days", removed, days),
+            );
+            if self.history_browser.is_open {
+                self.load_history_files();
+            }
+        }
+        removed
+    }
+    fn history_entry_visible(&self, entry: &HistoryEntry) -> bool {
+        let search = self.history_browser.search.trim().to_lowercase();
+        search.is_empty()
+            || entry.message.to_lowercase().contains(&search)
+            || entry.module.as_ref().is_some_and(|m| m.to_lowercase().contains(&search))
+    }
+    /// Writes `entries` to a new `export_<timestamp>.<ext>` file, adding a
+    /// `_<n>` suffix when an export already used this second.
+    pub(crate) fn export_history_entries(
+        entries: &[HistoryEntry],
+        format: HistoryExportFormat,
+    ) -> Result<std::path::PathBuf, String> {
+        let content = match format {
+            HistoryExportFormat::Text => {
+                entries
+                    .iter()
+                    .map(|entry| {
+                        let mut line = format!("[{}]", entry.session);
+                        for part in [&entry.timestamp, &entry.level] {
+                            if let Some(part) = part {
+                                line.push(' ');
+                                line.push_str(part);
+                            }
+                        }
+                        if let Some(module) = &entry.module {
+                            line.push_str(&format!(" [{}]", module));
+                        }
+                        format!("{} {}\n", line, entry.message)
+                    })
+                    .collect::<String>()
+            }
+            HistoryExportFormat::Json => {
+                serde_json::to_string_pretty(entries).map_err(|e| e.to_string())?
+            }
+            HistoryExportFormat::Html => {
+                let mut html = String::from(
+                    "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+                    <title>WinShell Log Export</title>\n\
+                    <style>body{font-family:monospace}td,th{padding:2px 8px;text-align:left}\
+                    tr:nth-child(even){background:#f0f0f0}</style>\n</head>\n<body>\n<table>\n\
+                    <tr><th>Session</th><th>Time</th><th>Level</th><th>Module</th><th>Message</th></tr>\n",
+                );
+                for entry in entries {
+                    html.push_str(
+                        &format!(
+                            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                            html_escape(&entry.session),
+                            html_escape(entry.timestamp.as_deref().unwrap_or_default()),
+                            html_escape(entry.level.as_deref().unwrap_or_default()),
+                            html_escape(entry.module.as_deref().unwrap_or_default()),
+                            html_escape(&entry.message),
+                        ),
+                    );
+                }
+                html.push_str("</table>\n</body>\n</html>\n");
+                html
+            }
+        };
+        use std::io::Write;
+        let stamp = chrono::Local::now().format("%Y%m%d_%H%M%S").to_string();
+        for attempt in 0u32.. {
+            let name = match attempt {
+                0 => format!("export_{}.{}", stamp, format.extension()),
+                n => format!("export_{}_{}.{}", stamp, n, format.extension()),
+            };
+            let path = Self::history_directory().join(name);
+            match std::fs::OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => {
+                    file.write_all(content.as_bytes()).map_err(|e| e.to_string())?;
+                    return Ok(path);
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.to_string()),
+            }
+        }
+        Err("no free export file name".to_string())
+    }
+    /// Exports the selected application log records in the browser's format.
+    pub(crate) fn export_selected_logs(&mut self) {
+        let entries: Vec<HistoryEntry> = self
+            .logs
+            .iter()
+            .filter(|record| self.selected_log_ids.contains(&record.id))
+            .map(|record: &LogRecord| HistoryEntry {
+                session: "current".to_string(),
+                timestamp: Some(record.timestamp.format("%Y-%m-%d %H:%M:%S%.3f").to_string()),
+                level: Some(record.level.name().to_string()),
+                module: Some(record.module.clone()),
+                message: record.message.clone(),
+            })
+            .collect();
+        match Self::export_history_entries(&entries, self.history_browser.export_format) {
+            Ok(path) => self.add_log(format!("💾 Exported {} log entries to {}", entries.len(), path.display())),
+            Err(e) => self.add_log_at(LogLevel::Error, format!("❌ Log export failed: {}", e)),
+        }
+    }
+    pub(crate) fn show_export_format_picker(&mut self, ui: &mut egui::Ui) {
+        egui::ComboBox::from_id_salt("history_export_format")
+            .selected_text(self.history_browser.export_format.name())
+            .width(70.0)
+            .show_ui(
+                ui,
+                |ui| {
+                    for format in HistoryExportFormat::ALL {
+                        ui.selectable_value(
+                            &mut self.history_browser.export_format,
+                            format,
+                            format.name(),
+                        );
+                    }
+                },
+            );
+    }
+    pub(crate) fn show_history_browser(&mut self, ui: &mut egui::Ui) {
+        let mut settings_changed = false;
+        let mut prune = false;
+        ui.horizontal(|ui| {
+            if ui.button("🔄 Reload").clicked() {
+                self.load_history_files();
+            }
+            ui.label("Session:");
+            let selected_text = match self.history_browser.selected_file {
+                Some(index) => session_label(&self.history_browser.files[index].session),
+                None => "All sessions".to_string(),
+            };
+            egui::ComboBox::from_id_salt("history_session")
+                .selected_text(selected_text)
+                .show_ui(
+                    ui,
+                    |ui| {
+                        let browser = &mut self.history_browser;
+                        ui.selectable_value(&mut browser.selected_file, None, "All sessions");
+                        for (index, file) in browser.files.iter().enumerate() {
+                            ui.selectable_value(
+                                &mut browser.selected_file,
+                                Some(index),
+                                format!(
+                                    "{} ({} entries)",
+                                    session_label(&file.session),
+                                    file.entries.len(),
+                                ),
+                            );
+                        }
+                    },
+                );
+            ui.label("🔍");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.history_browser.search)
+                    .hint_text("search across sessions")
+                    .desired_width(200.0),
+            );
+        });
+        ui.horizontal(|ui| {
+            ui.label("Keep history for");
+            settings_changed |= ui
+                .add(
+                    egui::DragValue::new(&mut self.log_view.settings.history_retention_days)
+                        .range(0..=3650)
+                        .suffix(" days"),
+                )
+                .on_hover_text("0 keeps history files forever")
+                .changed();
+            if ui.button("🧹 Prune Now").clicked() {
+                prune = true;
+            }
+            ui.label(format!("📁 {}", Self::history_directory().display()));
+        });
+        if settings_changed {
+            self.save_config();
+        }
+        if prune && self.prune_history_files() == 0 {
+            self.history_browser.status = Some("No history files to prune".to_string());
+        }
+        let visible: Vec<(usize, usize)> = self
+            .history_browser
+            .files
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                self.history_browser.selected_file.is_none_or(|selected| selected == *index)
+            })
+            .flat_map(|(file_index, file)| {
+                file.entries
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, entry)| self.history_entry_visible(entry))
+                    .map(move |(entry_index, _)| (file_index, entry_index))
+            })
+            .collect();
+        let mut export = false;
+        ui.horizontal(|ui| {
+            self.show_export_format_picker(ui);
+            let selected = self.history_browser.selected.len();
+            if ui
+                .add_enabled(
+                    selected > 0,
+                    egui::Button::new(format!("💾 Export Selected ({})", selected)),
+                )
+                .clicked()
+            {
+                export = true;
+            }
+            if ui.button("☑ Select Shown").clicked() {
+                self.history_browser.selected.extend(visible.iter().copied());
+            }
+            if ui.button("❌ Clear Selection").clicked() {
+                self.history_browser.selected.clear();
+            }
+        });
+        if export {
+            let mut keys: Vec<(usize, usize)> = self
+                .history_browser
+                .selected
+                .iter()
+                .copied()
+                .collect();
+            // Oldest session first, in file order.
+            keys.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+            let entries: Vec<HistoryEntry> = keys
+                .into_iter()
+                .filter_map(|(file, entry)| {
+                    self.history_browser.files.get(file)?.entries.get(entry).cloned()
+                })
+                .collect();
+            let format = self.history_browser.export_format;
+            match Self::export_history_entries(&entries, format) {
+                Ok(path) => {
+                    let message = format!(
+                        "💾 Exported {} entries to {}", entries.len(), path.display()
+                    );
+                    self.add_log(message.clone());
+                    self.history_browser.status = Some(message);
+                }
+                Err(e) => {
+                    self.add_log_at(LogLevel::Error, format!("❌ History export failed: {}", e));
+                    self.history_browser.status = Some(format!("❌ Export failed: {}", e));
+                }
+            }
+        }
+        if let Some(status) = &self.history_browser.status {
+            ui.label(status);
+        }
+        let total: usize = self.history_browser.files.iter().map(|file| file.entries.len()).sum();
+        ui.label(
+            format!(
+                "Showing {} of {} entries from {} session(s)",
+                visible.len(),
+                total,
+                self.history_browser.files.len(),
+            ),
+        );
+        ui.separator();
+        if visible.is_empty() {
+            ui.add_space(20.0);
+            ui.label("No saved history entries");
+            return;
+        }
+        let row_height = ui.text_style_height(&egui::TextStyle::Body) + 4.0;
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show_rows(
+                ui,
+                row_height,
+                visible.len(),
+                |ui, rows| {
+                    for &key in &visible[rows] {
+                        let file = &self.history_browser.files[key.0];
+                        let entry = &file.entries[key.1];
+                        let is_selected = self.history_browser.selected.contains(&key);
+                        let mut clicked = false;
+                        ui.horizontal(|ui| {
+                            clicked = ui
+                                .selectable_label(is_selected, session_label(&file.session))
+                                .on_hover_text(file.path.display().to_string())
+                                .clicked();
+                            if let Some(timestamp) = &entry.timestamp {
+                                ui.label(timestamp);
+                            }
+                            if let Some(level) = &entry.level {
+                                let color = LogLevel::from_name(level)
+                                    .map(|level| level.color())
+                                    .unwrap_or(ui.visuals().text_color());
+                                ui.colored_label(color, level);
+                            }
+                            if let Some(module) = &entry.module {
+                                ui.weak(module);
+                            }
+                            ui.label(&entry.message);
+                        });
+                        if clicked {
+                            let selection = &mut self.history_browser.selected;
+                            if ui.input(|i| i.modifiers.ctrl || i.modifiers.command) {
+                                if is_selected {
+                                    selection.remove(&key);
+                                } else {
+                                    selection.insert(key);
+                                }
+                            } else {
+                                selection.clear();
+                                selection.insert(key);
+                            }
+                        }
+                    }
+                },
+            );
+    }
+}
+
+/// `20261019_101530` as `2026-10-19 10:15:30`; other names are kept as is.
+fn session_label(session: &str) -> String {
+    chrono::NaiveDateTime::parse_from_str(session, "%Y%m%d_%H%M%S")
+        .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|_| session.to_string())
+}
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -1,3 +1,4 @@
+use crate::ws::ThumbnailJob;
 use crate::ws::WindowInfo;
 use std::time::Instant;
 use std::time::Duration;
@@ -51,30 +52,7 @@ impl ProcessManagerApp {
             / num_rows as f32;
         let target_w = (cell_width * self.live_detail_percent).max(32.0);
         let target_h = (cell_height * self.live_detail_percent).max(32.0);
-        if !self.live_paused
-            && self.live_last_capture.elapsed() >= self.live_capture_interval
-            && !filtered_windows.is_empty()
-        {
-            let window_idx = self.live_current_capture_index % filtered_windows.len();
-            let window = &filtered_windows[window_idx];
-            if let Some(image_data) = self
-                .capture_window_thumbnail(
-                    window.window_id,
-                    target_w as i32,
-                    target_h as i32,
-                )
-            {
-                let texture = ctx
-                    .load_texture(
-                        format!("window_thumb_{}", window.window_id),
-                        image_data,
-                        egui::TextureOptions::default(),
-                    );
-                self.live_thumbnails.insert(window.window_id, texture);
-            }
-            self.live_current_capture_index += 1;
-            self.live_last_capture = Instant::now();
-        }
+        self.poll_live_thumbnails(ctx);
         let items_per_page = (self.live_grid_size * self.live_grid_size).max(1);
         let total_pages = (filtered_windows.len() + items_per_page - 1) / items_per_page;
         if self.live_page >= total_pages && total_pages > 0 {
@@ -87,6 +65,12 @@ impl ProcessManagerApp {
         } else {
             &[]
         };
+        self.schedule_live_thumbnails(
+            &filtered_windows,
+            page_windows,
+            target_w as i32,
+            target_h as i32,
+        );
         ui.horizontal(|ui| {
             ui.heading("📸 Live Preview - Real-time Window Thumbnails");
             ui.separator();
@@ -124,7 +108,16 @@ impl ProcessManagerApp {
             if ui.button("🐇 Fast").clicked() {
                 self.live_capture_interval = Duration::from_millis(100);
             }
+            ui.separator();
+            self.show_live_capture_stats(ui);
         });
+        #[cfg(target_os = "linux")]
+        if matches!(self.window_system, Some(crate::ws::LinuxWindowSystem::Wayland(_))) {
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                "⚠️ Wayland windows are not captured (the screencast portal asks the user to pick each window); showing icons instead",
+            );
+        }
         ui.separator();
         let spacing = 10.0;
         let available_size = ui.available_size();
@@ -191,15 +184,31 @@ impl ProcessManagerApp {
                                                 4.0,
                                                 egui::Color32::from_rgb(50, 50, 55),
                                             );
-                                        let icon = Self::get_process_icon(&window.process_name);
-                                        ui.painter()
-                                            .text(
-                                                rect.center(),
-                                                egui::Align2::CENTER_CENTER,
-                                                icon,
-                                                egui::FontId::proportional(32.0),
-                                                egui::Color32::from_gray(150),
-                                            );
+                                        let exe_path = self.exe_for_pid(window.pid);
+                                        match self.process_icons.get(&window.process_name, exe_path.as_deref()) {
+                                            Some(icon) => {
+                                                ui.painter()
+                                                    .image(
+                                                        icon.id(),
+                                                        egui::Rect::from_center_size(rect.center(), egui::vec2(48.0, 48.0)),
+                                                        egui::Rect::from_min_max(
+                                                            egui::pos2(0.0, 0.0),
+                                                            egui::pos2(1.0, 1.0),
+                                                        ),
+                                                        egui::Color32::WHITE,
+                                                    );
+                                            }
+                                            None => {
+                                                ui.painter()
+                                                    .text(
+                                                        rect.center(),
+                                                        egui::Align2::CENTER_CENTER,
+                                                        Self::get_process_icon(&window.process_name),
+                                                        egui::FontId::proportional(32.0),
+                                                        egui::Color32::from_gray(150),
+                                                    );
+                                            }
+                                        }
                                     }
                                     if response.clicked() {
                                         window_to_focus = Some(window.window_id);
@@ -257,4 +266,118 @@ impl ProcessManagerApp {
             ctx.request_repaint();
         }
     }
+    /// Uploads finished captures, reusing each window's texture.
+    fn poll_live_thumbnails(&mut self, ctx: &egui::Context) {
+        while let Ok(result) = self.live_pipeline.results.try_recv() {
+            self.live_pipeline.record(&result);
+            let Some(image) = result.image else {
+                continue;
+            };
+            if !self.windows.iter().any(|window| window.window_id == result.window_id) {
+                continue;
+            }
+            match self.live_thumbnails.get_mut(&result.window_id) {
+                Some(texture) => texture.set(image, egui::TextureOptions::LINEAR),
+                None => {
+                    let texture = ctx
+                        .load_texture(
+                            format!("window_thumb_{}", result.window_id),
+                            image,
+                            egui::TextureOptions::LINEAR,
+                        );
+                    self.live_thumbnails.insert(result.window_id, texture);
+                }
+            }
+        }
+    }
+    /// Queues captures for stale thumbnails: windows on the current page at
+    /// the chosen update speed and ahead of everything else, the rest ten
+    /// times less often. Thumbnails of closed windows are dropped, and the
+    /// workers let go of them.
+    fn schedule_live_thumbnails(
+        &mut self,
+        filtered_windows: &[WindowInfo],
+        page_windows: &[WindowInfo],
+        max_width: i32,
+        max_height: i32,
+    ) {
+        let existing: HashSet<u64> = self.windows.iter().map(|window| window.window_id).collect();
+        self.live_thumbnails.retain(|window_id, _| existing.contains(window_id));
+        let closed: Vec<u64> = self
+            .live_pipeline
+            .captured_at
+            .keys()
+            .filter(|window_id| !existing.contains(window_id))
+            .copied()
+            .collect();
+        for window_id in &closed {
+            self.live_pipeline.captured_at.remove(window_id);
+        }
+        self.live_pipeline.release(&closed);
+        let visible: HashSet<u64> = page_windows.iter().map(|window| window.window_id).collect();
+        self.live_pipeline.reprioritize(&visible, &existing);
+        if self.live_paused {
+            return;
+        }
+        #[cfg(target_os = "linux")]
+        if !matches!(self.window_system, Some(crate::ws::LinuxWindowSystem::X11(_))) {
+            return;
+        }
+        let now = Instant::now();
+        for window in filtered_windows {
+            let on_page = visible.contains(&window.window_id);
+            let interval = if on_page {
+                self.live_capture_interval
+            } else {
+                self.live_capture_interval * 10
+            };
+            let stale = self
+                .live_pipeline
+                .captured_at
+                .get(&window.window_id)
+                .is_none_or(|captured| now.duration_since(*captured) >= interval);
+            if stale {
+                self.live_pipeline
+                    .submit(
+                        ThumbnailJob {
+                            window_id: window.window_id,
+                            max_width,
+                            max_height,
+                            queued_at: now,
+                        },
+                        on_page,
+                    );
+            }
+        }
+    }
+    fn show_live_capture_stats(&self, ui: &mut egui::Ui) {
+        let pipeline = &self.live_pipeline;
+        if pipeline.capture_times.is_empty() {
+            ui.label("⏱ No captures yet");
+            return;
+        }
+        let mut times: Vec<Duration> = pipeline.capture_times.iter().copied().collect();
+        times.sort();
+        let average = times.iter().sum::<Duration>() / times.len() as u32;
+        let p95 = times[(times.len() * 95 / 100).min(times.len() - 1)];
+        let wait = pipeline.queue_waits.iter().sum::<Duration>()
+            / pipeline.queue_waits.len().max(1) as u32;
+        ui.label(
+                format!(
+                    "⏱ {:.0} ms avg · {:.0} ms p95 · {} queued",
+                    average.as_secs_f64() * 1000.0,
+                    p95.as_secs_f64() * 1000.0,
+                    pipeline.queued(),
+                ),
+            )
+            .on_hover_text(
+                format!(
+                    "Capture and downscale time over the last {} thumbnails\n\
+                    Average wait in queue: {:.0} ms\nFailed captures: {}",
+                    times.len(),
+                    wait.as_secs_f64() * 1000.0,
+                    pipeline.failures,
+                ),
+            );
+    }
 }
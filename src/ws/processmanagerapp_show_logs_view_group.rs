@@ -4,82 +4,242 @@
 //!
 //! 🤖 Generated with [SplitRS](https://github.com/cool-japan/splitrs)
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use crate::ws::LogLevel;
 use super::processmanagerapp_type::ProcessManagerApp;
 
 impl ProcessManagerApp {
     pub(crate) fn show_logs_view(&mut self, ui: &mut egui::Ui) {
-        egui::ScrollArea::vertical()
-            .auto_shrink([false, false])
-            .stick_to_bottom(true)
-            .show(
-                ui,
-                |ui| {
-                    ui.heading("📋 Application Log");
-                    ui.add_space(10.0);
-                    ui.horizontal(|ui| {
-                        if ui.button("🗑 Clear Logs").clicked() {
-                            self.logs.clear();
-                            self.selected_log_indices.clear();
-                            self.add_log("Logs cleared".to_string());
+        ui.horizontal(|ui| {
+            let application = !self.audit_log.is_open && !self.history_browser.is_open;
+            if ui.selectable_label(application, "📋 Application Log").clicked() {
+                self.audit_log.is_open = false;
+                self.history_browser.is_open = false;
+            }
+            if ui.selectable_label(self.audit_log.is_open, "🧾 File Audit Log").clicked()
+                && !self.audit_log.is_open
+            {
+                self.audit_log.is_open = true;
+                self.history_browser.is_open = false;
+                self.load_audit_log();
+            }
+            if ui.selectable_label(self.history_browser.is_open, "🗂 Saved History").clicked()
+                && !self.history_browser.is_open
+            {
+                self.history_browser.is_open = true;
+                self.audit_log.is_open = false;
+                self.load_history_files();
+            }
+        });
+        ui.separator();
+        if self.audit_log.is_open {
+            self.show_audit_log(ui);
+            return;
+        }
+        if self.history_browser.is_open {
+            self.show_history_browser(ui);
+            return;
+        }
+        self.show_application_log(ui);
+    }
+    fn show_application_log(&mut self, ui: &mut egui::Ui) {
+        self.refresh_visible_logs();
+        ui.horizontal(|ui| {
+            if ui.button("🗑 Clear Logs").clicked() {
+                self.logs.clear();
+                self.selected_log_ids.clear();
+                self.add_log("Logs cleared".to_string());
+            }
+            if ui.button("📋 Copy Selected").clicked() && !self.selected_log_ids.is_empty() {
+                let combined = self
+                    .logs
+                    .iter()
+                    .filter(|record| self.selected_log_ids.contains(&record.id))
+                    .map(|record| record.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                ui.ctx().copy_text(combined);
+            }
+            if ui.button("📋 Copy Shown").clicked() {
+                let shown = self
+                    .log_view
+                    .visible
+                    .iter()
+                    .rev()
+                    .map(|&index| self.logs[index].to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                ui.ctx().copy_text(shown);
+            }
+            if ui.button("❌ Clear Selection").clicked() {
+                self.selected_log_ids.clear();
+            }
+            self.show_export_format_picker(ui);
+            if ui
+                .add_enabled(!self.selected_log_ids.is_empty(), egui::Button::new("💾 Export Selected"))
+                .clicked()
+            {
+                self.export_selected_logs();
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("Level:");
+            egui::ComboBox::from_id_salt("log_level_filter")
+                .selected_text(format!("{} and above", self.log_view.min_level.name()))
+                .show_ui(
+                    ui,
+                    |ui| {
+                        for level in LogLevel::ALL {
+                            ui.selectable_value(
+                                &mut self.log_view.min_level,
+                                level,
+                                level.name(),
+                            );
                         }
-                        if ui.button("📋 Copy Selected").clicked() {
-                            if !self.selected_log_indices.is_empty() {
-                                let mut selected_logs: Vec<_> = self
-                                    .selected_log_indices
-                                    .iter()
-                                    .filter_map(|&idx| self.logs.get(idx))
-                                    .cloned()
-                                    .collect();
-                                selected_logs.sort();
-                                let combined = selected_logs.join("\n");
-                                ui.ctx().copy_text(combined);}
+                    },
+                );
+            let modules: BTreeSet<String> = self
+                .logs
+                .iter()
+                .map(|record| record.module.clone())
+                .collect();
+            ui.label("Module:");
+            egui::ComboBox::from_id_salt("log_module_filter")
+                .selected_text(self.log_view.module_filter.as_deref().unwrap_or("All"))
+                .show_ui(
+                    ui,
+                    |ui| {
+                        ui.selectable_value(&mut self.log_view.module_filter, None, "All");
+                        for module in modules {
+                            let label = module.clone();
+                            ui.selectable_value(
+                                &mut self.log_view.module_filter,
+                                Some(module),
+                                label,
+                            );
                         }
-                        if ui.button("📋 Copy All").clicked() {
-                            let all_logs = self.logs.join("\n");
-                            ui.ctx().copy_text(all_logs);}
-                        if ui.button("❌ Clear Selection").clicked() {
-                            self.selected_log_indices.clear();
+                    },
+                );
+            ui.label("🔍");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.log_view.search)
+                    .hint_text("search messages")
+                    .desired_width(200.0),
+            );
+        });
+        let mut settings_changed = false;
+        ui.horizontal(|ui| {
+            let settings = &mut self.log_view.settings;
+            settings_changed |= ui
+                .checkbox(&mut settings.to_file, "💾 Write to log file")
+                .on_hover_text(Self::log_file_path().display().to_string())
+                .changed();
+            ui.label("Rotate at");
+            settings_changed |= ui
+                .add(
+                    egui::DragValue::new(&mut settings.max_file_kb)
+                        .range(16..=1024 * 1024)
+                        .suffix(" KB"),
+                )
+                .changed();
+            ui.label("Library messages:");
+            egui::ComboBox::from_id_salt("log_library_level")
+                .selected_text(settings.library_level.name())
+                .show_ui(
+                    ui,
+                    |ui| {
+                        for level in LogLevel::ALL {
+                            settings_changed |= ui
+                                .selectable_value(&mut settings.library_level, level, level.name())
+                                .changed();
                         }
-                        ui.label(
-                            format!(
-                                "Selected: {} / {}", self.selected_log_indices.len(), self
-                                .logs.len()
-                            ),
-                        );
-                    });
-                    ui.add_space(5.0);
-                    ui.label(
-                        "💡 Tip: Click to select, Ctrl+Click to toggle selection",
-                    );
-                    ui.add_space(5.0);
-                    ui.separator();
-                    ui.add_space(5.0);
-                    for (idx, log) in self.logs.iter().enumerate().rev() {
-                        let is_selected = self.selected_log_indices.contains(&idx);
+                    },
+                );
+        });
+        if settings_changed {
+            self.apply_log_settings();
+            self.save_config();
+        }
+        if let Some(error) = &self.log_view.file_error {
+            ui.colored_label(egui::Color32::RED, format!("❌ Log file: {}", error));
+        }
+        ui.label("💡 Tip: Click to select, Ctrl+Click to toggle selection");
+        ui.separator();
+        // Newest first; only the rows in view are laid out. The filter
+        // widgets above may have changed this frame.
+        self.refresh_visible_logs();
+        let visible = std::mem::take(&mut self.log_view.visible);
+        ui.label(format!("Showing {} of {} entries", visible.len(), self.logs.len()));
+        if visible.is_empty() {
+            ui.add_space(20.0);
+            ui.label("No log entries yet");
+            self.log_view.visible = visible;
+            return;
+        }
+        let row_height = ui.text_style_height(&egui::TextStyle::Body) + 4.0;
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show_rows(
+                ui,
+                row_height,
+                visible.len(),
+                |ui, rows| {
+                    for &index in &visible[rows] {
+                        let record = &self.logs[index];
+                        let is_selected = self.selected_log_ids.contains(&record.id);
+                        let id = record.id;
+                        let mut clicked = false;
                         ui.horizontal(|ui| {
-                            let response = ui
-                                .selectable_label(is_selected, format!("[{}]", idx + 1));
-                            if response.clicked() {
-                                if ui.input(|i| i.modifiers.ctrl || i.modifiers.command) {
-                                    if is_selected {
-                                        self.selected_log_indices.remove(&idx);
-                                    } else {
-                                        self.selected_log_indices.insert(idx);
-                                    }
+                            clicked = ui
+                                .selectable_label(is_selected, format!("[{}]", id))
+                                .clicked();
+                            ui.label(record.timestamp.format("%H:%M:%S").to_string())
+                                .on_hover_text(record.timestamp.to_rfc3339());
+                            ui.colored_label(record.level.color(), record.level.name());
+                            ui.weak(&record.module);
+                            ui.label(&record.message);
+                        });
+                        if clicked {
+                            if ui.input(|i| i.modifiers.ctrl || i.modifiers.command) {
+                                if is_selected {
+                                    self.selected_log_ids.remove(&id);
                                 } else {
-                                    self.selected_log_indices.clear();
-                                    self.selected_log_indices.insert(idx);
+                                    self.selected_log_ids.insert(id);
                                 }
+                            } else {
+                                self.selected_log_ids.clear();
+                                self.selected_log_ids.insert(id);
                             }
-                            ui.label(log);
-                        });
-                    }
-                    if self.logs.is_empty() {
-                        ui.add_space(20.0);
-                        ui.label("No log entries yet");
+                        }
                     }
                 },
             );
+        self.log_view.visible = visible;
+    }
+    /// Re-filters `logs` into `log_view.visible` when a filter changed or
+    /// records were added or evicted since the last frame.
+    fn refresh_visible_logs(&mut self) {
+        let search = self.log_view.search.trim().to_lowercase();
+        let key = (
+            self.log_view.min_level,
+            self.log_view.module_filter.clone(),
+            search,
+            self.logs.front().map_or(0, |record| record.id),
+            self.log_view.next_id,
+        );
+        if self.log_view.visible_key.as_ref() == Some(&key) {
+            return;
+        }
+        let (min_level, module_filter, search, _, _) = &key;
+        self.log_view.visible = (0..self.logs.len())
+            .rev()
+            .filter(|&index| {
+                let record = &self.logs[index];
+                record.level <= *min_level
+                    && module_filter.as_ref().is_none_or(|module| &record.module == module)
+                    && (search.is_empty() || record.message.to_lowercase().contains(search.as_str()))
+            })
+            .collect();
+        self.log_view.visible_key = Some(key);
     }
 }
@@ -5,6 +5,7 @@
 //! 🤖 Generated with [SplitRS](https://github.com/cool-japan/splitrs)
 
 use std::collections::{HashMap, HashSet};
+use crate::ws::CustomProgram;
 use super::processmanagerapp_type::ProcessManagerApp;
 
 impl ProcessManagerApp {
@@ -15,6 +16,8 @@ impl ProcessManagerApp {
                 ui,
                 |ui| {
                     ui.heading("🚀 Launch New Program");
+                    ui.add_space(10.0);
+                    self.show_launcher_search(ui);
                     ui.add_space(20.0);
                     ui.horizontal(|ui| {
                         ui.label("Program Path:");
@@ -41,18 +44,44 @@ impl ProcessManagerApp {
                         );
                     });
                     ui.add_space(10.0);
-                    #[cfg(windows)]
-                    ui.checkbox(&mut self.run_as_admin, "⚡ Run as Administrator");
+                    ui.horizontal(|ui| {
+                        ui.label("Working Dir:    ");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.program_working_dir)
+                                .desired_width(400.0)
+                                .hint_text("Optional (defaults to the program's folder)"),
+                        );
+                    });
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Environment:   ");
+                        ui.add(
+                            egui::TextEdit::multiline(&mut self.program_env)
+                                .desired_width(400.0)
+                                .desired_rows(2)
+                                .hint_text("KEY=VALUE, one per line"),
+                        );
+                    });
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        #[cfg(windows)]
+                        ui.checkbox(&mut self.run_as_admin, "⚡ Run as Administrator");
+                        ui.add_enabled_ui(
+                            !self.run_as_admin,
+                            |ui| {
+                                ui.checkbox(&mut self.program_capture, "🖥 Capture output")
+                                    .on_hover_text("Show stdout/stderr in a console pane below");
+                                ui.checkbox(&mut self.program_restart, "🔁 Restart on crash")
+                                    .on_hover_text("Restart with increasing delay when it exits with an error");
+                            },
+                        );
+                    });
                     ui.add_space(20.0);
                     ui.horizontal(|ui| {
                         if ui.button("▶ Launch Program").clicked() {
                             if !self.program_path.is_empty() {
-                                let program_path = self.program_path.clone();
-                                let program_args = self.program_args.clone();
-                                let run_as_admin = self.run_as_admin;
-                                match self
-                                    .launch_program(&program_path, &program_args, run_as_admin)
-                                {
+                                let profile = self.launch_form_profile();
+                                match self.launch_profile(profile) {
                                     Ok(pid) => {
                                         if pid > 0 {
                                             ui.label(
@@ -63,8 +92,6 @@ impl ProcessManagerApp {
                                                 "✓ Program launched with elevation (PID unavailable)",
                                             );
                                         }
-                                        self.program_path.clear();
-                                        self.program_args.clear();
                                         self.refresh_all_data();
                                     }
                                     Err(e) => {
@@ -77,10 +104,27 @@ impl ProcessManagerApp {
                             }
                         }
                         if ui.button("🗑 Clear").clicked() {
-                            self.program_path.clear();
-                            self.program_args.clear();
+                            self.clear_launch_form();
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.program_profile_name)
+                                .desired_width(200.0)
+                                .hint_text("profile name"),
+                        );
+                        let can_save = !self.program_path.is_empty()
+                            && !self.program_profile_name.trim().is_empty();
+                        if ui
+                            .add_enabled(can_save, egui::Button::new("💾 Save as Profile"))
+                            .on_hover_text("Add to Custom Programs, replacing a profile with the same name")
+                            .clicked()
+                        {
+                            self.save_launch_profile();
                         }
                     });
+                    self.show_program_groups(ui);
+                    self.show_launch_consoles(ui);
                     ui.add_space(30.0);
                     ui.separator();
                     ui.add_space(10.0);
@@ -278,24 +322,31 @@ impl ProcessManagerApp {
                         ui.add_space(10.0);
                         ui.heading("⭐ Custom Programs");
                         ui.add_space(10.0);
-                        ui.horizontal_wrapped(|ui| {
-                            let programs = self.custom_programs.clone();
-                            for program in programs {
+                        let programs = self.custom_programs.clone();
+                        let mut remove = None;
+                        for (index, program) in programs.into_iter().enumerate() {
+                            ui.horizontal(|ui| {
                                 if ui
                                     .button(format!("🚀 {}", program.name))
-                                    .on_hover_text(&program.path)
+                                    .on_hover_text(format!("{} {}", program.path, program.args))
                                     .clicked()
                                 {
-                                    let _ = self
-                                        .launch_program(
-                                            &program.path,
-                                            &program.args,
-                                            program.admin,
-                                        );
+                                    let _ = self.launch_profile(program.clone());
                                     self.refresh_all_data();
                                 }
-                            }
-                        });
+                                if ui.small_button("✏").on_hover_text("Load into the form").clicked() {
+                                    self.edit_launch_profile(&program);
+                                }
+                                if ui.small_button("✖").on_hover_text("Delete profile").clicked() {
+                                    remove = Some(index);
+                                }
+                            });
+                        }
+                        if let Some(index) = remove {
+                            let program = self.custom_programs.remove(index);
+                            self.add_log(format!("🗑 Deleted profile '{}'", program.name));
+                            self.save_config();
+                        }
                     }
                 },
             );
@@ -372,105 +423,74 @@ impl ProcessManagerApp {
         args: &str,
         run_as_admin: bool,
     ) -> Result<u32, String> {
-        let launch_info = if args.is_empty() {
-            if run_as_admin {
-                format!("Launching with elevation: {}", program)
+        let name = std::path::Path::new(program)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| program.to_string());
+        self.launch_profile(CustomProgram {
+            name,
+            path: program.to_string(),
+            args: args.to_string(),
+            admin: run_as_admin,
+            ..CustomProgram::default()
+        })
+    }
+    #[cfg(windows)]
+    pub(crate) fn launch_elevated(&mut self, program: &str, args: &str) -> Result<u32, String> {
+        use windows::Win32::UI::Shell::{ShellExecuteW, SE_ERR_ACCESSDENIED};
+        use windows::Win32::Foundation::HWND;
+        use windows::core::PCWSTR;
+        use std::os::windows::ffi::OsStrExt;
+        use std::ffi::OsStr;
+        unsafe {
+            let operation = windows::core::w!("runas");
+            let file: Vec<u16> = OsStr::new(program)
+                .encode_wide()
+                .chain(std::iter::once(0))
+                .collect();
+            let parameters: Vec<u16> = if !args.is_empty() {
+                OsStr::new(args)
+                    .encode_wide()
+                    .chain(std::iter::once(0))
+                    .collect()
             } else {
-                format!("Launching: {}", program)
-            }
-        } else {
-            if run_as_admin {
-                format!("Launching with elevation: {} {}", program, args)
+                vec![0]
+            };
+            let directory: Vec<u16> = if let Some(parent) = std::path::Path::new(
+                    program,
+                )
+                .parent()
+            {
+                OsStr::new(&parent.to_string_lossy().to_string())
+                    .encode_wide()
+                    .chain(std::iter::once(0))
+                    .collect()
             } else {
-                format!("Launching: {} {}", program, args)
-            }
-        };
-        self.add_log(launch_info);
-        #[cfg(windows)]
-        {
-            if run_as_admin {
-                use windows::Win32::UI::Shell::{ShellExecuteW, SE_ERR_ACCESSDENIED};
-                use windows::Win32::Foundation::HWND;
-                use windows::core::PCWSTR;
-                use std::os::windows::ffi::OsStrExt;
-                use std::ffi::OsStr;
-                unsafe {
-                    let operation = windows::core::w!("runas");
-                    let file: Vec<u16> = OsStr::new(program)
-                        .encode_wide()
-                        .chain(std::iter::once(0))
-                        .collect();
-                    let parameters: Vec<u16> = if !args.is_empty() {
-                        OsStr::new(args)
-                            .encode_wide()
-                            .chain(std::iter::once(0))
-                            .collect()
-                    } else {
-                        vec![0]
-                    };
-                    let directory: Vec<u16> = if let Some(parent) = std::path::Path::new(
-                            program,
-                        )
-                        .parent()
-                    {
-                        OsStr::new(&parent.to_string_lossy().to_string())
-                            .encode_wide()
-                            .chain(std::iter::once(0))
-                            .collect()
-                    } else {
-                        vec![0]
-                    };
-                    let result = ShellExecuteW(
-                        HWND(std::ptr::null_mut()),
-                        PCWSTR::from_raw(operation.as_ptr()),
-                        PCWSTR::from_raw(file.as_ptr()),
-                        PCWSTR::from_raw(parameters.as_ptr()),
-                        PCWSTR::from_raw(directory.as_ptr()),
-                        windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL,
-                    );
-                    if result.0 as i32 <= 32 {
-                        let error_msg = if result.0 as i32 == SE_ERR_ACCESSDENIED as i32
-                        {
-                            "Access denied. User canceled the elevation prompt."
-                                .to_string()
-                        } else {
-                            format!("ShellExecute failed with code: {}", result.0 as i32)
-                        };
-                        self.add_log(format!("✗ Failed to launch: {}", error_msg));
-                        return Err(error_msg);
-                    }
-                    self.add_log(
-                        "✓ Successfully launched program with elevation".to_string(),
-                    );
-                    return Ok(0);
-                }
-            }
-        }
-        use std::process::Command;
-        let mut cmd = Command::new(program);
-        if !args.is_empty() {
-            for arg in args.split_whitespace() {
-                cmd.arg(arg);
-            }
-        }
-        if let Some(parent) = std::path::Path::new(program).parent() {
-            if parent.exists() {
-                cmd.current_dir(parent);
-            }
-        }
-        match cmd.spawn() {
-            Ok(child) => {
-                let pid = child.id();
-                self.add_log(
-                    format!("✓ Successfully launched program with PID: {}", pid),
-                );
-                Ok(pid)
-            }
-            Err(e) => {
-                let error_msg = format!("✗ Failed to launch: {}", e);
-                self.add_log(error_msg.clone());
-                Err(error_msg)
+                vec![0]
+            };
+            let result = ShellExecuteW(
+                HWND(std::ptr::null_mut()),
+                PCWSTR::from_raw(operation.as_ptr()),
+                PCWSTR::from_raw(file.as_ptr()),
+                PCWSTR::from_raw(parameters.as_ptr()),
+                PCWSTR::from_raw(directory.as_ptr()),
+                windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL,
+            );
+            if result.0 as i32 <= 32 {
+                let error_msg = if result.0 as i32 == SE_ERR_ACCESSDENIED as i32
+                {
+                    "Access denied. User canceled the elevation prompt."
+                        .to_string()
+                } else {
+                    format!("ShellExecute failed with code: {}", result.0 as i32)
+                };
+                self.add_log_at(crate::ws::LogLevel::Error, format!("✗ Failed to launch: {}", error_msg));
+                return Err(error_msg);
             }
+            self.add_log(
+                "✓ Successfully launched program with elevation".to_string(),
+            );
+            Ok(0)
         }
     }
 }
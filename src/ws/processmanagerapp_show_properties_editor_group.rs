@@ -0,0 +1,573 @@
+use crate::ws::LogLevel;
+use crate::ws::FileProperties;
+// # ProcessManagerApp - show_properties_editor_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Only fields that differ from the values loaded when the editor opened are
+// written back, so applying recursively never clobbers untouched attributes.
+// Permissions follow the same rule bit by bit: each entry keeps its own mode
+// and only the bits the user toggled are set or cleared. Below the edited
+// item, execute bits are only added to files that are already executable
+// (chmod's `X`).
+
+use chrono::TimeZone;
+use std::time::SystemTime;
+use super::processmanagerapp_type::ProcessManagerApp;
+
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Attribute changes resolved from the editor, applied to every target path.
+#[derive(Default)]
+struct PropertyChanges {
+    /// Permission bits the user turned on.
+    mode_set: u32,
+    /// Permission bits the user turned off.
+    mode_clear: u32,
+    owner: Option<u32>,
+    group: Option<u32>,
+    accessed: Option<SystemTime>,
+    modified: Option<SystemTime>,
+    readonly: Option<bool>,
+    hidden: Option<bool>,
+}
+impl PropertyChanges {
+    fn is_empty(&self) -> bool {
+        self.mode_set == 0 && self.mode_clear == 0 && self.owner.is_none() && self.group.is_none()
+            && self.accessed.is_none() && self.modified.is_none()
+            && self.readonly.is_none() && self.hidden.is_none()
+    }
+}
+
+impl ProcessManagerApp {
+    pub fn open_properties_editor(&mut self, path: &str) {
+        let editor = &mut self.properties_editor;
+        editor.error_msg = None;
+        editor.recursive = false;
+        editor.path = path.to_string();
+        match Self::read_file_properties(path) {
+            Ok(properties) => {
+                let metadata = std::fs::metadata(path).ok();
+                editor.is_directory = metadata.as_ref().is_some_and(|m| m.is_dir());
+                editor.size = metadata.map(|m| m.len()).unwrap_or(0);
+                editor.mode_text = format!("{:04o}", properties.mode);
+                editor.original = properties.clone();
+                editor.edited = properties;
+            }
+            Err(e) => {
+                editor.original = FileProperties::default();
+                editor.edited = FileProperties::default();
+                editor.error_msg = Some(format!("Failed to read properties: {}", e));
+            }
+        }
+        editor.is_open = true;
+    }
+    pub(crate) fn show_properties_editor(&mut self, ctx: &egui::Context) {
+        let mut open = self.properties_editor.is_open;
+        let mut apply = false;
+        let mut reload = false;
+        egui::Window::new("ℹ️ Properties")
+            .open(&mut open)
+            .default_size([460.0, 420.0])
+            .resizable(true)
+            .show(
+                ctx,
+                |ui| {
+                    let editor = &mut self.properties_editor;
+                    ui.label(egui::RichText::new(&editor.path).monospace());
+                    ui.label(
+                        format!(
+                            "Type: {}", if editor.edited.symlink_target.is_some() {
+                            "Symbolic link" } else if editor.is_directory { "Directory" }
+                            else { "File" }
+                        ),
+                    );
+                    if !editor.is_directory {
+                        ui.label(format!("Size: {} bytes", editor.size));
+                    }
+                    ui.separator();
+                    egui::Grid::new("properties_editor_grid")
+                        .num_columns(2)
+                        .spacing([12.0, 6.0])
+                        .show(
+                            ui,
+                            |ui| {
+                                if let Some(target) = &mut editor.edited.symlink_target {
+                                    ui.label("Link target:");
+                                    ui.add_enabled(
+                                        cfg!(unix),
+                                        egui::TextEdit::singleline(target).desired_width(280.0),
+                                    );
+                                    ui.end_row();
+                                }
+                                if cfg!(unix) {
+                                    ui.label("Owner:");
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut editor.edited.owner)
+                                            .hint_text("name or uid")
+                                            .desired_width(160.0),
+                                    );
+                                    ui.end_row();
+                                    ui.label("Group:");
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut editor.edited.group)
+                                            .hint_text("name or gid")
+                                            .desired_width(160.0),
+                                    );
+                                    ui.end_row();
+                                }
+                                ui.label("Accessed:");
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut editor.edited.accessed)
+                                        .hint_text("YYYY-MM-DD HH:MM:SS")
+                                        .desired_width(160.0),
+                                );
+                                ui.end_row();
+                                ui.label("Modified:");
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut editor.edited.modified)
+                                        .hint_text("YYYY-MM-DD HH:MM:SS")
+                                        .desired_width(160.0),
+                                );
+                                ui.end_row();
+                            },
+                        );
+                    ui.separator();
+                    if cfg!(unix) {
+                        ui.horizontal(|ui| {
+                            ui.label("Mode:");
+                            let response = ui
+                                .add(
+                                    egui::TextEdit::singleline(&mut editor.mode_text)
+                                        .desired_width(50.0),
+                                );
+                            if response.changed() {
+                                if let Ok(mode) = u32::from_str_radix(
+                                    editor.mode_text.trim(),
+                                    8,
+                                ) {
+                                    editor.edited.mode = mode & 0o7777;
+                                }
+                            }
+                        });
+                        let mut mode = editor.edited.mode;
+                        egui::Grid::new("properties_mode_grid")
+                            .num_columns(4)
+                            .show(
+                                ui,
+                                |ui| {
+                                    ui.label("");
+                                    ui.label("Read");
+                                    ui.label("Write");
+                                    ui.label("Execute");
+                                    ui.end_row();
+                                    for (name, shift) in [("Owner", 6), ("Group", 3), ("Others", 0)] {
+                                        ui.label(name);
+                                        for bit in [0o4, 0o2, 0o1] {
+                                            let mask = bit << shift;
+                                            let mut set = mode & mask != 0;
+                                            if ui.checkbox(&mut set, "").changed() {
+                                                mode ^= mask;
+                                            }
+                                        }
+                                        ui.end_row();
+                                    }
+                                },
+                            );
+                        ui.horizontal(|ui| {
+                            for (name, mask) in [
+                                ("setuid", 0o4000),
+                                ("setgid", 0o2000),
+                                ("sticky", 0o1000),
+                            ] {
+                                let mut set = mode & mask != 0;
+                                if ui.checkbox(&mut set, name).changed() {
+                                    mode ^= mask;
+                                }
+                            }
+                        });
+                        if mode != editor.edited.mode {
+                            editor.edited.mode = mode;
+                            editor.mode_text = format!("{:04o}", mode);
+                        }
+                    } else {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut editor.edited.readonly, "Read-only");
+                            ui.checkbox(&mut editor.edited.hidden, "Hidden");
+                        });
+                    }
+                    ui.separator();
+                    if editor.is_directory {
+                        ui.checkbox(
+                            &mut editor.recursive,
+                            "Apply to all files and subdirectories",
+                        );
+                    }
+                    if let Some(error) = &editor.error_msg {
+                        ui.colored_label(egui::Color32::RED, format!("❌ {}", error));
+                    }
+                    ui.horizontal(|ui| {
+                        let changed = editor.edited != editor.original;
+                        if ui.add_enabled(changed, egui::Button::new("✅ Apply")).clicked() {
+                            apply = true;
+                        }
+                        if ui.button("🔄 Reload").clicked() {
+                            reload = true;
+                        }
+                    });
+                },
+            );
+        if apply {
+            self.apply_properties_changes();
+        }
+        if reload || apply && self.properties_editor.error_msg.is_none() {
+            let path = self.properties_editor.path.clone();
+            let recursive = self.properties_editor.recursive;
+            self.open_properties_editor(&path);
+            self.properties_editor.recursive = recursive;
+        }
+        self.properties_editor.is_open &= open;
+    }
+    fn apply_properties_changes(&mut self) {
+        self.properties_editor.error_msg = None;
+        let path = self.properties_editor.path.clone();
+        let original = self.properties_editor.original.clone();
+        let edited = self.properties_editor.edited.clone();
+        let changes = match Self::resolve_property_changes(&original, &edited) {
+            Ok(changes) => changes,
+            Err(e) => {
+                self.properties_editor.error_msg = Some(e);
+                return;
+            }
+        };
+        if edited.symlink_target != original.symlink_target {
+            if let Some(target) = &edited.symlink_target {
+                match Self::retarget_symlink(&path, target) {
+                    Ok(()) => {
+                        self.add_log(
+                            format!(
+                                "🔗 SYMLINK: {} -> {} (was {})", path, target, original
+                                .symlink_target.clone().unwrap_or_default()
+                            ),
+                        )
+                    }
+                    Err(e) => {
+                        self.add_log_at(LogLevel::Error, format!("❌ SYMLINK FAILED: {} - {}", path, e));
+                        self.properties_editor.error_msg = Some(e.to_string());
+                        return;
+                    }
+                }
+            }
+        }
+        if changes.is_empty() {
+            return;
+        }
+        let mut targets = vec![std::path::PathBuf::from(&path)];
+        if self.properties_editor.recursive && self.properties_editor.is_directory {
+            Self::collect_tree(std::path::Path::new(&path), &mut targets);
+        }
+        let mut failures = 0;
+        for (index, target) in targets.iter().enumerate() {
+            for entry in Self::apply_property_changes(target, &changes, index > 0) {
+                match entry {
+                    Ok(message) => self.add_log(message),
+                    Err(message) => {
+                        failures += 1;
+                        self.add_log_at(LogLevel::Error, message);
+                    }
+                }
+            }
+        }
+        if targets.len() > 1 {
+            self.add_log(
+                format!(
+                    "ℹ️ PROPERTIES: Applied to {} item(s) under {} ({} failure(s))",
+                    targets.len(), path, failures
+                ),
+            );
+        }
+        if failures > 0 {
+            self.properties_editor.error_msg = Some(
+                format!("{} change(s) failed, see Logs", failures),
+            );
+        }
+    }
+    /// Collects every entry below `dir` without following symlinked
+    /// directories, so link cycles cannot recurse forever.
+    fn collect_tree(dir: &std::path::Path, targets: &mut Vec<std::path::PathBuf>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_symlink() {
+                continue;
+            }
+            targets.push(entry.path());
+            if file_type.is_dir() {
+                Self::collect_tree(&entry.path(), targets);
+            }
+        }
+    }
+    fn resolve_property_changes(
+        original: &FileProperties,
+        edited: &FileProperties,
+    ) -> Result<PropertyChanges, String> {
+        let mut changes = PropertyChanges::default();
+        changes.mode_set = edited.mode & !original.mode;
+        changes.mode_clear = original.mode & !edited.mode;
+        if edited.owner != original.owner {
+            changes.owner = Some(
+                Self::resolve_user_id(edited.owner.trim())
+                    .ok_or_else(|| format!("Unknown user: {}", edited.owner))?,
+            );
+        }
+        if edited.group != original.group {
+            changes.group = Some(
+                Self::resolve_group_id(edited.group.trim())
+                    .ok_or_else(|| format!("Unknown group: {}", edited.group))?,
+            );
+        }
+        if edited.accessed != original.accessed {
+            changes.accessed = Some(Self::parse_property_time(&edited.accessed)?);
+        }
+        if edited.modified != original.modified {
+            changes.modified = Some(Self::parse_property_time(&edited.modified)?);
+        }
+        if edited.readonly != original.readonly {
+            changes.readonly = Some(edited.readonly);
+        }
+        if edited.hidden != original.hidden {
+            changes.hidden = Some(edited.hidden);
+        }
+        Ok(changes)
+    }
+    /// Applies `changes` to `path`. For `descendant`s of a recursive apply,
+    /// added execute bits follow chmod's `X` rule and only reach directories
+    /// and files that are already executable by someone; the edited item
+    /// itself gets the requested mode as is.
+    fn apply_property_changes(
+        path: &std::path::Path,
+        changes: &PropertyChanges,
+        descendant: bool,
+    ) -> Vec<Result<String, String>> {
+        let display = path.display();
+        let report = |label: &str, detail: String, result: std::io::Result<()>| match result {
+            Ok(()) => Ok(format!("🔐 {}: {} {}", label, display, detail)),
+            Err(e) => Err(format!("❌ {} FAILED: {} - {}", label, display, e)),
+        };
+        let mut results = Vec::new();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if changes.mode_set != 0 || changes.mode_clear != 0 {
+                const EXECUTE_BITS: u32 = 0o111;
+                let mut applied = 0;
+                let result = std::fs::metadata(path)
+                    .and_then(|metadata| {
+                        let current = metadata.permissions().mode() & 0o7777;
+                        let mut set = changes.mode_set;
+                        if descendant && !metadata.is_dir() && current & EXECUTE_BITS == 0 {
+                            set &= !EXECUTE_BITS;
+                        }
+                        applied = (current & !changes.mode_clear) | set;
+                        std::fs::set_permissions(path, std::fs::Permissions::from_mode(applied))
+                    });
+                results.push(report("CHMOD", format!("{:04o}", applied), result));
+            }
+            if changes.owner.is_some() || changes.group.is_some() {
+                let result = std::os::unix::fs::chown(path, changes.owner, changes.group);
+                let detail = format!(
+                    "{}:{}", changes.owner.map(|id| id.to_string()).unwrap_or_default(),
+                    changes.group.map(|id| id.to_string()).unwrap_or_default()
+                );
+                results.push(report("CHOWN", detail, result));
+            }
+        }
+        if changes.accessed.is_some() || changes.modified.is_some() {
+            let mut times = std::fs::FileTimes::new();
+            let mut detail = Vec::new();
+            if let Some(accessed) = changes.accessed {
+                times = times.set_accessed(accessed);
+                detail.push(format!("atime={}", Self::format_property_time(accessed)));
+            }
+            if let Some(modified) = changes.modified {
+                times = times.set_modified(modified);
+                detail.push(format!("mtime={}", Self::format_property_time(modified)));
+            }
+            let result = Self::open_for_times(path).and_then(|file| file.set_times(times));
+            results.push(report("TOUCH", detail.join(" "), result));
+        }
+        if let Some(readonly) = changes.readonly {
+            let result = std::fs::metadata(path)
+                .and_then(|metadata| {
+                    let mut permissions = metadata.permissions();
+                    permissions.set_readonly(readonly);
+                    std::fs::set_permissions(path, permissions)
+                });
+            results.push(report("ATTRIB", format!("read-only={}", readonly), result));
+        }
+        if let Some(hidden) = changes.hidden {
+            results.push(report("ATTRIB", format!("hidden={}", hidden), Self::set_hidden(path, hidden)));
+        }
+        results
+    }
+    fn read_file_properties(path: &str) -> std::io::Result<FileProperties> {
+        let link_metadata = std::fs::symlink_metadata(path)?;
+        let symlink_target = if link_metadata.file_type().is_symlink() {
+            Some(std::fs::read_link(path)?.to_string_lossy().to_string())
+        } else {
+            None
+        };
+        let metadata = std::fs::metadata(path).unwrap_or(link_metadata);
+        let mut properties = FileProperties {
+            accessed: metadata
+                .accessed()
+                .map(Self::format_property_time)
+                .unwrap_or_default(),
+            modified: metadata
+                .modified()
+                .map(Self::format_property_time)
+                .unwrap_or_default(),
+            symlink_target,
+            readonly: metadata.permissions().readonly(),
+            ..Default::default()
+        };
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            properties.mode = metadata.mode() & 0o7777;
+            properties.owner = nix::unistd::User::from_uid(metadata.uid().into())
+                .ok()
+                .flatten()
+                .map(|user| user.name)
+                .unwrap_or_else(|| metadata.uid().to_string());
+            properties.group = nix::unistd::Group::from_gid(metadata.gid().into())
+                .ok()
+                .flatten()
+                .map(|group| group.name)
+                .unwrap_or_else(|| metadata.gid().to_string());
+            properties.hidden = std::path::Path::new(path)
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        }
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::fs::MetadataExt;
+            const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+            properties.hidden = metadata.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0;
+        }
+        Ok(properties)
+    }
+    /// Resolves a user name (or numeric id) through NSS, so LDAP and sssd
+    /// accounts work as well as local ones.
+    #[cfg(unix)]
+    fn resolve_user_id(name: &str) -> Option<u32> {
+        if let Ok(id) = name.parse() {
+            return Some(id);
+        }
+        nix::unistd::User::from_name(name).ok().flatten().map(|user| user.uid.as_raw())
+    }
+    #[cfg(unix)]
+    fn resolve_group_id(name: &str) -> Option<u32> {
+        if let Ok(id) = name.parse() {
+            return Some(id);
+        }
+        nix::unistd::Group::from_name(name).ok().flatten().map(|group| group.gid.as_raw())
+    }
+    #[cfg(not(unix))]
+    fn resolve_user_id(name: &str) -> Option<u32> {
+        name.parse().ok()
+    }
+    #[cfg(not(unix))]
+    fn resolve_group_id(name: &str) -> Option<u32> {
+        name.parse().ok()
+    }
+    fn format_property_time(time: SystemTime) -> String {
+        chrono::DateTime::<chrono::Local>::from(time).format(TIME_FORMAT).to_string()
+    }
+    fn parse_property_time(input: &str) -> Result<SystemTime, String> {
+        let naive = chrono::NaiveDateTime::parse_from_str(input.trim(), TIME_FORMAT)
+            .map_err(|_| format!("Invalid time '{}', expected YYYY-MM-DD HH:MM:SS", input))?;
+        chrono::Local
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(SystemTime::from)
+            .ok_or_else(|| format!("Time '{}' does not exist in the local timezone", input))
+    }
+    fn open_for_times(path: &std::path::Path) -> std::io::Result<std::fs::File> {
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::fs::OpenOptionsExt;
+            // Directories can only be opened with backup semantics.
+            const FILE_FLAG_BACKUP_SEMANTICS: u32 = 0x0200_0000;
+            std::fs::OpenOptions::new()
+                .write(true)
+                .custom_flags(FILE_FLAG_BACKUP_SEMANTICS)
+                .open(path)
+        }
+        #[cfg(not(target_os = "windows"))]
+        {
+            std::fs::File::open(path)
+        }
+    }
+    fn set_hidden(path: &std::path::Path, hidden: bool) -> std::io::Result<()> {
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::ffi::OsStrExt;
+            use std::os::windows::fs::MetadataExt;
+            use windows::core::PCWSTR;
+            use windows::Win32::Storage::FileSystem::{
+                SetFileAttributesW, FILE_ATTRIBUTE_HIDDEN, FILE_FLAGS_AND_ATTRIBUTES,
+            };
+            let attributes = std::fs::metadata(path)?.file_attributes();
+            let attributes = if hidden {
+                attributes | FILE_ATTRIBUTE_HIDDEN.0
+            } else {
+                attributes & !FILE_ATTRIBUTE_HIDDEN.0
+            };
+            let mut wide_path: Vec<u16> = path.as_os_str().encode_wide().collect();
+            wide_path.push(0);
+            unsafe {
+                SetFileAttributesW(
+                    PCWSTR::from_raw(wide_path.as_ptr()),
+                    FILE_FLAGS_AND_ATTRIBUTES(attributes),
+                )
+            }
+            .map_err(|e| std::io::Error::other(e.to_string()))
+        }
+        #[cfg(not(target_os = "windows"))]
+        {
+            let _ = (path, hidden);
+            Err(
+                std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "hidden attribute is derived from the file name on this platform",
+                ),
+            )
+        }
+    }
+    fn retarget_symlink(link: &str, target: &str) -> std::io::Result<()> {
+        #[cfg(unix)]
+        {
+            // Build the new link beside the old one, then swap it in atomically.
+            let temp = format!("{}.winshell-link", link);
+            std::os::unix::fs::symlink(target, &temp)?;
+            std::fs::rename(&temp, link).inspect_err(|_| {
+                let _ = std::fs::remove_file(&temp);
+            })
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = (link, target);
+            Err(
+                std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "editing symlink targets is only supported on Unix",
+                ),
+            )
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use crate::ws::fuzzy_score;
+use crate::ws::is_remote_path;
+use crate::ws::FilepaneBookmark;
+use crate::ws::QuickJumpSource;
+// # ProcessManagerApp - show_quick_jump_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Panel navigation is detected by comparing each panel path with the last
+// path its history recorded, so every way of changing directory (clicks,
+// the path field, keyboard, search results) feeds back/forward and MRU.
+
+use std::path::Path;
+use super::processmanagerapp_type::ProcessManagerApp;
+
+const MAX_HISTORY: usize = 50;
+const MAX_RECENT_DIRS: usize = 30;
+const MAX_JUMP_RESULTS: usize = 50;
+
+impl ProcessManagerApp {
+    /// Records panel path changes into the per-panel history and the MRU
+    /// list. Paths being typed into a path field are recorded once the field
+    /// loses focus, so partial paths do not pollute the history.
+    pub(crate) fn track_filepane_navigation(&mut self, ctx: &egui::Context) {
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+        let mut visited = Vec::new();
+        for tab in &mut self.filepane_tabs {
+            for panel in 0..2 {
+                let path = if panel == 0 { tab.left_path.clone() } else { tab.right_path.clone() };
+                let history = tab.history(panel);
+                if history.current == path || !(is_remote_path(&path) || Path::new(&path).is_dir()) {
+                    continue;
+                }
+                let previous = std::mem::replace(&mut history.current, path.clone());
+                if !previous.is_empty() {
+                    history.back.push(previous);
+                    if history.back.len() > MAX_HISTORY {
+                        history.back.remove(0);
+                    }
+                }
+                history.forward.clear();
+                visited.push(path);
+            }
+        }
+        for path in visited {
+            self.record_recent_directory(&path);
+        }
+    }
+    fn record_recent_directory(&mut self, path: &str) {
+        self.filepane_recent_dirs.retain(|recent| recent != path);
+        self.filepane_recent_dirs.insert(0, path.to_string());
+        self.filepane_recent_dirs.truncate(MAX_RECENT_DIRS);
+        self.filepane_recent_dirs_changed = true;
+    }
+    pub(crate) fn filepane_go_back(&mut self, panel: usize) {
+        self.step_filepane_history(panel, true);
+    }
+    pub(crate) fn filepane_go_forward(&mut self, panel: usize) {
+        self.step_filepane_history(panel, false);
+    }
+    fn step_filepane_history(&mut self, panel: usize, back: bool) {
+        let tab = &mut self.filepane_tabs[self.filepane_active_tab];
+        let history = tab.history(panel);
+        let target = if back { history.back.pop() } else { history.forward.pop() };
+        let Some(target) = target else {
+            return;
+        };
+        let previous = std::mem::replace(&mut history.current, target.clone());
+        if back {
+            history.forward.push(previous.clone());
+        } else {
+            history.back.push(previous.clone());
+        }
+        // Going back out of a directory puts the cursor on it again.
+        let focus = Path::new(&previous)
+            .parent()
+            .filter(|parent| *parent == Path::new(&target))
+            .and_then(|_| Path::new(&previous).file_name())
+            .map(|name| name.to_string_lossy().to_string());
+        self.navigate_filepane_panel(panel, target.clone(), focus);
+        self.record_recent_directory(&target);
+    }
+    pub(crate) fn is_bookmarked(&self, path: &str) -> bool {
+        self.filepane_bookmarks.iter().any(|bookmark| bookmark.path == path)
+    }
+    pub(crate) fn toggle_filepane_bookmark(&mut self, path: &str) {
+        if self.is_bookmarked(path) {
+            self.filepane_bookmarks.retain(|bookmark| bookmark.path != path);
+            self.add_log(format!("⭐ Removed bookmark {}", path));
+        } else {
+            let name = Path::new(path)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.to_string());
+            self.filepane_bookmarks
+                .push(FilepaneBookmark {
+                    name,
+                    path: path.to_string(),
+                });
+            self.add_log(format!("⭐ Bookmarked {}", path));
+        }
+        self.save_config();
+    }
+    /// Toolbar menus listing bookmarks and recently visited directories; a
+    /// pick opens in the keyboard-active panel.
+    pub(crate) fn show_filepane_jump_menus(&mut self, ui: &mut egui::Ui) {
+        let panel = self.filepane_keyboard.active_panel;
+        let mut target = None;
+        let mut remove = None;
+        ui.menu_button(
+            "⭐ Bookmarks",
+            |ui| {
+                if self.filepane_bookmarks.is_empty() {
+                    ui.label("No bookmarks yet (Ctrl+D)");
+                }
+                for (index, bookmark) in self.filepane_bookmarks.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        if ui
+                            .button(&bookmark.name)
+                            .on_hover_text(&bookmark.path)
+                            .clicked()
+                        {
+                            target = Some(bookmark.path.clone());
+                            ui.close_menu();
+                        }
+                        if ui.small_button("✖").on_hover_text("Remove bookmark").clicked() {
+                            remove = Some(index);
+                        }
+                    });
+                }
+            },
+        );
+        ui.menu_button(
+            "🕘 Recent",
+            |ui| {
+                if self.filepane_recent_dirs.is_empty() {
+                    ui.label("No recent directories");
+                }
+                egui::ScrollArea::vertical()
+                    .max_height(400.0)
+                    .show(
+                        ui,
+                        |ui| {
+                            for path in &self.filepane_recent_dirs {
+                                if ui.button(path).clicked() {
+                                    target = Some(path.clone());
+                                    ui.close_menu();
+                                }
+                            }
+                        },
+                    );
+            },
+        );
+        if ui.button("🧭 Jump").on_hover_text("Fuzzy jump to a directory").clicked() {
+            self.open_quick_jump();
+        }
+        if let Some(index) = remove {
+            let bookmark = self.filepane_bookmarks.remove(index);
+            self.add_log(format!("⭐ Removed bookmark {}", bookmark.path));
+            self.save_config();
+        }
+        if let Some(path) = target {
+            self.navigate_filepane_panel(panel, path, None);
+        }
+    }
+    pub fn open_quick_jump(&mut self) {
+        self.quick_jump.is_open = true;
+        self.quick_jump.query.clear();
+        self.quick_jump.selected = 0;
+    }
+    /// Candidates from bookmarks, the active tab's panel histories and the
+    /// MRU list, best fuzzy match first; a path appears once, under its most
+    /// specific source.
+    fn quick_jump_candidates(&self) -> Vec<(QuickJumpSource, String, String)> {
+        let mut candidates: Vec<(QuickJumpSource, String, String)> = Vec::new();
+        let mut push = |source: QuickJumpSource, label: String, path: &str| {
+            if !candidates.iter().any(|(_, _, existing)| existing == path) {
+                candidates.push((source, label, path.to_string()));
+            }
+        };
+        for bookmark in &self.filepane_bookmarks {
+            push(QuickJumpSource::Bookmark, bookmark.name.clone(), &bookmark.path);
+        }
+        if let Some(tab) = self.filepane_tabs.get(self.filepane_active_tab) {
+            for history in [&tab.left_history, &tab.right_history] {
+                for path in history.back.iter().rev().chain(history.forward.iter().rev()) {
+                    push(QuickJumpSource::History, String::new(), path);
+                }
+            }
+        }
+        for path in &self.filepane_recent_dirs {
+            push(QuickJumpSource::Recent, String::new(), path);
+        }
+        let query = self.quick_jump.query.trim();
+        let mut scored: Vec<(i32, usize, (QuickJumpSource, String, String))> = candidates
+            .into_iter()
+            .enumerate()
+            .filter_map(|(order, candidate)| {
+                let path_score = fuzzy_score(query, &candidate.2);
+                let label_score = if candidate.1.is_empty() {
+                    None
+                } else {
+                    fuzzy_score(query, &candidate.1)
+                };
+                let score = path_score.max(label_score)?;
+                Some((score, order, candidate))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        scored
+            .into_iter()
+            .take(MAX_JUMP_RESULTS)
+            .map(|(_, _, candidate)| candidate)
+            .collect()
+    }
+    pub(crate) fn show_quick_jump(&mut self, ctx: &egui::Context) {
+        if !self.quick_jump.is_open {
+            return;
+        }
+        let candidates = self.quick_jump_candidates();
+        let mut open = true;
+        let mut chosen = None;
+        egui::Window::new("🧭 Quick Jump")
+            .open(&mut open)
+            .collapsible(false)
+            .default_width(520.0)
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 80.0])
+            .show(
+                ctx,
+                |ui| {
+                    let (up, down, enter) = ui
+                        .input_mut(|i| {
+                            (
+                                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                                i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+                            )
+                        });
+                    let response = ui
+                        .add(
+                            egui::TextEdit::singleline(&mut self.quick_jump.query)
+                                .hint_text("type part of a directory name...")
+                                .desired_width(f32::INFINITY),
+                        );
+                    response.request_focus();
+                    if response.changed() {
+                        self.quick_jump.selected = 0;
+                    }
+                    if candidates.is_empty() {
+                        ui.label("No matching directories");
+                        return;
+                    }
+                    let last = candidates.len() - 1;
+                    if up {
+                        self.quick_jump.selected = self.quick_jump.selected.saturating_sub(1);
+                    }
+                    if down {
+                        self.quick_jump.selected = (self.quick_jump.selected + 1).min(last);
+                    }
+                    self.quick_jump.selected = self.quick_jump.selected.min(last);
+                    ui.separator();
+                    egui::ScrollArea::vertical()
+                        .max_height(360.0)
+                        .show(
+                            ui,
+                            |ui| {
+                                for (index, (source, label, path)) in candidates
+                                    .iter()
+                                    .enumerate()
+                                {
+                                    let text = if label.is_empty() {
+                                        format!("{} {}", source.icon(), path)
+                                    } else {
+                                        format!("{} {} — {}", source.icon(), label, path)
+                                    };
+                                    let selected = index == self.quick_jump.selected;
+                                    let row = ui.selectable_label(selected, text);
+                                    if selected && (up || down) {
+                                        row.scroll_to_me(None);
+                                    }
+                                    if row.clicked() {
+                                        chosen = Some(path.clone());
+                                    }
+                                }
+                            },
+                        );
+                    if enter {
+                        chosen = candidates
+                            .get(self.quick_jump.selected)
+                            .map(|(_, _, path)| path.clone());
+                    }
+                },
+            );
+        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            open = false;
+        }
+        if let Some(path) = chosen {
+            let panel = self.filepane_keyboard.active_panel;
+            self.navigate_filepane_panel(panel, path, None);
+            open = false;
+        }
+        self.quick_jump.is_open = open;
+    }
+}
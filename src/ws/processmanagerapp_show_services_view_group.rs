@@ -0,0 +1,595 @@
+use crate::ws::LogLevel;
+use crate::ws::ServiceAction;
+use crate::ws::ServiceInfo;
+use crate::ws::ServiceStateFilter;
+use crate::ws::ViewMode;
+// # ProcessManagerApp - show_services_view_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Listing and actions both run on background threads: systemctl may wait
+// for a polkit prompt and the Service Control Manager may take seconds to
+// stop a service, neither of which should stall the UI.
+
+use super::processmanagerapp_type::ProcessManagerApp;
+
+/// Runs systemctl and returns its stdout, or stderr as the error.
+#[cfg(target_os = "linux")]
+pub(crate) fn systemctl(user: bool, args: &[&str]) -> Result<String, String> {
+    let mut command = std::process::Command::new("systemctl");
+    if user {
+        command.arg("--user");
+    }
+    let output = command
+        .args(args)
+        .arg("--no-pager")
+        .output()
+        .map_err(|e| format!("cannot run systemctl: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(
+            if stderr.is_empty() { format!("systemctl exited with {}", output.status) } else { stderr },
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Service units from `list-units`, completed with main PID, memory and
+/// enablement from one batched `systemctl show`.
+#[cfg(target_os = "linux")]
+fn list_services(user: bool) -> Result<Vec<ServiceInfo>, String> {
+    #[derive(serde::Deserialize)]
+    struct Unit {
+        unit: String,
+        active: String,
+        sub: String,
+        #[serde(default)]
+        description: String,
+    }
+    let listing = systemctl(user, &["list-units", "--type=service", "--all", "--output=json"])?;
+    let units: Vec<Unit> = serde_json::from_str(&listing)
+        .map_err(|e| format!("unexpected systemctl output: {}", e))?;
+    let mut services: Vec<ServiceInfo> = units
+        .into_iter()
+        .map(|unit| ServiceInfo {
+            name: unit.unit,
+            description: unit.description,
+            state: unit.active,
+            sub_state: unit.sub,
+            main_pid: None,
+            memory: None,
+            start_mode: String::new(),
+        })
+        .collect();
+    if services.is_empty() {
+        return Ok(services);
+    }
+    let mut args = vec!["show", "--property=Id,MainPID,MemoryCurrent,UnitFileState", "--"];
+    args.extend(services.iter().map(|service| service.name.as_str()));
+    let details = systemctl(user, &args)?;
+    let mut by_name = std::collections::HashMap::new();
+    for block in details.split("\n\n") {
+        let properties: std::collections::HashMap<&str, &str> = block
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .collect();
+        if let Some(id) = properties.get("Id") {
+            by_name.insert(id.to_string(), properties);
+        }
+    }
+    for service in &mut services {
+        let Some(properties) = by_name.get(&service.name) else {
+            continue;
+        };
+        service.main_pid = properties
+            .get("MainPID")
+            .and_then(|pid| pid.parse().ok())
+            .filter(|pid| *pid != 0);
+        // systemd reports "[not set]" or u64::MAX when memory is not tracked.
+        service.memory = properties
+            .get("MemoryCurrent")
+            .and_then(|memory| memory.parse().ok())
+            .filter(|memory| *memory != u64::MAX);
+        service.start_mode = properties.get("UnitFileState").unwrap_or(&"").to_string();
+    }
+    Ok(services)
+}
+
+#[cfg(target_os = "linux")]
+fn run_service_action(name: &str, action: ServiceAction, user: bool) -> Result<(), String> {
+    systemctl(user, &[action.name(), "--", name]).map(|_| ())
+}
+
+#[cfg(windows)]
+fn wide(text: &str) -> Vec<u16> {
+    text.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+#[cfg(windows)]
+fn list_services(_user: bool) -> Result<Vec<ServiceInfo>, String> {
+    use windows::core::PCWSTR;
+    use windows::Win32::Foundation::ERROR_MORE_DATA;
+    use windows::Win32::System::Services::{
+        CloseServiceHandle, EnumServicesStatusExW, OpenSCManagerW, ENUM_SERVICE_STATUS_PROCESSW,
+        SC_ENUM_PROCESS_INFO, SC_MANAGER_CONNECT, SC_MANAGER_ENUMERATE_SERVICE,
+        SERVICE_STATE_ALL, SERVICE_WIN32,
+    };
+    unsafe {
+        let scm = OpenSCManagerW(
+            PCWSTR::null(),
+            PCWSTR::null(),
+            SC_MANAGER_CONNECT | SC_MANAGER_ENUMERATE_SERVICE,
+        )
+            .map_err(|e| e.to_string())?;
+        // The SCM returns as many entries as fit and ERROR_MORE_DATA with a
+        // resume handle when there are more; keep asking until it is done.
+        let mut buffer = vec![0u8; 64 * 1024];
+        let mut resume = 0u32;
+        let mut services = Vec::new();
+        loop {
+            let mut needed = 0u32;
+            let mut count = 0u32;
+            let listed = EnumServicesStatusExW(
+                scm,
+                SC_ENUM_PROCESS_INFO,
+                SERVICE_WIN32,
+                SERVICE_STATE_ALL,
+                Some(&mut buffer),
+                &mut needed,
+                &mut count,
+                Some(&mut resume),
+                PCWSTR::null(),
+            );
+            let more = match listed {
+                Ok(()) => false,
+                Err(e) if e.code() == ERROR_MORE_DATA.to_hresult() => true,
+                Err(e) => {
+                    let _ = CloseServiceHandle(scm);
+                    return Err(e.to_string());
+                }
+            };
+            if more && count == 0 {
+                // Not even one entry fits; grow to the size the SCM asked for.
+                buffer.resize((needed as usize).max(buffer.len() * 2), 0);
+                continue;
+            }
+            let entries = std::slice::from_raw_parts(
+                buffer.as_ptr() as *const ENUM_SERVICE_STATUS_PROCESSW,
+                count as usize,
+            );
+            services.extend(entries.iter().map(|entry| service_info(scm, entry)));
+            if !more {
+                break;
+            }
+        }
+        let _ = CloseServiceHandle(scm);
+        Ok(services)
+    }
+}
+
+/// Converts one enumerated service, reading its start type from its config.
+#[cfg(windows)]
+fn service_info(
+    scm: windows::Win32::System::Services::SC_HANDLE,
+    entry: &windows::Win32::System::Services::ENUM_SERVICE_STATUS_PROCESSW,
+) -> ServiceInfo {
+    use windows::Win32::System::Services::{
+        CloseServiceHandle, OpenServiceW, QueryServiceConfigW, QUERY_SERVICE_CONFIGW,
+        SERVICE_AUTO_START, SERVICE_BOOT_START, SERVICE_CONTINUE_PENDING, SERVICE_DEMAND_START,
+        SERVICE_DISABLED, SERVICE_PAUSED, SERVICE_PAUSE_PENDING, SERVICE_QUERY_CONFIG,
+        SERVICE_RUNNING, SERVICE_START_PENDING, SERVICE_STOPPED, SERVICE_STOP_PENDING,
+        SERVICE_SYSTEM_START,
+    };
+    unsafe {
+        let status = entry.ServiceStatusProcess;
+        let state = match status.dwCurrentState {
+            SERVICE_RUNNING => "running",
+            SERVICE_STOPPED => "stopped",
+            SERVICE_START_PENDING => "starting",
+            SERVICE_STOP_PENDING => "stopping",
+            SERVICE_PAUSED => "paused",
+            SERVICE_PAUSE_PENDING => "pausing",
+            SERVICE_CONTINUE_PENDING => "resuming",
+            _ => "unknown",
+        };
+        let name = entry.lpServiceName.to_string().unwrap_or_default();
+        let mut start_mode = String::new();
+        if let Ok(service) = OpenServiceW(scm, entry.lpServiceName, SERVICE_QUERY_CONFIG) {
+            let mut config_needed = 0u32;
+            let _ = QueryServiceConfigW(service, None, 0, &mut config_needed);
+            let mut config = vec![0u8; config_needed as usize];
+            let queried = QueryServiceConfigW(
+                service,
+                Some(config.as_mut_ptr() as *mut QUERY_SERVICE_CONFIGW),
+                config_needed,
+                &mut config_needed,
+            );
+            if queried.is_ok() {
+                let config = &*(config.as_ptr() as *const QUERY_SERVICE_CONFIGW);
+                start_mode = match config.dwStartType {
+                    SERVICE_AUTO_START => "automatic",
+                    SERVICE_DEMAND_START => "manual",
+                    SERVICE_DISABLED => "disabled",
+                    SERVICE_BOOT_START => "boot",
+                    SERVICE_SYSTEM_START => "system",
+                    _ => "",
+                }
+                    .to_string();
+            }
+            let _ = CloseServiceHandle(service);
+        }
+        ServiceInfo {
+            name,
+            description: entry.lpDisplayName.to_string().unwrap_or_default(),
+            state: state.to_string(),
+            sub_state: String::new(),
+            main_pid: Some(status.dwProcessId).filter(|pid| *pid != 0),
+            memory: None,
+            start_mode,
+        }
+    }
+}
+
+/// Sends the request through the Service Control Manager. Restart waits up
+/// to 30 seconds for the service to stop before starting it again.
+#[cfg(windows)]
+fn run_service_action(name: &str, action: ServiceAction, _user: bool) -> Result<(), String> {
+    use windows::core::PCWSTR;
+    use windows::Win32::System::Services::{
+        ChangeServiceConfigW, CloseServiceHandle, ControlService, OpenSCManagerW, OpenServiceW,
+        QueryServiceStatus, StartServiceW, ENUM_SERVICE_TYPE, SC_MANAGER_CONNECT,
+        SERVICE_AUTO_START, SERVICE_CHANGE_CONFIG, SERVICE_CONTROL_STOP, SERVICE_DISABLED,
+        SERVICE_ERROR, SERVICE_NO_CHANGE, SERVICE_QUERY_STATUS, SERVICE_START, SERVICE_STATUS,
+        SERVICE_STOP, SERVICE_STOPPED,
+    };
+    let name = wide(name);
+    unsafe {
+        let scm = OpenSCManagerW(PCWSTR::null(), PCWSTR::null(), SC_MANAGER_CONNECT)
+            .map_err(|e| e.to_string())?;
+        let service = match OpenServiceW(
+            scm,
+            PCWSTR::from_raw(name.as_ptr()),
+            SERVICE_START | SERVICE_STOP | SERVICE_QUERY_STATUS | SERVICE_CHANGE_CONFIG,
+        ) {
+            Ok(service) => service,
+            Err(e) => {
+                let _ = CloseServiceHandle(scm);
+                return Err(e.to_string());
+            }
+        };
+        let stop = || {
+            let mut status = SERVICE_STATUS::default();
+            ControlService(service, SERVICE_CONTROL_STOP, &mut status).map_err(|e| e.to_string())
+        };
+        let start_type = |start_type| {
+            ChangeServiceConfigW(
+                service,
+                ENUM_SERVICE_TYPE(SERVICE_NO_CHANGE),
+                start_type,
+                SERVICE_ERROR(SERVICE_NO_CHANGE),
+                PCWSTR::null(),
+                PCWSTR::null(),
+                None,
+                PCWSTR::null(),
+                PCWSTR::null(),
+                PCWSTR::null(),
+                PCWSTR::null(),
+            )
+                .map_err(|e| e.to_string())
+        };
+        let result = match action {
+            ServiceAction::Start => StartServiceW(service, None).map_err(|e| e.to_string()),
+            ServiceAction::Stop => stop(),
+            ServiceAction::Restart => {
+                stop()
+                    .and_then(|_| {
+                        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(30);
+                        loop {
+                            let mut status = SERVICE_STATUS::default();
+                            QueryServiceStatus(service, &mut status).map_err(|e| e.to_string())?;
+                            if status.dwCurrentState == SERVICE_STOPPED {
+                                break;
+                            }
+                            if std::time::Instant::now() >= deadline {
+                                return Err("service did not stop within 30s".to_string());
+                            }
+                            std::thread::sleep(std::time::Duration::from_millis(250));
+                        }
+                        StartServiceW(service, None).map_err(|e| e.to_string())
+                    })
+            }
+            ServiceAction::Enable => start_type(SERVICE_AUTO_START),
+            ServiceAction::Disable => start_type(SERVICE_DISABLED),
+        };
+        let _ = CloseServiceHandle(service);
+        let _ = CloseServiceHandle(scm);
+        result
+    }
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn list_services(_user: bool) -> Result<Vec<ServiceInfo>, String> {
+    Err("service management is not supported on this platform".to_string())
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn run_service_action(_name: &str, _action: ServiceAction, _user: bool) -> Result<(), String> {
+    Err("service management is not supported on this platform".to_string())
+}
+
+impl ProcessManagerApp {
+    pub(crate) fn refresh_services(&mut self) {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let user = self.services.user_units;
+        std::thread::spawn(move || {
+            let _ = sender.send(list_services(user));
+        });
+        self.services.loader = Some(receiver);
+    }
+    fn run_service_action(&mut self, name: String, action: ServiceAction) {
+        if !self.services.busy.insert(name.clone()) {
+            return;
+        }
+        self.add_log(format!("{} Service {}: {}", action.icon(), action.name(), name));
+        let sender = self.services.action_sender.clone();
+        let user = self.services.user_units;
+        std::thread::spawn(move || {
+            let result = run_service_action(&name, action, user);
+            let _ = sender.send((name, action, result));
+        });
+    }
+    fn poll_services(&mut self, ctx: &egui::Context) {
+        if let Some(loader) = &self.services.loader {
+            match loader.try_recv() {
+                Ok(result) => {
+                    self.services.loader = None;
+                    self.services.loaded = true;
+                    match result {
+                        Ok(services) => {
+                            self.services.services = services;
+                            self.services.error_msg = None;
+                        }
+                        Err(e) => self.services.error_msg = Some(e),
+                    }
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => {}
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    self.services.loader = None;
+                    self.services.loaded = true;
+                }
+            }
+        }
+        let results: Vec<_> = self.services.action_results.try_iter().collect();
+        for (name, action, result) in &results {
+            self.services.busy.remove(name);
+            match result {
+                Ok(()) => self.add_log(format!("✓ Service {}: {} succeeded", name, action.name())),
+                Err(e) => self.add_log_at(LogLevel::Error, format!("❌ Service {} {} failed: {}", name, action.name(), e)),
+            }
+        }
+        if !results.is_empty() {
+            self.refresh_services();
+        }
+        if self.services.loader.is_some() || !self.services.busy.is_empty() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(200));
+        }
+    }
+    pub(crate) fn show_services_view(&mut self, ui: &mut egui::Ui) {
+        if !self.services.loaded && self.services.loader.is_none() {
+            self.refresh_services();
+        }
+        self.poll_services(ui.ctx());
+        ui.horizontal(|ui| {
+            ui.heading("🧩 Services");
+            ui.separator();
+            if self.services.loader.is_some() {
+                ui.spinner();
+            } else if ui.button("🔄 Refresh").clicked() {
+                self.refresh_services();
+            }
+            #[cfg(target_os = "linux")]
+            if ui
+                .checkbox(&mut self.services.user_units, "User units")
+                .on_hover_text("systemctl --user")
+                .changed()
+            {
+                self.services.services.clear();
+                self.refresh_services();
+            }
+            ui.label("State:");
+            egui::ComboBox::from_id_salt("service_state_filter")
+                .selected_text(format!("{:?}", self.services.state_filter))
+                .show_ui(
+                    ui,
+                    |ui| {
+                        for filter in [
+                            ServiceStateFilter::All,
+                            ServiceStateFilter::Running,
+                            ServiceStateFilter::Stopped,
+                            ServiceStateFilter::Failed,
+                        ] {
+                            ui.selectable_value(
+                                &mut self.services.state_filter,
+                                filter,
+                                format!("{:?}", filter),
+                            );
+                        }
+                    },
+                );
+        });
+        if let Some(error) = &self.services.error_msg {
+            ui.colored_label(egui::Color32::RED, format!("❌ {}", error));
+        }
+        let filter_lower = self.search_filter.to_lowercase();
+        let state_filter = self.services.state_filter;
+        let services: Vec<ServiceInfo> = self
+            .services
+            .services
+            .iter()
+            .filter(|service| {
+                match state_filter {
+                    ServiceStateFilter::All => true,
+                    ServiceStateFilter::Running => service.is_running(),
+                    ServiceStateFilter::Stopped => !service.is_running() && !service.is_failed(),
+                    ServiceStateFilter::Failed => service.is_failed(),
+                }
+            })
+            .filter(|service| {
+                filter_lower.is_empty()
+                    || service.name.to_lowercase().contains(&filter_lower)
+                    || service.description.to_lowercase().contains(&filter_lower)
+            })
+            .cloned()
+            .collect();
+        ui.label(format!("{} of {} services", services.len(), self.services.services.len()));
+        ui.separator();
+        let mut action = None;
+        let mut show_process = None;
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(
+                ui,
+                |ui| {
+                    egui::Grid::new("services_grid")
+                        .striped(true)
+                        .num_columns(7)
+                        .show(
+                            ui,
+                            |ui| {
+                                for header in [
+                                    "Name",
+                                    "Description",
+                                    "State",
+                                    "Main PID",
+                                    "Memory",
+                                    "Startup",
+                                    "Actions",
+                                ] {
+                                    ui.strong(header);
+                                }
+                                ui.end_row();
+                                for service in &services {
+                                    ui.label(&service.name);
+                                    ui.label(&service.description);
+                                    let state = if service.sub_state.is_empty() {
+                                        service.state.clone()
+                                    } else {
+                                        format!("{} ({})", service.state, service.sub_state)
+                                    };
+                                    let color = if service.is_failed() {
+                                        egui::Color32::RED
+                                    } else if service.is_running() {
+                                        egui::Color32::GREEN
+                                    } else {
+                                        egui::Color32::GRAY
+                                    };
+                                    ui.colored_label(color, state);
+                                    let process = service
+                                        .main_pid
+                                        .and_then(|pid| self.processes.iter().find(|p| p.pid == pid));
+                                    match (service.main_pid, process) {
+                                        (Some(pid), Some(process)) => {
+                                            if ui
+                                                .link(format!("{} ({})", pid, process.name))
+                                                .on_hover_text("Show in Processes")
+                                                .clicked()
+                                            {
+                                                show_process = Some(pid);
+                                            }
+                                        }
+                                        (Some(pid), None) => {
+                                            ui.label(pid.to_string());
+                                        }
+                                        (None, _) => {
+                                            ui.label("—");
+                                        }
+                                    }
+                                    let memory = service.memory.or(process.map(|p| p.memory));
+                                    ui.label(memory.map_or("—".to_string(), Self::format_memory));
+                                    ui.label(&service.start_mode);
+                                    ui.horizontal(|ui| {
+                                        if self.services.busy.contains(&service.name) {
+                                            ui.spinner();
+                                            return;
+                                        }
+                                        for choice in ServiceAction::ALL {
+                                            let enabled = match choice {
+                                                ServiceAction::Start => !service.is_running(),
+                                                ServiceAction::Stop => service.is_running(),
+                                                _ => true,
+                                            };
+                                            if ui
+                                                .add_enabled(enabled, egui::Button::new(choice.icon()).small())
+                                                .on_hover_text(choice.name())
+                                                .clicked()
+                                            {
+                                                action = Some((service.name.clone(), choice));
+                                            }
+                                        }
+                                    });
+                                    ui.end_row();
+                                }
+                            },
+                        );
+                },
+            );
+        if let Some((name, choice)) = action {
+            match choice {
+                ServiceAction::Stop | ServiceAction::Disable => {
+                    self.services.pending_confirmation = Some((name, choice));
+                }
+                _ => self.run_service_action(name, choice),
+            }
+        }
+        self.show_service_confirmation(ui.ctx());
+        if let Some(pid) = show_process {
+            self.selected_pid = Some(pid);
+            self.search_filter.clear();
+            self.view_mode = ViewMode::Processes;
+        }
+    }
+    /// Asks before stopping or disabling a service, since either can take
+    /// down whatever depends on it.
+    fn show_service_confirmation(&mut self, ctx: &egui::Context) {
+        let Some((name, choice)) = self.services.pending_confirmation.clone() else {
+            return;
+        };
+        let mut open = true;
+        let mut confirmed = false;
+        let mut cancelled = false;
+        egui::Window::new("⚠️ Confirm Service Action")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .open(&mut open)
+            .show(
+                ctx,
+                |ui| {
+                    ui.set_min_width(300.0);
+                    ui.vertical_centered(|ui| {
+                        ui.heading(format!("Are you sure you want to {} {}?", choice.name(), name));
+                        ui.add_space(10.0);
+                        ui.label(match choice {
+                            ServiceAction::Disable => "It will no longer start automatically.",
+                            _ => "Anything that depends on it may stop working.",
+                        });
+                        ui.add_space(20.0);
+                        ui.horizontal(|ui| {
+                            if ui.button("❌ Cancel").clicked() {
+                                cancelled = true;
+                            }
+                            if ui.button(format!("{} Yes, {}", choice.icon(), choice.name())).clicked() {
+                                confirmed = true;
+                            }
+                        });
+                    });
+                },
+            );
+        if confirmed {
+            self.services.pending_confirmation = None;
+            self.run_service_action(name, choice);
+        } else if cancelled || !open {
+            self.services.pending_confirmation = None;
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::ws::LogLevel;
+use crate::ws::FileSystemBackend;
+use crate::ws::LocalBackend;
+use crate::ws::SftpBackend;
+use crate::ws::SftpTarget;
+// # ProcessManagerApp - show_sftp_connect_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Logging in happens on a worker thread; the finished session is handed back
+// through a channel and kept until disconnected, so panel paths on that
+// server resolve to it.
+
+use std::sync::mpsc::TryRecvError;
+use std::sync::Arc;
+use super::processmanagerapp_type::ProcessManagerApp;
+
+impl ProcessManagerApp {
+    /// The backend serving a panel path; remote paths need an open session.
+    pub(crate) fn filesystem(&self, path: &str) -> std::io::Result<Arc<dyn FileSystemBackend>> {
+        let Some((target, _)) = SftpTarget::parse(path) else {
+            return Ok(Arc::new(LocalBackend));
+        };
+        match self.sftp_sessions.get(&target.key()) {
+            Some(session) => Ok(session.clone()),
+            None => {
+                Err(
+                    std::io::Error::new(
+                        std::io::ErrorKind::NotConnected,
+                        format!("not connected to {}", target.key()),
+                    ),
+                )
+            }
+        }
+    }
+    pub(crate) fn is_sftp_connected(&self, path: &str) -> bool {
+        SftpTarget::parse(path)
+            .is_some_and(|(target, _)| self.sftp_sessions.contains_key(&target.key()))
+    }
+    /// Opens the connect dialog for `panel`, prefilled from the panel path
+    /// when it already points at a server.
+    pub(crate) fn open_sftp_connect(&mut self, panel: usize) {
+        let path = self.filepane_panel_path(panel);
+        let state = &mut self.sftp_connect;
+        state.is_open = true;
+        state.panel = panel;
+        state.error_msg = None;
+        if let Some((target, remote)) = SftpTarget::parse(&path) {
+            state.host = target.host;
+            state.port = target.port.to_string();
+            state.user = target.user;
+            state.remote_path = remote;
+        }
+    }
+    fn start_sftp_connect(&mut self) {
+        let port = match self.sftp_connect.port.trim().parse::<u16>() {
+            Ok(port) => port,
+            Err(_) => {
+                self.sftp_connect.error_msg = Some("Port must be a number".to_string());
+                return;
+            }
+        };
+        let target = SftpTarget {
+            user: self.sftp_connect.user.trim().to_string(),
+            host: self.sftp_connect.host.trim().to_string(),
+            port,
+        };
+        if target.user.is_empty() || target.host.is_empty() {
+            self.sftp_connect.error_msg = Some("Host and user are required".to_string());
+            return;
+        }
+        let credentials = self.sftp_connect.credentials.clone();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(SftpBackend::connect(target, &credentials));
+        });
+        self.sftp_connect.connecting = Some(receiver);
+        self.sftp_connect.error_msg = None;
+    }
+    fn poll_sftp_connect(&mut self, ctx: &egui::Context) {
+        let Some(receiver) = &self.sftp_connect.connecting else {
+            return;
+        };
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => {
+                ctx.request_repaint_after(std::time::Duration::from_millis(100));
+                return;
+            }
+            Err(TryRecvError::Disconnected) => Err("Connection attempt aborted".to_string()),
+        };
+        self.sftp_connect.connecting = None;
+        match result {
+            Ok(backend) => {
+                let remote = match self.sftp_connect.remote_path.trim() {
+                    "" => backend.home.clone(),
+                    path => path.to_string(),
+                };
+                let path = backend.target.uri(&remote);
+                let key = backend.target.key();
+                self.add_log(format!("🌐 Connected to {}", key));
+                self.sftp_sessions.insert(key, Arc::new(backend));
+                self.sftp_connect.credentials.password.clear();
+                self.sftp_connect.is_open = false;
+                self.invalidate_directory_listing(&path);
+                let panel = self.sftp_connect.panel;
+                self.navigate_filepane_panel(panel, path, None);
+            }
+            Err(e) => {
+                self.add_log_at(LogLevel::Error, format!("❌ SFTP connection failed: {}", e));
+                self.sftp_connect.error_msg = Some(e);
+            }
+        }
+    }
+    pub(crate) fn disconnect_sftp(&mut self, key: &str) {
+        if self.sftp_sessions.remove(key).is_some() {
+            let prefix = format!("sftp://{}", key);
+            self.directory_watch
+                .listings
+                .retain(|path, _| !path.to_string_lossy().starts_with(&prefix));
+            self.add_log(format!("🔌 Disconnected from {}", key));
+        }
+    }
+    pub(crate) fn show_sftp_connect(&mut self, ctx: &egui::Context) {
+        self.poll_sftp_connect(ctx);
+        if !self.sftp_connect.is_open {
+            return;
+        }
+        let mut open = true;
+        let mut connect = false;
+        let mut disconnect = None;
+        let connecting = self.sftp_connect.connecting.is_some();
+        let panel_name = if (self.sftp_connect.panel == 0) != self.filepane_swap_columns {
+            "left"
+        } else {
+            "right"
+        };
+        egui::Window::new("🌐 SFTP Connection")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(
+                ctx,
+                |ui| {
+                    let state = &mut self.sftp_connect;
+                    egui::Grid::new("sftp_connect_grid")
+                        .num_columns(2)
+                        .show(
+                            ui,
+                            |ui| {
+                                ui.label("Host:");
+                                ui.text_edit_singleline(&mut state.host);
+                                ui.end_row();
+                                ui.label("Port:");
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut state.port)
+                                        .desired_width(60.0),
+                                );
+                                ui.end_row();
+                                ui.label("User:");
+                                ui.text_edit_singleline(&mut state.user);
+                                ui.end_row();
+                                ui.label("Password:");
+                                ui.add(
+                                        egui::TextEdit::singleline(
+                                                &mut state.credentials.password,
+                                            )
+                                            .password(true),
+                                    )
+                                    .on_hover_text(
+                                        "Key passphrase when a key file is set; leave both empty to use ssh-agent",
+                                    );
+                                ui.end_row();
+                                ui.label("Key file:");
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut state.credentials.key_path)
+                                        .hint_text("~/.ssh/id_ed25519"),
+                                );
+                                ui.end_row();
+                                ui.label("Remote path:");
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut state.remote_path)
+                                        .hint_text("home directory"),
+                                );
+                                ui.end_row();
+                            },
+                        );
+                    ui.checkbox(
+                        &mut state.credentials.accept_new_host_key,
+                        "Trust new host key (adds it to known_hosts)",
+                    );
+                    if let Some(error) = &state.error_msg {
+                        ui.colored_label(egui::Color32::RED, format!("❌ {}", error));
+                    }
+                    ui.horizontal(|ui| {
+                        if connecting {
+                            ui.spinner();
+                            ui.label("Connecting...");
+                        } else if ui
+                            .button(format!("🔌 Connect {} panel", panel_name))
+                            .clicked()
+                        {
+                            connect = true;
+                        }
+                    });
+                    if !self.sftp_sessions.is_empty() {
+                        ui.separator();
+                        ui.label("Open connections:");
+                        for key in self.sftp_sessions.keys() {
+                            ui.horizontal(|ui| {
+                                ui.label(format!("🌐 {}", key));
+                                if ui.small_button("⏏ Disconnect").clicked() {
+                                    disconnect = Some(key.clone());
+                                }
+                            });
+                        }
+                    }
+                },
+            );
+        if connect {
+            self.start_sftp_connect();
+        }
+        if let Some(key) = disconnect {
+            self.disconnect_sftp(&key);
+        }
+        self.sftp_connect.is_open = open;
+    }
+}
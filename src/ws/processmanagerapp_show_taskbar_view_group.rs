@@ -108,13 +108,28 @@ impl ProcessManagerApp {
                                         |ui| {
                                             ui.set_min_size(egui::vec2(icon_size, icon_size));
                                             ui.set_max_size(egui::vec2(icon_size, icon_size));
-                                            let icon = Self::get_process_icon(&window.process_name);
+                                            let icon = self
+                                                .process_icons
+                                                .get(&window.process_name, pid_to_exe.get(&window.pid).map(String::as_str));
                                             ui.vertical_centered(|ui| {
-                                                let icon_response = ui
-                                                    .add(
-                                                        egui::Label::new(egui::RichText::new(icon).size(24.0))
-                                                            .sense(egui::Sense::click()),
-                                                    );
+                                                let icon_response = match icon {
+                                                    Some(texture) => {
+                                                        ui.add(
+                                                            egui::Image::new(&texture)
+                                                                .fit_to_exact_size(egui::vec2(28.0, 28.0))
+                                                                .sense(egui::Sense::click()),
+                                                        )
+                                                    }
+                                                    None => {
+                                                        ui.add(
+                                                            egui::Label::new(
+                                                                    egui::RichText::new(Self::get_process_icon(&window.process_name))
+                                                                        .size(24.0),
+                                                                )
+                                                                .sense(egui::Sense::click()),
+                                                        )
+                                                    }
+                                                };
                                                 let title_display = if window.window_title.len() > 10 {
                                                     format!(
                                                         "{}...", & window.window_title.chars().take(7).collect::<
@@ -200,6 +215,7 @@ impl ProcessManagerApp {
             self.view_mode = ViewMode::New;
         }
     }
+    /// Emoji drawn while a real icon is loading or when the program has none.
     pub fn get_process_icon(process_name: &str) -> &'static str {
         let name_lower = process_name.to_lowercase();
         if name_lower.contains("chrome") {
@@ -1,5 +1,6 @@
 use crate::ws::WindowInfo;
 use crate::ws::ViewMode;
+use crate::ws::WindowAction;
 // # ProcessManagerApp - show_windows_view_group Methods
 //
 // This module contains method implementations for `ProcessManagerApp`.
@@ -11,8 +12,16 @@ use super::processmanagerapp_type::ProcessManagerApp;
 
 impl ProcessManagerApp {
     pub(crate) fn show_windows_view(&mut self, ui: &mut egui::Ui) {
+        if let Some(error) = &self.window_system_error {
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                format!("⚠️ Windows cannot be listed: {}", error),
+            );
+        }
         let filter_lower = self.search_filter.to_lowercase();
         let mut window_to_focus: Option<u64> = None;
+        let mut window_action: Option<(u64, WindowAction)> = None;
+        let mut selection_change: Option<(u64, bool)> = None;
         let mut program_to_add: Option<(String, String)> = None;
         let pid_to_exe: std::collections::HashMap<u32, String> = self
             .processes
@@ -70,6 +79,12 @@ impl ProcessManagerApp {
                                             ui.set_max_width(200.0);
                                             ui.set_min_height(120.0);
                                             ui.vertical(|ui| {
+                                                let mut selected = self
+                                                    .selected_window_ids
+                                                    .contains(&window.window_id);
+                                                if ui.checkbox(&mut selected, "Select").changed() {
+                                                    selection_change = Some((window.window_id, selected));
+                                                }
                                                 if window.is_foreground {
                                                     ui.colored_label(
                                                         egui::Color32::from_rgb(100, 200, 255),
@@ -87,11 +102,27 @@ impl ProcessManagerApp {
                                                 } else {
                                                     window.process_name.clone()
                                                 };
-                                                ui.label(format!("🖥 {}", proc_name));
+                                                ui.horizontal(|ui| {
+                                                    let icon = self
+                                                        .process_icons
+                                                        .get(
+                                                            &window.process_name,
+                                                            pid_to_exe.get(&window.pid).map(String::as_str),
+                                                        );
+                                                    Self::show_process_icon(ui, icon, &window.process_name, 16.0);
+                                                    ui.label(proc_name);
+                                                });
                                                 ui.label(format!("PID: {}", window.pid));
-                                                if ui.button("Focus Window").clicked() {
-                                                    window_to_focus = Some(window.window_id);
-                                                }
+                                                ui.horizontal(|ui| {
+                                                    if ui.button("Focus Window").clicked() {
+                                                        window_to_focus = Some(window.window_id);
+                                                    }
+                                                    Self::show_window_action_buttons(
+                                                        ui,
+                                                        window.window_id,
+                                                        &mut window_action,
+                                                    );
+                                                });
                                                 if ui.button("📋 Copy Info").clicked() {
                                                     let info = format!(
                                                         "{} - {} (PID: {})", window.window_title, window
@@ -132,6 +163,8 @@ impl ProcessManagerApp {
                     |ui| {
                         ui.horizontal(|ui| {
                             ui.style_mut().spacing.item_spacing.x = 10.0;
+                            ui.label("☑");
+                            ui.separator();
                             ui.label("Status");
                             ui.separator();
                             ui.label("PID");
@@ -146,6 +179,13 @@ impl ProcessManagerApp {
                         for window in &filtered_windows {
                             ui.horizontal(|ui| {
                                 ui.style_mut().spacing.item_spacing.x = 10.0;
+                                let mut selected = self
+                                    .selected_window_ids
+                                    .contains(&window.window_id);
+                                if ui.checkbox(&mut selected, "").changed() {
+                                    selection_change = Some((window.window_id, selected));
+                                }
+                                ui.separator();
                                 if window.is_foreground {
                                     ui.colored_label(
                                         egui::Color32::from_rgb(100, 200, 255),
@@ -157,6 +197,10 @@ impl ProcessManagerApp {
                                 ui.separator();
                                 ui.label(format!("{}", window.pid));
                                 ui.separator();
+                                let icon = self
+                                    .process_icons
+                                    .get(&window.process_name, pid_to_exe.get(&window.pid).map(String::as_str));
+                                Self::show_process_icon(ui, icon, &window.process_name, 16.0);
                                 ui.label(&window.process_name);
                                 ui.separator();
                                 ui.label(&window.window_title);
@@ -164,6 +208,11 @@ impl ProcessManagerApp {
                                 if ui.button("Focus").clicked() {
                                     window_to_focus = Some(window.window_id);
                                 }
+                                Self::show_window_action_buttons(
+                                    ui,
+                                    window.window_id,
+                                    &mut window_action,
+                                );
                                 if ui.button("📋 Copy").clicked() {
                                     let info = format!(
                                         "{} - {} (PID: {})", window.window_title, window
@@ -191,6 +240,18 @@ impl ProcessManagerApp {
                     },
                 );
         }
+        match selection_change {
+            Some((window_id, true)) => {
+                self.selected_window_ids.insert(window_id);
+            }
+            Some((window_id, false)) => {
+                self.selected_window_ids.remove(&window_id);
+            }
+            None => {}
+        }
+        if let Some((window_id, action)) = window_action {
+            self.perform_window_action(window_id, action);
+        }
         if let Some((name, path)) = program_to_add {
             self.add_custom_program(name, path, String::new(), false);
             self.view_mode = ViewMode::New;
@@ -78,6 +78,26 @@ impl ProcessManagerApp {
                         }
                     });
             }
+            SortColumn::DiskRead => {
+                self.processes
+                    .sort_by(|a, b| {
+                        if ascending {
+                            a.disk_read_rate.cmp(&b.disk_read_rate)
+                        } else {
+                            b.disk_read_rate.cmp(&a.disk_read_rate)
+                        }
+                    });
+            }
+            SortColumn::DiskWrite => {
+                self.processes
+                    .sort_by(|a, b| {
+                        if ascending {
+                            a.disk_write_rate.cmp(&b.disk_write_rate)
+                        } else {
+                            b.disk_write_rate.cmp(&a.disk_write_rate)
+                        }
+                    });
+            }
             _ => {}
         }
     }
@@ -17,6 +17,11 @@ use std::time::Duration;
 impl eframe::App for ProcessManagerApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         self.apply_theme(ctx);
+        self.drain_library_logs();
+        self.poll_managed_processes(ctx);
+        self.poll_group_launches(ctx);
+        self.poll_process_icons(ctx);
+        self.poll_backend_jobs(ctx);
         if self.auto_refresh && self.last_update.elapsed() >= self.update_interval {
             self.refresh_all_data();
         }
@@ -56,6 +61,21 @@ impl eframe::App for ProcessManagerApp {
                                 ViewMode::Windows,
                                 "🪟 Windows",
                             );
+                            ui.selectable_value(
+                                &mut self.view_mode,
+                                ViewMode::Services,
+                                "🧩 Services",
+                            );
+                            ui.selectable_value(
+                                &mut self.view_mode,
+                                ViewMode::Autostart,
+                                "🚦 Startup",
+                            );
+                            ui.selectable_value(
+                                &mut self.view_mode,
+                                ViewMode::Disks,
+                                "💽 Disks",
+                            );
                             ui.selectable_value(
                                 &mut self.view_mode,
                                 ViewMode::Taskbar,
@@ -132,6 +152,7 @@ impl eframe::App for ProcessManagerApp {
                                 {
                                     self.show_window_grid = !self.show_window_grid;
                                 }
+                                self.show_window_layout_menu(ui);
                             }
                         });
                         ui.horizontal(|ui| {
@@ -252,6 +273,21 @@ impl eframe::App for ProcessManagerApp {
                                     ui.label("📂 Filepane File Manager");
                                     (0, 0)
                                 }
+                                ViewMode::Services => {
+                                    let total = self.services.services.len();
+                                    ui.label(format!("Total: {} services", total));
+                                    (total, 0)
+                                }
+                                ViewMode::Autostart => {
+                                    let total = self.autostart.entries.len();
+                                    ui.label(format!("Total: {} startup entries", total));
+                                    (total, 0)
+                                }
+                                ViewMode::Disks => {
+                                    let total = self.disks.disks.list().len();
+                                    ui.label(format!("Total: {} mounts", total));
+                                    (total, 0)
+                                }
                             };
                             ui.separator();
                             if total_pages > 1 {
@@ -348,6 +384,17 @@ impl eframe::App for ProcessManagerApp {
                                     "Two-column file manager with directory navigation and file operations",
                                 );
                             }
+                            ViewMode::Services => {
+                                ui.label(
+                                    "System services - start, stop, restart and set startup behaviour",
+                                );
+                            }
+                            ViewMode::Autostart => {
+                                ui.label("Programs started at login - untick to disable without deleting");
+                            }
+                            ViewMode::Disks => {
+                                ui.label("Mounted filesystems, disk throughput and per-process I/O");
+                            }
                         }
                     });
                 },
@@ -416,14 +463,39 @@ impl eframe::App for ProcessManagerApp {
                             ViewMode::Logs => self.show_logs_view(ui),
                             ViewMode::Settings => self.show_settings_view(ui),
                             ViewMode::Filepane => self.show_filepane_view(ui),
+                            ViewMode::Services => self.show_services_view(ui),
+                            ViewMode::Autostart => self.show_autostart_view(ui),
+                            ViewMode::Disks => self.show_disks_view(ui),
                         }
                     }
                     self.show_kill_confirm_dialog(ctx);
                     if self.font_picker.is_open {
                         self.show_font_picker(ctx);
                     }
+                    if self.file_search.is_open {
+                        self.show_file_search(ctx);
+                    }
+                    if self.disk_usage.is_open {
+                        self.show_disk_usage(ctx);
+                    }
+                    if self.duplicate_finder.is_open {
+                        self.show_duplicate_finder(ctx);
+                    }
+                    if self.properties_editor.is_open {
+                        self.show_properties_editor(ctx);
+                    }
+                    if self.file_viewer.is_open {
+                        self.show_file_viewer(ctx);
+                    }
                 },
             );
     }
+    fn raw_input_hook(&mut self, ctx: &egui::Context, raw_input: &mut egui::RawInput) {
+        self.capture_filepane_keys(ctx, raw_input);
+    }
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        if self.filepane_recent_dirs_changed {
+            self.save_config();
+        }
+    }
 }
-
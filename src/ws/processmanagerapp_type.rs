@@ -1,4 +1,6 @@
 use sysinfo::System;
+use crate::ws::BackendJob;
+use crate::ws::CopyBatch;
 use crate::ws::ProcessInfo;
 use crate::ws::FileHandle;
 use crate::ws::NetworkConnection;
@@ -10,6 +12,29 @@ use crate::ws::ViewMode;
 use crate::egui::Theme;
 use crate::ws::CustomProgram;
 use crate::ws::FontPickerState;
+use crate::ws::{SftpBackend, SftpConnectState};
+use crate::ws::AuditLogState;
+use crate::ws::ThumbnailPipeline;
+use crate::ws::WindowLayout;
+use crate::ws::ManagedProcess;
+use crate::ws::{GroupLaunch, ProgramGroup};
+use crate::ws::LauncherIndexState;
+use crate::ws::ProcessIconCache;
+use crate::ws::ServicesState;
+use crate::ws::AutostartState;
+use crate::ws::DisksState;
+use crate::ws::HistoryBrowserState;
+use crate::ws::{LogRecord, LogViewState};
+use crate::ws::FileViewerState;
+use crate::ws::FileSearchState;
+use crate::ws::DiskUsageState;
+use crate::ws::DuplicateFinderState;
+use crate::ws::PropertiesEditorState;
+use crate::ws::DirectoryWatchState;
+use crate::ws::FilepaneKeyboardState;
+use crate::ws::QuickJumpState;
+use crate::ws::FilepaneBookmark;
+use crate::ws::FilepaneKeymap;
 use crate::ws::FilepaneTab;
 use crate::ws::FileInfo;
 use crate::ws::FilepaneCommand;
@@ -43,20 +68,41 @@ pub struct ProcessManagerApp {
     pub(super) custom_refresh_input: String,
     pub(super) show_refresh_input: bool,
     pub(super) foreground_window_id: Option<u64>,
+    #[cfg(target_os = "linux")]
+    pub(super) window_system: Option<crate::ws::LinuxWindowSystem>,
+    /// Why windows cannot be enumerated, shown in the Windows view.
+    pub(super) window_system_error: Option<String>,
     pub(super) items_per_page: usize,
     pub(super) current_page: usize,
     pub(super) show_window_grid: bool,
+    /// Windows picked in the Windows view for layout actions.
+    pub(super) selected_window_ids: HashSet<u64>,
+    pub(super) window_layouts: Vec<WindowLayout>,
+    pub(super) window_layout_name: String,
     pub(super) program_path: String,
     pub(super) program_args: String,
-    pub(super) logs: Vec<String>,
-    pub(super) selected_log_indices: HashSet<usize>,
+    pub(super) program_working_dir: String,
+    /// `KEY=VALUE` lines for the launch form.
+    pub(super) program_env: String,
+    pub(super) program_capture: bool,
+    pub(super) program_restart: bool,
+    pub(super) program_profile_name: String,
+    pub(super) managed_processes: Vec<ManagedProcess>,
+    pub(super) next_managed_id: u64,
+    pub(super) selected_console: Option<u64>,
+    pub(super) program_groups: Vec<ProgramGroup>,
+    pub(super) group_launches: Vec<GroupLaunch>,
+    /// Group being edited in the New view, if any.
+    pub(super) group_editor: Option<ProgramGroup>,
+    pub(super) logs: std::collections::VecDeque<LogRecord>,
+    pub(super) selected_log_ids: HashSet<u64>,
+    pub(super) log_view: LogViewState,
     pub(super) run_as_admin: bool,
     pub(super) custom_programs: Vec<CustomProgram>,
     pub(super) taskbar_page: usize,
     pub(super) live_page: usize,
     pub(super) live_thumbnails: HashMap<u64, egui::TextureHandle>,
-    pub(super) live_current_capture_index: usize,
-    pub(super) live_last_capture: Instant,
+    pub(super) live_pipeline: ThumbnailPipeline,
     pub(super) live_capture_interval: Duration,
     pub(super) live_paused: bool,
     pub(super) kill_confirm_pid: Option<u32>,
@@ -65,12 +111,41 @@ pub struct ProcessManagerApp {
     pub(super) use_noto_font: bool,
     pub(super) font_path: String,
     pub(super) font_picker: FontPickerState,
+    pub(super) file_viewer: FileViewerState,
+    pub(super) file_search: FileSearchState,
+    pub(super) disk_usage: DiskUsageState,
+    pub(super) duplicate_finder: DuplicateFinderState,
+    pub(super) properties_editor: PropertiesEditorState,
+    pub(super) directory_watch: DirectoryWatchState,
+    pub(super) filepane_keymap: FilepaneKeymap,
+    pub(super) filepane_keyboard: FilepaneKeyboardState,
+    pub(super) filepane_bookmarks: Vec<FilepaneBookmark>,
+    pub(super) filepane_recent_dirs: Vec<String>,
+    /// The MRU list changed since the config was last written; it is saved
+    /// with the next config write or on exit rather than on every visit,
+    /// and cleared once a write succeeds.
+    pub(super) filepane_recent_dirs_changed: bool,
+    pub(super) quick_jump: QuickJumpState,
+    pub(super) launcher_index: LauncherIndexState,
+    pub(super) process_icons: ProcessIconCache,
+    pub(super) services: ServicesState,
+    pub(super) autostart: AutostartState,
+    pub(super) disks: DisksState,
+    pub(super) sftp_connect: SftpConnectState,
+    pub(super) audit_log: AuditLogState,
+    pub(super) history_browser: HistoryBrowserState,
+    /// Open SFTP sessions keyed by `SftpTarget::key`.
+    pub(super) filepane_backend_jobs: Vec<BackendJob>,
+    pub(super) filepane_copy_batches: Vec<CopyBatch>,
+    pub(super) filepane_next_batch_id: u64,
+    pub(super) sftp_sessions: std::collections::HashMap<String, std::sync::Arc<SftpBackend>>,
     pub(super) live_grid_size: usize,
     pub(super) live_detail_percent: f32,
     pub(super) attempt_start_as_admin: bool,
     pub(super) filepane_tabs: Vec<FilepaneTab>,
     pub(super) filepane_active_tab: usize,
     pub(super) filepane_swap_columns: bool,
+    pub(super) filepane_one_filesystem: bool,
     pub(super) filepane_config_path: String,
     pub(super) context_menu_file: Option<(FileInfo, usize)>,
     pub(super) show_context_menu: bool,
@@ -82,4 +157,6 @@ pub struct ProcessManagerApp {
     pub(super) filepane_pending_operation: Option<FileOperation>,
     pub(super) filepane_operation_history: Vec<FileOperation>,
     pub(super) filepane_trash_items: Vec<TrashItem>,
+    pub(super) filepane_dir_sizes: HashMap<String, u64>,
+    pub(super) filepane_size_receiver: Option<std::sync::mpsc::Receiver<(String, u64)>>,
 }
@@ -0,0 +1,266 @@
+use crate::ws::LogLevel;
+use crate::ws::FileInfo;
+use crate::ws::is_remote_path;
+use crate::ws::parent_directory;
+// # ProcessManagerApp - watch_directories_group Methods
+//
+// This module contains method implementations for `ProcessManagerApp`.
+//
+// Panel listings are cached and only re-read once a watched directory has
+// been quiet for `WATCH_DEBOUNCE`, so bursts such as a large copy collapse
+// into a single refresh.
+
+use notify::Watcher;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::TryRecvError;
+use std::time::{Duration, Instant};
+use super::processmanagerapp_type::ProcessManagerApp;
+
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+/// Listings expire on their own when notifications are unavailable.
+const UNWATCHED_REFRESH: Duration = Duration::from_secs(2);
+/// How long a directory (or the watcher) that failed to register is left
+/// alone before trying again.
+const WATCH_RETRY: Duration = Duration::from_secs(30);
+
+impl ProcessManagerApp {
+    /// Keeps the set of watched directories in line with the panel paths of
+    /// every tab and folds debounced change events into the listing cache.
+    pub(crate) fn poll_directory_watches(&mut self, ctx: &egui::Context) {
+        self.sync_directory_watches();
+        let mut disconnected = false;
+        if let Some(receiver) = &self.directory_watch.receiver {
+            loop {
+                match receiver.try_recv() {
+                    Ok(Ok(event)) => {
+                        if matches!(event.kind, notify::EventKind::Access(_)) {
+                            continue;
+                        }
+                        let now = Instant::now();
+                        // A watched directory that changes itself (renamed,
+                        // deleted) also changes the listing of its parent.
+                        for path in event.paths {
+                            if let Some(parent) = path.parent() {
+                                if self.directory_watch.watched.contains(parent) {
+                                    self.directory_watch.pending.insert(parent.to_path_buf(), now);
+                                }
+                            }
+                            if self.directory_watch.watched.contains(&path) {
+                                self.directory_watch.pending.insert(path, now);
+                            }
+                        }
+                    }
+                    Ok(Err(e)) => {
+                        self.directory_watch.error_msg = Some(e.to_string());
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        disconnected = true;
+                        break;
+                    }
+                }
+            }
+        }
+        if disconnected {
+            self.directory_watch.receiver = None;
+            self.directory_watch.watcher = None;
+            self.directory_watch.watched.clear();
+            self.directory_watch.synced_paths = None;
+        }
+        let settled: Vec<PathBuf> = self
+            .directory_watch
+            .pending
+            .iter()
+            .filter(|(_, last_event)| last_event.elapsed() >= WATCH_DEBOUNCE)
+            .map(|(path, _)| path.clone())
+            .collect();
+        for directory in settled {
+            self.directory_watch.pending.remove(&directory);
+            self.refresh_directory_listing(&directory);
+        }
+        if !self.directory_watch.pending.is_empty() {
+            ctx.request_repaint_after(WATCH_DEBOUNCE);
+        }
+    }
+    /// Re-checks the panel directories only when a tab's path changed or a
+    /// failed watch is due for another try, not on every frame.
+    fn sync_directory_watches(&mut self) {
+        let paths_unchanged = self.directory_watch.synced_paths.as_ref().is_some_and(|synced| {
+            self.filepane_tabs
+                .iter()
+                .flat_map(|tab| [&tab.left_path, &tab.right_path])
+                .eq(synced.iter())
+        });
+        let retry_due = self
+            .directory_watch
+            .unwatchable
+            .values()
+            .chain(&self.directory_watch.watcher_failed_at)
+            .any(|failed_at| failed_at.elapsed() >= WATCH_RETRY);
+        if paths_unchanged && !retry_due {
+            return;
+        }
+        self.directory_watch.synced_paths = Some(
+            self.filepane_tabs
+                .iter()
+                .flat_map(|tab| [tab.left_path.clone(), tab.right_path.clone()])
+                .collect(),
+        );
+        let wanted: HashSet<PathBuf> = self
+            .filepane_tabs
+            .iter()
+            .flat_map(|tab| [PathBuf::from(&tab.left_path), PathBuf::from(&tab.right_path)])
+            .filter(|path| path.is_dir())
+            .collect();
+        self.directory_watch
+            .unwatchable
+            .retain(|path, failed_at| wanted.contains(path) && failed_at.elapsed() < WATCH_RETRY);
+        let to_watch: HashSet<PathBuf> = wanted
+            .iter()
+            .filter(|path| !self.directory_watch.unwatchable.contains_key(*path))
+            .cloned()
+            .collect();
+        if to_watch == self.directory_watch.watched {
+            return;
+        }
+        if self
+            .directory_watch
+            .watcher_failed_at
+            .is_some_and(|failed_at| failed_at.elapsed() < WATCH_RETRY)
+        {
+            return;
+        }
+        if self.directory_watch.watcher.is_none() {
+            let (sender, receiver) = std::sync::mpsc::channel();
+            match notify::recommended_watcher(move |event| {
+                let _ = sender.send(event);
+            }) {
+                Ok(watcher) => {
+                    self.directory_watch.watcher_failed_at = None;
+                    self.directory_watch.watcher = Some(watcher);
+                    self.directory_watch.receiver = Some(receiver);
+                    self.directory_watch.watched.clear();
+                }
+                Err(e) => {
+                    if self.directory_watch.error_msg.is_none() {
+                        self.add_log_at(LogLevel::Warn, format!("⚠️ Directory watching unavailable: {}", e));
+                    }
+                    self.directory_watch.error_msg = Some(e.to_string());
+                    self.directory_watch.watcher_failed_at = Some(Instant::now());
+                    return;
+                }
+            }
+        }
+        self.directory_watch
+            .listings
+            .retain(|path, _| wanted.contains(path));
+        let Some(watcher) = self.directory_watch.watcher.as_mut() else {
+            return;
+        };
+        let stale: Vec<PathBuf> = self
+            .directory_watch
+            .watched
+            .difference(&to_watch)
+            .cloned()
+            .collect();
+        for path in stale {
+            let _ = watcher.unwatch(&path);
+            self.directory_watch.watched.remove(&path);
+        }
+        let mut failures = Vec::new();
+        for path in to_watch {
+            if self.directory_watch.watched.contains(&path) {
+                continue;
+            }
+            match watcher.watch(&path, notify::RecursiveMode::NonRecursive) {
+                Ok(()) => {
+                    self.directory_watch.watched.insert(path);
+                }
+                Err(e) => {
+                    failures.push(format!("{}: {}", path.display(), e));
+                    self.directory_watch.unwatchable.insert(path, Instant::now());
+                }
+            }
+        }
+        // Unwatchable paths fall back to timed refreshes until their retry
+        // is due; note them once.
+        for failure in failures {
+            if self.directory_watch.error_msg.as_deref() != Some(failure.as_str()) {
+                self.add_log_at(LogLevel::Warn, format!("⚠️ Cannot watch {}", failure));
+                self.directory_watch.error_msg = Some(failure);
+            }
+        }
+    }
+    /// Unfiltered entries of `path`, read from its backend only when the
+    /// cached copy was invalidated by a change notification or has expired.
+    /// Remote listings stay cached until refreshed or changed by an operation.
+    pub(crate) fn directory_listing(&mut self, path: &str) -> Option<(Vec<FileInfo>, Option<FileInfo>)> {
+        let key = PathBuf::from(path);
+        if self.directory_watch.failures.contains_key(&key) {
+            return None;
+        }
+        let watched = self.directory_watch.watched.contains(&key) || is_remote_path(path);
+        if let Some((read_at, listing, parent)) = self.directory_watch.listings.get(&key) {
+            if watched || read_at.elapsed() < UNWATCHED_REFRESH {
+                return Some((listing.clone(), parent.clone()));
+            }
+        }
+        let listing = match self.filesystem(path).and_then(|backend| backend.read_dir(path)) {
+            Ok(listing) => listing,
+            Err(e) => {
+                // Remember remote failures so the server is not asked every frame.
+                if self.is_sftp_connected(path) {
+                    self.add_log_at(LogLevel::Error, format!("❌ Cannot list {}: {}", path, e));
+                    self.directory_watch.failures.insert(key, e.to_string());
+                }
+                return None;
+            }
+        };
+        // The ".." row comes from the same backend as the listing.
+        let parent = parent_directory(path)
+            .and_then(|parent| {
+                self.filesystem(&parent).and_then(|backend| backend.stat(&parent)).ok()
+            });
+        self.directory_watch
+            .listings
+            .insert(key, (Instant::now(), listing.clone(), parent.clone()));
+        Some((listing, parent))
+    }
+    pub(crate) fn invalidate_directory_listing(&mut self, path: &str) {
+        self.directory_watch.listings.remove(Path::new(path));
+        self.directory_watch.failures.remove(Path::new(path));
+    }
+    /// Remote servers send no change notifications, so listings are dropped
+    /// after every operation that may have touched them.
+    pub(crate) fn invalidate_remote_listings(&mut self) {
+        self.directory_watch
+            .listings
+            .retain(|path, _| !is_remote_path(&path.to_string_lossy()));
+        self.directory_watch.failures.clear();
+    }
+    /// Re-reads a changed directory and drops selections and checkboxes for
+    /// entries that no longer exist; everything else stays selected.
+    fn refresh_directory_listing(&mut self, directory: &Path) {
+        let path = directory.to_string_lossy().to_string();
+        self.invalidate_directory_listing(&path);
+        let Some((listing, _)) = self.directory_listing(&path) else {
+            return;
+        };
+        let mut names: HashSet<&str> = listing.iter().map(|info| info.name.as_str()).collect();
+        // The ".." row is listed under the parent's own name.
+        if let Some(parent_name) = directory.parent().and_then(|parent| parent.file_name()) {
+            names.extend(parent_name.to_str());
+        }
+        for tab in &mut self.filepane_tabs {
+            if Path::new(&tab.left_path) == directory {
+                tab.selected_left.retain(|name| names.contains(name.as_str()));
+                tab.left_checkboxes.retain(|name| names.contains(name.as_str()));
+            }
+            if Path::new(&tab.right_path) == directory {
+                tab.selected_right.retain(|name| names.contains(name.as_str()));
+                tab.right_checkboxes.retain(|name| names.contains(name.as_str()));
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! # PropertiesEditorState - Trait Implementations
+//!
+//! This module contains trait implementations for `PropertiesEditorState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::{FileProperties, PropertiesEditorState};
+
+impl Default for PropertiesEditorState {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            path: String::new(),
+            is_directory: false,
+            size: 0,
+            original: FileProperties::default(),
+            edited: FileProperties::default(),
+            mode_text: String::new(),
+            recursive: false,
+            error_msg: None,
+        }
+    }
+}
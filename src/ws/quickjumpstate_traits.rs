@@ -0,0 +1,19 @@
+//! # QuickJumpState - Trait Implementations
+//!
+//! This module contains trait implementations for `QuickJumpState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::QuickJumpState;
+
+impl Default for QuickJumpState {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            query: String::new(),
+            selected: 0,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! # ServicesState - Trait Implementations
+//!
+//! This module contains trait implementations for `ServicesState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::{ServiceStateFilter, ServicesState};
+
+impl Default for ServicesState {
+    fn default() -> Self {
+        let (action_sender, action_results) = std::sync::mpsc::channel();
+        Self {
+            services: Vec::new(),
+            loader: None,
+            loaded: false,
+            error_msg: None,
+            user_units: false,
+            state_filter: ServiceStateFilter::All,
+            busy: std::collections::HashSet::new(),
+            pending_confirmation: None,
+            action_sender,
+            action_results,
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! # SftpBackend - Trait Implementations
+//!
+//! This module contains trait implementations for `SftpBackend`.
+//!
+//! ## Implemented Traits
+//!
+//! - `FileSystemBackend`
+
+use std::path::Path;
+use super::types::{FileInfo, FileSystemBackend, SftpBackend};
+
+impl FileSystemBackend for SftpBackend {
+    fn label(&self) -> String {
+        format!("sftp {}", self.target.key())
+    }
+    fn read_dir(&self, path: &str) -> std::io::Result<Vec<FileInfo>> {
+        let remote = self.remote_path(path)?;
+        let entries = self.sftp.readdir(Path::new(&remote))?;
+        Ok(
+            entries
+                .into_iter()
+                .filter_map(|(entry, stat)| {
+                    let name = entry.file_name()?.to_string_lossy().to_string();
+                    Some(self.remote_file_info(&Self::join_remote(&remote, &name), stat))
+                })
+                .collect(),
+        )
+    }
+    fn stat(&self, path: &str) -> std::io::Result<FileInfo> {
+        let remote = self.remote_path(path)?;
+        let stat = self.sftp.lstat(Path::new(&remote))?;
+        Ok(self.remote_file_info(&remote, stat))
+    }
+    fn create_dir_all(&self, path: &str) -> std::io::Result<()> {
+        let remote = self.remote_path(path)?;
+        let mut current = String::new();
+        for component in remote.split('/').filter(|c| !c.is_empty()) {
+            current = Self::join_remote(&current, component);
+            match self.sftp.stat(Path::new(&current)) {
+                Ok(stat) if stat.is_dir() => continue,
+                Ok(_) => {
+                    return Err(
+                        std::io::Error::new(
+                            std::io::ErrorKind::AlreadyExists,
+                            format!("{} exists and is not a directory", current),
+                        ),
+                    );
+                }
+                Err(_) => self.sftp.mkdir(Path::new(&current), 0o755)?,
+            }
+        }
+        Ok(())
+    }
+    fn rename(&self, from: &str, to: &str) -> std::io::Result<()> {
+        let from = self.remote_path(from)?;
+        let to = self.remote_path(to)?;
+        Ok(self.sftp.rename(Path::new(&from), Path::new(&to), None)?)
+    }
+    fn remove_file(&self, path: &str) -> std::io::Result<()> {
+        let remote = self.remote_path(path)?;
+        Ok(self.sftp.unlink(Path::new(&remote))?)
+    }
+    fn remove_dir(&self, path: &str) -> std::io::Result<()> {
+        let remote = self.remote_path(path)?;
+        Ok(self.sftp.rmdir(Path::new(&remote))?)
+    }
+    fn read_link(&self, path: &str) -> std::io::Result<String> {
+        let remote = self.remote_path(path)?;
+        Ok(self.sftp.readlink(Path::new(&remote))?.to_string_lossy().to_string())
+    }
+    fn hard_link(&self, _target: &str, _link_path: &str, _replace: bool) -> std::io::Result<()> {
+        Err(
+            std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "hard links are not supported over SFTP",
+            ),
+        )
+    }
+    fn symlink(&self, target: &str, link_path: &str) -> std::io::Result<()> {
+        let link = self.remote_path(link_path)?;
+        Ok(self.sftp.symlink(Path::new(target), Path::new(&link))?)
+    }
+    fn open_read(&self, path: &str) -> std::io::Result<Box<dyn std::io::Read + '_>> {
+        let remote = self.remote_path(path)?;
+        Ok(Box::new(self.sftp.open(Path::new(&remote))?))
+    }
+    fn create_file(&self, path: &str) -> std::io::Result<Box<dyn std::io::Write + '_>> {
+        let remote = self.remote_path(path)?;
+        Ok(Box::new(self.sftp.create(Path::new(&remote))?))
+    }
+}
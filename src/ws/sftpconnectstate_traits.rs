@@ -0,0 +1,27 @@
+//! # SftpConnectState - Trait Implementations
+//!
+//! This module contains trait implementations for `SftpConnectState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Default`
+
+use super::types::{SftpConnectState, SftpCredentials};
+
+impl Default for SftpConnectState {
+    fn default() -> Self {
+        Self {
+            is_open: false,
+            panel: 0,
+            host: String::new(),
+            port: "22".to_string(),
+            user: std::env::var("USER")
+                .or_else(|_| std::env::var("USERNAME"))
+                .unwrap_or_default(),
+            remote_path: String::new(),
+            credentials: SftpCredentials::default(),
+            connecting: None,
+            error_msg: None,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! # ThumbnailPipeline - Trait Implementations
+//!
+//! This module contains trait implementations for `ThumbnailPipeline`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Drop`
+
+use super::types::ThumbnailPipeline;
+
+impl Drop for ThumbnailPipeline {
+    fn drop(&mut self) {
+        let (lock, ready) = &*self.queue;
+        if let Ok(mut pending) = lock.lock() {
+            pending.shutdown = true;
+        }
+        ready.notify_all();
+    }
+}
@@ -17,6 +17,9 @@ pub enum ViewMode {
     Logs,
     Settings,
     Filepane,
+    Services,
+    Autostart,
+    Disks,
 }
 #[derive(Clone, Debug)]
 pub struct WindowInfo {
@@ -26,6 +29,201 @@ pub struct WindowInfo {
     pub window_id: u64,
     pub is_foreground: bool,
 }
+/// Window manager requests offered next to each window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WindowAction {
+    Minimize,
+    ToggleMaximize,
+    Close,
+}
+impl WindowAction {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            WindowAction::Minimize => "🗕",
+            WindowAction::ToggleMaximize => "🗖",
+            WindowAction::Close => "✖",
+        }
+    }
+    pub fn name(&self) -> &'static str {
+        match self {
+            WindowAction::Minimize => "Minimize",
+            WindowAction::ToggleMaximize => "Maximize / restore",
+            WindowAction::Close => "Close window",
+        }
+    }
+}
+/// A screen rectangle in pixels, including window decorations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+impl WindowRect {
+    /// The part both rectangles cover, if any.
+    pub fn intersect(&self, other: &WindowRect) -> Option<WindowRect> {
+        let left = self.x.max(other.x);
+        let top = self.y.max(other.y);
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+        (right > left && bottom > top)
+            .then(|| WindowRect {
+                x: left,
+                y: top,
+                width: right - left,
+                height: bottom - top,
+            })
+    }
+    pub fn area(&self) -> i64 {
+        self.width.max(0) as i64 * self.height.max(0) as i64
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SnapPosition {
+    LeftHalf,
+    RightHalf,
+    TopHalf,
+    BottomHalf,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+impl SnapPosition {
+    pub const ALL: [SnapPosition; 8] = [
+        SnapPosition::LeftHalf,
+        SnapPosition::RightHalf,
+        SnapPosition::TopHalf,
+        SnapPosition::BottomHalf,
+        SnapPosition::TopLeft,
+        SnapPosition::TopRight,
+        SnapPosition::BottomLeft,
+        SnapPosition::BottomRight,
+    ];
+    pub fn name(&self) -> &'static str {
+        match self {
+            SnapPosition::LeftHalf => "◧ Left half",
+            SnapPosition::RightHalf => "◨ Right half",
+            SnapPosition::TopHalf => "⬒ Top half",
+            SnapPosition::BottomHalf => "⬓ Bottom half",
+            SnapPosition::TopLeft => "◰ Top-left quarter",
+            SnapPosition::TopRight => "◳ Top-right quarter",
+            SnapPosition::BottomLeft => "◱ Bottom-left quarter",
+            SnapPosition::BottomRight => "◲ Bottom-right quarter",
+        }
+    }
+}
+/// Ways of placing a set of windows inside the monitor work area.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WindowArrangement {
+    /// Full-width strips stacked top to bottom.
+    TileHorizontally,
+    /// Full-height columns side by side.
+    TileVertically,
+    Cascade,
+    Snap(SnapPosition),
+}
+impl WindowArrangement {
+    /// Target rectangles for `count` windows, in window order.
+    pub fn rects(&self, area: WindowRect, count: usize) -> Vec<WindowRect> {
+        let n = count.max(1) as i32;
+        (0..count as i32)
+            .map(|i| match self {
+                WindowArrangement::TileHorizontally => {
+                    let height = area.height / n;
+                    WindowRect {
+                        x: area.x,
+                        y: area.y + i * height,
+                        width: area.width,
+                        height,
+                    }
+                }
+                WindowArrangement::TileVertically => {
+                    let width = area.width / n;
+                    WindowRect {
+                        x: area.x + i * width,
+                        y: area.y,
+                        width,
+                        height: area.height,
+                    }
+                }
+                WindowArrangement::Cascade => {
+                    const STEP: i32 = 32;
+                    let width = area.width * 2 / 3;
+                    let height = area.height * 2 / 3;
+                    // Start over at the corner once the next step would
+                    // push a window past the work area.
+                    let steps = ((area.height - height) / STEP).max(1);
+                    let offset = (i % steps) * STEP;
+                    WindowRect {
+                        x: area.x + offset,
+                        y: area.y + offset,
+                        width,
+                        height,
+                    }
+                }
+                WindowArrangement::Snap(position) => {
+                    let (half_w, half_h) = (area.width / 2, area.height / 2);
+                    let (x, y, width, height) = match position {
+                        SnapPosition::LeftHalf => (0, 0, half_w, area.height),
+                        SnapPosition::RightHalf => (half_w, 0, area.width - half_w, area.height),
+                        SnapPosition::TopHalf => (0, 0, area.width, half_h),
+                        SnapPosition::BottomHalf => (0, half_h, area.width, area.height - half_h),
+                        SnapPosition::TopLeft => (0, 0, half_w, half_h),
+                        SnapPosition::TopRight => (half_w, 0, area.width - half_w, half_h),
+                        SnapPosition::BottomLeft => (0, half_h, half_w, area.height - half_h),
+                        SnapPosition::BottomRight => {
+                            (half_w, half_h, area.width - half_w, area.height - half_h)
+                        }
+                    };
+                    WindowRect {
+                        x: area.x + x,
+                        y: area.y + y,
+                        width,
+                        height,
+                    }
+                }
+            })
+            .collect()
+    }
+}
+/// Places every window whose title matches `title_pattern` (a regex).
+#[derive(Clone, Debug)]
+pub struct WindowLayoutRule {
+    pub title_pattern: String,
+    pub rect: WindowRect,
+}
+impl WindowLayoutRule {
+    /// Config form `x,y,width,height,pattern`; the pattern comes last so it
+    /// may contain commas.
+    pub fn to_config(&self) -> String {
+        format!(
+            "{},{},{},{},{}", self.rect.x, self.rect.y, self.rect.width, self.rect.height,
+            self.title_pattern
+        )
+    }
+    pub fn from_config(value: &str) -> Option<Self> {
+        let mut parts = value.splitn(5, ',');
+        let mut number = || parts.next()?.trim().parse::<i32>().ok();
+        let rect = WindowRect {
+            x: number()?,
+            y: number()?,
+            width: number()?,
+            height: number()?,
+        };
+        Some(WindowLayoutRule {
+            title_pattern: parts.next()?.to_string(),
+            rect,
+        })
+    }
+}
+/// A named set of window placements saved in the config.
+#[derive(Clone, Debug)]
+pub struct WindowLayout {
+    pub name: String,
+    pub rules: Vec<WindowLayoutRule>,
+}
 pub struct AppConfig {
     pub programs: Vec<CustomProgram>,
     pub font_path: String,
@@ -34,6 +232,14 @@ pub struct AppConfig {
     pub live_grid_size: usize,
     pub live_detail_percent: f32,
     pub attempt_start_as_admin: bool,
+    pub keymap: FilepaneKeymap,
+    pub bookmarks: Vec<FilepaneBookmark>,
+    pub recent_dirs: Vec<String>,
+    pub logging: LogSettings,
+    pub window_layouts: Vec<WindowLayout>,
+    pub program_groups: Vec<ProgramGroup>,
+    /// Config lines that were skipped, logged once the app has started.
+    pub warnings: Vec<String>,
 }
 #[derive(Clone, Debug)]
 pub struct FileHandle {
@@ -61,10 +267,29 @@ pub struct FileInfo {
     pub size: u64,
     pub modified: std::time::SystemTime,
     pub extension: Option<String>,
+    /// Where the entry points when it is a symbolic link.
+    pub symlink_target: Option<String>,
+    /// A symlink whose target does not exist.
+    pub is_broken_link: bool,
+    /// Number of hard links to the file; 1 when unknown.
+    pub hard_links: u64,
+    /// A directory that is the root of another mounted filesystem.
+    pub is_mount_point: bool,
 }
 impl FileInfo {
     pub fn new(path: std::path::PathBuf) -> Result<Self, Box<dyn std::error::Error>> {
-        let metadata = std::fs::metadata(&path)?;
+        let link_metadata = std::fs::symlink_metadata(&path)?;
+        let symlink_target = if link_metadata.file_type().is_symlink() {
+            Some(std::fs::read_link(&path)?.to_string_lossy().to_string())
+        } else {
+            None
+        };
+        // Broken links keep their own metadata so they stay visible.
+        let (metadata, is_broken_link) = match std::fs::metadata(&path) {
+            Ok(metadata) => (metadata, false),
+            Err(_) if symlink_target.is_some() => (link_metadata, true),
+            Err(e) => return Err(e.into()),
+        };
         let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("").to_string();
         let path_str = path.to_string_lossy().to_string();
         let is_directory = metadata.is_dir();
@@ -75,6 +300,15 @@ impl FileInfo {
         } else {
             None
         };
+        #[cfg(unix)]
+        let hard_links = {
+            use std::os::unix::fs::MetadataExt;
+            if is_directory { 1 } else { metadata.nlink() }
+        };
+        #[cfg(not(unix))]
+        let hard_links = 1;
+        let is_mount_point = is_directory && symlink_target.is_none()
+            && Self::detect_mount_point(&path, &metadata);
         Ok(FileInfo {
             name,
             path: path_str,
@@ -82,8 +316,139 @@ impl FileInfo {
             size,
             modified,
             extension,
+            symlink_target,
+            is_broken_link,
+            hard_links,
+            is_mount_point,
         })
     }
+    #[cfg(unix)]
+    fn detect_mount_point(path: &std::path::Path, metadata: &std::fs::Metadata) -> bool {
+        use std::os::unix::fs::MetadataExt;
+        match path.parent().map(std::fs::metadata) {
+            Some(Ok(parent)) => {
+                parent.dev() != metadata.dev() || parent.ino() == metadata.ino()
+            }
+            Some(Err(_)) => false,
+            None => true,
+        }
+    }
+    #[cfg(not(unix))]
+    fn detect_mount_point(path: &std::path::Path, metadata: &std::fs::Metadata) -> bool {
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::fs::MetadataExt;
+            const FILE_ATTRIBUTE_REPARSE_POINT: u32 = 0x400;
+            path.parent().is_none()
+                || metadata.file_attributes() & FILE_ATTRIBUTE_REPARSE_POINT != 0
+        }
+        #[cfg(not(target_os = "windows"))]
+        {
+            let _ = metadata;
+            path.parent().is_none()
+        }
+    }
+    pub fn is_symlink(&self) -> bool {
+        self.symlink_target.is_some()
+    }
+}
+/// Why a recursive walk refused to descend into a directory.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TraversalSkip {
+    Cycle,
+    OtherFilesystem,
+    /// Its metadata could not be read.
+    Unreadable(std::io::ErrorKind),
+}
+/// Tracks directories already entered during a recursive walk so bind mounts
+/// and looping links cannot recurse forever, and optionally keeps the walk on
+/// the filesystem it started on.
+pub struct TraversalGuard {
+    visited: HashSet<String>,
+    root_device: Option<u64>,
+    one_filesystem: bool,
+}
+impl TraversalGuard {
+    pub fn new(root: &std::path::Path, one_filesystem: bool) -> Self {
+        Self {
+            visited: HashSet::new(),
+            root_device: std::fs::metadata(root).ok().and_then(|m| Self::device_of(&m)),
+            one_filesystem,
+        }
+    }
+    /// Records `path` as entered, or reports why it must be skipped.
+    pub fn enter(&mut self, path: &std::path::Path) -> Result<(), TraversalSkip> {
+        let metadata = std::fs::metadata(path).map_err(|e| TraversalSkip::Unreadable(e.kind()))?;
+        if self.one_filesystem && self.crosses_filesystem(&metadata) {
+            return Err(TraversalSkip::OtherFilesystem);
+        }
+        let identity = Self::identity_of(path, &metadata);
+        if self.visited.insert(format!("d:{}", identity)) {
+            Ok(())
+        } else {
+            Err(TraversalSkip::Cycle)
+        }
+    }
+    /// True the first time a hardlinked file is seen; later links to the same
+    /// inode return false so sizes are not counted twice.
+    pub fn first_link(&mut self, path: &std::path::Path, metadata: &std::fs::Metadata) -> bool {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            metadata.nlink() <= 1
+                || self.visited.insert(format!("f:{}", Self::identity_of(path, metadata)))
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = (path, metadata);
+            true
+        }
+    }
+    fn crosses_filesystem(&self, metadata: &std::fs::Metadata) -> bool {
+        let other_device = match (self.root_device, Self::device_of(metadata)) {
+            (Some(root), Some(device)) => root != device,
+            _ => false,
+        };
+        #[cfg(target_os = "windows")]
+        {
+            // Junctions and volume mount points are reparse points.
+            use std::os::windows::fs::MetadataExt;
+            const FILE_ATTRIBUTE_REPARSE_POINT: u32 = 0x400;
+            other_device || metadata.file_attributes() & FILE_ATTRIBUTE_REPARSE_POINT != 0
+        }
+        #[cfg(not(target_os = "windows"))]
+        {
+            other_device
+        }
+    }
+    fn device_of(metadata: &std::fs::Metadata) -> Option<u64> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            Some(metadata.dev())
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = metadata;
+            None
+        }
+    }
+    fn identity_of(path: &std::path::Path, metadata: &std::fs::Metadata) -> String {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            let _ = path;
+            format!("{}:{}", metadata.dev(), metadata.ino())
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = metadata;
+            std::fs::canonicalize(path)
+                .unwrap_or_else(|_| path.to_path_buf())
+                .to_string_lossy()
+                .to_string()
+        }
+    }
 }
 #[derive(Clone, Debug)]
 pub struct ProcessInfo {
@@ -96,6 +461,9 @@ pub struct ProcessInfo {
     pub run_time: u64,
     pub is_foreground: bool,
     pub exe_path: Option<String>,
+    /// Bytes per second read from storage since the previous refresh.
+    pub disk_read_rate: u64,
+    pub disk_write_rate: u64,
 }
 #[derive(PartialEq, Clone, Copy)]
 pub enum SortColumn {
@@ -114,16 +482,25 @@ pub enum SortColumn {
     RemoteAddr,
     NetState,
     WindowTitle,
+    DiskRead,
+    DiskWrite,
 }
 #[derive(Clone, Debug)]
 pub enum FilepaneCommand {
-    CopyFile { source: String, destination: String },
+    /// `speed_limit` caps the transfer in MB/s; `None` copies at full speed.
+    CopyFile { source: String, destination: String, speed_limit: Option<f64> },
     MoveFile { source: String, destination: String },
     DeleteFile { path: String },
     CreateDirectory { path: String },
     RenameFile { old_path: String, new_path: String },
     ChangeDirectory { panel: usize, new_path: String },
     CalculateChecksum { path: String, algorithm: ChecksumAlgorithm },
+    /// `replace` lets the link take the place of an existing file with the
+    /// same contents (duplicate resolution); otherwise an existing
+    /// `link_path` is an error.
+    CreateHardlink { target: String, link_path: String, replace: bool },
+    CreateSymlink { target: String, link_path: String },
+    Batch { commands: Vec<FilepaneCommand> },
 }
 #[derive(Clone, Debug, PartialEq)]
 pub enum ChecksumAlgorithm {
@@ -141,18 +518,534 @@ impl ChecksumAlgorithm {
             ChecksumAlgorithm::CRC32 => "CRC32",
         }
     }
+    /// Streams the file through the selected algorithm and returns the
+    /// lowercase hex digest.
+    pub fn hash_file(&self, path: &std::path::Path) -> std::io::Result<String> {
+        self.hash_reader(std::fs::File::open(path)?)
+    }
+    pub fn hash_reader(&self, file: impl std::io::Read) -> std::io::Result<String> {
+        use sha2::Digest;
+        use std::io::Read;
+        fn feed(
+            mut reader: impl Read,
+            mut update: impl FnMut(&[u8]),
+        ) -> std::io::Result<()> {
+            let mut buffer = vec![0u8; 64 * 1024];
+            loop {
+                let read = reader.read(&mut buffer)?;
+                if read == 0 {
+                    return Ok(());
+                }
+                update(&buffer[..read]);
+            }
+        }
+        let bytes = match self {
+            ChecksumAlgorithm::MD5 => {
+                let mut hasher = md5::Md5::new();
+                feed(file, |chunk| hasher.update(chunk))?;
+                hasher.finalize().to_vec()
+            }
+            ChecksumAlgorithm::SHA1 => {
+                let mut hasher = sha1::Sha1::new();
+                feed(file, |chunk| hasher.update(chunk))?;
+                hasher.finalize().to_vec()
+            }
+            ChecksumAlgorithm::SHA256 => {
+                let mut hasher = sha2::Sha256::new();
+                feed(file, |chunk| hasher.update(chunk))?;
+                hasher.finalize().to_vec()
+            }
+            ChecksumAlgorithm::CRC32 => {
+                let mut hasher = crc32fast::Hasher::new();
+                feed(file, |chunk| hasher.update(chunk))?;
+                hasher.finalize().to_be_bytes().to_vec()
+            }
+        };
+        Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+    }
 }
 #[derive(PartialEq, Clone, Copy)]
 pub enum Theme {
     Dark,
     Light,
 }
-#[derive(Clone, Debug)]
+/// A saved launch profile shown under Custom Programs.
+#[derive(Clone, Debug, Default)]
 pub struct CustomProgram {
     pub name: String,
     pub path: String,
+    /// One command line: passed verbatim on Windows, split with POSIX shell
+    /// quoting elsewhere.
     pub args: String,
     pub admin: bool,
+    /// Empty runs the program from its own directory.
+    pub working_dir: String,
+    /// Variables set on top of WinShell's own environment.
+    pub env: Vec<(String, String)>,
+    /// Pipe stdin/stdout/stderr into a console pane.
+    pub capture_output: bool,
+    /// Start the program again, with backoff, when it exits unsuccessfully.
+    pub restart_on_crash: bool,
+}
+impl CustomProgram {
+    /// Parses `KEY=VALUE` lines; blank lines and `#` comments are skipped.
+    pub fn parse_env(text: &str) -> Vec<(String, String)> {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+            .filter(|(key, _)| !key.is_empty())
+            .collect()
+    }
+    pub fn env_text(&self) -> String {
+        self.env
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConsoleStream {
+    Stdout,
+    Stderr,
+    /// Text the user sent to the process.
+    Stdin,
+    /// Launch, exit and restart notices.
+    System,
+}
+#[derive(Clone, Debug)]
+pub struct ConsoleLine {
+    pub stream: ConsoleStream,
+    pub text: String,
+}
+/// Lines kept per console pane.
+pub const CONSOLE_LINE_LIMIT: usize = 5000;
+/// A process started from a launch profile and watched until it exits.
+pub struct ManagedProcess {
+    pub id: u64,
+    pub profile: CustomProgram,
+    pub pid: u32,
+    pub child: Option<std::process::Child>,
+    pub stdin: Option<std::process::ChildStdin>,
+    pub output: Option<std::sync::mpsc::Receiver<ConsoleLine>>,
+    pub lines: std::collections::VecDeque<ConsoleLine>,
+    pub started_at: std::time::Instant,
+    /// Set once the process has exited; `None` inside means it was killed by
+    /// a signal.
+    pub exit_code: Option<Option<i32>>,
+    /// Restarts since the last run that stayed up long enough.
+    pub restarts: u32,
+    pub restart_at: Option<std::time::Instant>,
+    /// Stopped from the UI, so its exit is not treated as a crash.
+    pub stopped: bool,
+    pub input: String,
+}
+impl ManagedProcess {
+    pub fn push_line(&mut self, stream: ConsoleStream, text: String) {
+        self.lines.push_back(ConsoleLine { stream, text });
+        while self.lines.len() > CONSOLE_LINE_LIMIT {
+            self.lines.pop_front();
+        }
+    }
+    pub fn is_running(&self) -> bool {
+        self.child.is_some() && self.exit_code.is_none()
+    }
+}
+/// One program in a `ProgramGroup`, naming a launch profile.
+#[derive(Clone, Debug, Default)]
+pub struct ProgramGroupStep {
+    pub program: String,
+    /// Pause before this step starts.
+    pub delay_secs: u64,
+    /// Hold the next step until this TCP port is listening.
+    pub wait_port: Option<u16>,
+}
+impl ProgramGroupStep {
+    /// Config form `delay,port,program`; the port is empty when not waited
+    /// for, and the program name comes last so it may contain commas.
+    pub fn to_config(&self) -> String {
+        format!(
+            "{},{},{}", self.delay_secs, self.wait_port.map(|port| port.to_string())
+            .unwrap_or_default(), self.program
+        )
+    }
+    pub fn from_config(value: &str) -> Option<Self> {
+        let mut parts = value.splitn(3, ',');
+        let delay_secs = parts.next()?.trim().parse().ok()?;
+        let wait_port = match parts.next()?.trim() {
+            "" => None,
+            port => Some(port.parse().ok()?),
+        };
+        Some(ProgramGroupStep {
+            program: parts.next()?.trim().to_string(),
+            delay_secs,
+            wait_port,
+        })
+    }
+}
+/// Launch profiles started together, in order.
+#[derive(Clone, Debug, Default)]
+pub struct ProgramGroup {
+    pub name: String,
+    pub steps: Vec<ProgramGroupStep>,
+}
+/// Progress of one group launch; kept after the last step, or after a step
+/// fails, so the group can still be stopped.
+#[derive(Debug)]
+pub struct GroupLaunch {
+    pub group: String,
+    pub steps: Vec<ProgramGroupStep>,
+    /// Index of the step being delayed or waited on.
+    pub step: usize,
+    pub ready_at: std::time::Instant,
+    /// Port of the current step and when waiting started.
+    pub waiting_port: Option<(u16, std::time::Instant)>,
+    /// Results from the background probe of `waiting_port`, one per check.
+    pub port_probe: Option<std::sync::mpsc::Receiver<bool>>,
+    /// `ManagedProcess` ids started by this launch.
+    pub launched: Vec<u64>,
+    /// Why the launch was abandoned; no further steps run.
+    pub failure: Option<String>,
+}
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LauncherSource {
+    /// A freedesktop `.desktop` file from the XDG data directories.
+    DesktopEntry,
+    /// A Start Menu shortcut.
+    StartMenu,
+    /// An executable found on `PATH`.
+    Path,
+}
+impl LauncherSource {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            LauncherSource::DesktopEntry => "🖥",
+            LauncherSource::StartMenu => "🪟",
+            LauncherSource::Path => "⚙",
+        }
+    }
+}
+/// How an icon theme directory's icons may be scaled, from `index.theme`.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IconDirectoryKind {
+    Fixed,
+    Scalable { min_size: u32, max_size: u32 },
+    Threshold { threshold: u32 },
+}
+/// One subdirectory listed in an icon theme's `Directories`.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Debug)]
+pub struct IconThemeDirectory {
+    /// Relative to the theme root, e.g. `48x48/apps`.
+    pub path: String,
+    pub size: u32,
+    pub scale: u32,
+    pub kind: IconDirectoryKind,
+}
+#[cfg(target_os = "linux")]
+impl IconThemeDirectory {
+    /// `DirectoryMatchesSize` from the icon theme specification.
+    pub fn matches_size(&self, size: u32, scale: u32) -> bool {
+        if self.scale != scale {
+            return false;
+        }
+        match self.kind {
+            IconDirectoryKind::Fixed => self.size == size,
+            IconDirectoryKind::Scalable { min_size, max_size } => (min_size..=max_size).contains(&size),
+            IconDirectoryKind::Threshold { threshold } => {
+                self.size.saturating_sub(threshold) <= size && size <= self.size + threshold
+            }
+        }
+    }
+    /// `DirectorySizeDistance` from the icon theme specification.
+    pub fn size_distance(&self, size: u32, scale: u32) -> u32 {
+        let wanted = size * scale;
+        let (low, high) = match self.kind {
+            IconDirectoryKind::Fixed => (self.size, self.size),
+            IconDirectoryKind::Scalable { min_size, max_size } => (min_size, max_size),
+            IconDirectoryKind::Threshold { threshold } => {
+                (self.size.saturating_sub(threshold), self.size + threshold)
+            }
+        };
+        if wanted < low * self.scale {
+            low * self.scale - wanted
+        } else {
+            wanted.saturating_sub(high * self.scale)
+        }
+    }
+}
+/// An installed icon theme. The same theme name can have a directory in
+/// several base directories; all of them are searched.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Debug)]
+pub struct IconTheme {
+    pub name: String,
+    pub roots: Vec<std::path::PathBuf>,
+    pub directories: Vec<IconThemeDirectory>,
+    pub inherits: Vec<String>,
+}
+/// An application the launcher search can start or pin.
+#[derive(Clone, Debug)]
+pub struct LauncherEntry {
+    pub name: String,
+    pub path: String,
+    /// Arguments as one command line, quoted like `CustomProgram::args`.
+    pub args: String,
+    pub working_dir: String,
+    pub comment: String,
+    /// Image file for the entry's icon, when one was found.
+    pub icon_path: Option<String>,
+    /// Wants a terminal; its output is captured instead.
+    pub terminal: bool,
+    pub source: LauncherSource,
+    /// The `.desktop` file, shortcut or executable the entry came from.
+    pub location: String,
+}
+pub struct LauncherIndexState {
+    pub entries: Vec<LauncherEntry>,
+    /// Pending background scan.
+    pub loader: Option<std::sync::mpsc::Receiver<Vec<LauncherEntry>>>,
+    pub query: String,
+    /// Decoded icons by `icon_path`; `None` when the file could not be read.
+    pub icons: std::collections::HashMap<String, Option<egui::TextureHandle>>,
+}
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ServiceAction {
+    Start,
+    Stop,
+    Restart,
+    /// Start at boot (systemd `enable`, Windows automatic start).
+    Enable,
+    /// Never start automatically (systemd `disable`, Windows disabled).
+    Disable,
+}
+impl ServiceAction {
+    pub const ALL: [ServiceAction; 5] = [
+        ServiceAction::Start,
+        ServiceAction::Stop,
+        ServiceAction::Restart,
+        ServiceAction::Enable,
+        ServiceAction::Disable,
+    ];
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ServiceAction::Start => "▶",
+            ServiceAction::Stop => "⏹",
+            ServiceAction::Restart => "🔁",
+            ServiceAction::Enable => "✅",
+            ServiceAction::Disable => "🚫",
+        }
+    }
+    /// Lowercase verb, as systemctl spells it.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ServiceAction::Start => "start",
+            ServiceAction::Stop => "stop",
+            ServiceAction::Restart => "restart",
+            ServiceAction::Enable => "enable",
+            ServiceAction::Disable => "disable",
+        }
+    }
+}
+/// A systemd service unit or a Windows service.
+#[derive(Clone, Debug)]
+pub struct ServiceInfo {
+    /// Unit name (`sshd.service`) or service key name.
+    pub name: String,
+    pub description: String,
+    /// `active`/`inactive`/`failed`/... on Linux, `running`/`stopped`/...
+    /// on Windows.
+    pub state: String,
+    /// Finer state such as `running`, `exited` or `dead`; empty on Windows.
+    pub sub_state: String,
+    pub main_pid: Option<u32>,
+    /// Memory of the whole unit as systemd accounts it, when known.
+    pub memory: Option<u64>,
+    /// `enabled`/`disabled`/`static`/... or the Windows start type.
+    pub start_mode: String,
+}
+impl ServiceInfo {
+    pub fn is_running(&self) -> bool {
+        self.state == "active" || self.state == "running"
+    }
+    pub fn is_failed(&self) -> bool {
+        self.state == "failed"
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ServiceStateFilter {
+    All,
+    Running,
+    Stopped,
+    Failed,
+}
+pub struct ServicesState {
+    pub services: Vec<ServiceInfo>,
+    pub loader: Option<std::sync::mpsc::Receiver<Result<Vec<ServiceInfo>, String>>>,
+    /// A listing has completed, even if it was empty or failed.
+    pub loaded: bool,
+    pub error_msg: Option<String>,
+    /// List systemd user units instead of system units.
+    pub user_units: bool,
+    pub state_filter: ServiceStateFilter,
+    /// Services with an action still running, by name.
+    pub busy: HashSet<String>,
+    /// A Stop or Disable waiting for the user to confirm it.
+    pub pending_confirmation: Option<(String, ServiceAction)>,
+    pub action_sender: std::sync::mpsc::Sender<(String, ServiceAction, Result<(), String>)>,
+    pub action_results: std::sync::mpsc::Receiver<(String, ServiceAction, Result<(), String>)>,
+}
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AutostartSource {
+    /// A `.desktop` file in an XDG `autostart` directory.
+    XdgAutostart,
+    /// A systemd user unit enabled for the login session.
+    SystemdUser,
+    /// A value under a `...\CurrentVersion\Run` registry key.
+    RunKey { machine: bool },
+    /// A file in a Startup folder.
+    StartupFolder { machine: bool },
+}
+impl AutostartSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AutostartSource::XdgAutostart => "XDG autostart",
+            AutostartSource::SystemdUser => "systemd user unit",
+            AutostartSource::RunKey { machine: false } => "Run key (user)",
+            AutostartSource::RunKey { machine: true } => "Run key (machine)",
+            AutostartSource::StartupFolder { machine: false } => "Startup folder (user)",
+            AutostartSource::StartupFolder { machine: true } => "Startup folder (all users)",
+        }
+    }
+}
+/// Something started at login.
+#[derive(Clone, Debug)]
+pub struct AutostartEntry {
+    pub name: String,
+    /// The command line that runs, with the program first.
+    pub command: String,
+    /// File, unit or registry value the entry is stored in.
+    pub location: String,
+    pub source: AutostartSource,
+    pub enabled: bool,
+    /// Main PID reported by the service manager, when it has one.
+    pub main_pid: Option<u32>,
+}
+impl AutostartEntry {
+    /// The program part of `command`, without quotes or arguments.
+    pub fn program(&self) -> String {
+        let command = self.command.trim();
+        match command.strip_prefix('"') {
+            Some(rest) => rest.split('"').next().unwrap_or("").to_string(),
+            None => command.split_whitespace().next().unwrap_or("").to_string(),
+        }
+    }
+}
+pub struct AutostartState {
+    pub entries: Vec<AutostartEntry>,
+    pub loader: Option<std::sync::mpsc::Receiver<(Vec<AutostartEntry>, Vec<String>)>>,
+    /// A scan has completed, even if it found nothing.
+    pub loaded: bool,
+    /// Sources that could not be read on the last scan.
+    pub errors: Vec<String>,
+    /// Index into `custom_programs` picked for "Add at login".
+    pub add_program: Option<usize>,
+}
+pub struct ProcessIconRequest {
+    pub key: String,
+    pub name: String,
+    pub exe_path: Option<String>,
+}
+/// Icons for processes, looked up on a worker thread and cached as
+/// textures. Keys are executable paths, or process names when the path is
+/// unknown; `None` means no icon was found and the emoji is used.
+pub struct ProcessIconCache {
+    pub textures: std::collections::HashMap<String, Option<egui::TextureHandle>>,
+    pub pending: HashSet<String>,
+    pub requests: std::sync::mpsc::Sender<ProcessIconRequest>,
+    pub results: std::sync::mpsc::Receiver<(String, Option<egui::ColorImage>)>,
+}
+impl ProcessIconCache {
+    pub const ICON_SIZE: u32 = 32;
+    pub fn start() -> Self {
+        let (requests, request_receiver) = std::sync::mpsc::channel();
+        let (result_sender, results) = std::sync::mpsc::channel();
+        std::thread::spawn(move || Self::run_worker(request_receiver, result_sender));
+        ProcessIconCache {
+            textures: std::collections::HashMap::new(),
+            pending: HashSet::new(),
+            requests,
+            results,
+        }
+    }
+    pub fn key(name: &str, exe_path: Option<&str>) -> String {
+        exe_path.map_or_else(|| name.to_lowercase(), str::to_string)
+    }
+    /// The cached icon, or `None` while it is being looked up or when the
+    /// program has none.
+    pub fn get(&mut self, name: &str, exe_path: Option<&str>) -> Option<egui::TextureHandle> {
+        let key = Self::key(name, exe_path);
+        if let Some(texture) = self.textures.get(&key) {
+            return texture.clone();
+        }
+        if self.pending.insert(key.clone()) {
+            let _ = self
+                .requests
+                .send(ProcessIconRequest {
+                    key,
+                    name: name.to_string(),
+                    exe_path: exe_path.map(str::to_string),
+                });
+        }
+        None
+    }
+    fn run_worker(
+        requests: std::sync::mpsc::Receiver<ProcessIconRequest>,
+        results: std::sync::mpsc::Sender<(String, Option<egui::ColorImage>)>,
+    ) {
+        #[cfg(target_os = "linux")]
+        let mut desktop_icons = None;
+        while let Ok(request) = requests.recv() {
+            #[cfg(windows)]
+            let image = request
+                .exe_path
+                .as_deref()
+                .and_then(crate::ws::extract_executable_icon);
+            #[cfg(target_os = "linux")]
+            let image = {
+                let index = desktop_icons.get_or_insert_with(crate::ws::desktop_icon_index);
+                let data_dirs = crate::ws::xdg_data_directories();
+                let exe_name = request
+                    .exe_path
+                    .as_deref()
+                    .and_then(|path| std::path::Path::new(path).file_name())
+                    .map(|name| name.to_string_lossy().to_lowercase());
+                exe_name
+                    .into_iter()
+                    .chain(std::iter::once(request.name.to_lowercase()))
+                    .find_map(|candidate| {
+                        index
+                            .get(&candidate)
+                            .cloned()
+                            .or_else(|| crate::ws::resolve_desktop_icon(&candidate, &data_dirs))
+                    })
+                    .and_then(|path| crate::ws::load_icon_image(&path, Self::ICON_SIZE))
+            };
+            #[cfg(not(any(windows, target_os = "linux")))]
+            let image = None;
+            if results.send((request.key, image)).is_err() {
+                break;
+            }
+        }
+    }
+}
+impl GroupLaunch {
+    pub fn is_finished(&self) -> bool {
+        self.failure.is_some() || self.step >= self.steps.len()
+    }
 }
 pub struct FontPickerState {
     pub is_open: bool,
@@ -163,78 +1056,2125 @@ pub struct FontPickerState {
     pub preview_text: String,
     pub error_msg: Option<String>,
 }
-#[derive(Clone, Debug)]
-pub struct FilepaneTab {
-    pub name: String,
-    pub left_path: String,
-    pub right_path: String,
-    pub selected_left: Vec<String>,
-    pub selected_right: Vec<String>,
-    pub filter: String,
-    pub left_checkboxes: HashSet<String>,
-    pub right_checkboxes: HashSet<String>,
-    pub show_checkboxes: bool,
-    pub command_history: Vec<FilepaneCommand>,
-    pub undo_stack: Vec<FilepaneCommand>,
-    pub redo_stack: Vec<FilepaneCommand>,
-    pub copy_speed_limit_mb_per_sec: f64,
-    pub checksum_algorithm: ChecksumAlgorithm,
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum FileViewerMode {
+    Text,
+    Hex,
+    Image,
 }
-impl FilepaneTab {
-    pub fn new(name: String, left_path: String, right_path: String) -> Self {
-        Self {
-            name,
-            left_path,
-            right_path,
-            selected_left: Vec::new(),
-            selected_right: Vec::new(),
-            filter: String::new(),
-            left_checkboxes: HashSet::new(),
-            right_checkboxes: HashSet::new(),
-            show_checkboxes: false,
-            command_history: Vec::new(),
-            undo_stack: Vec::new(),
-            redo_stack: Vec::new(),
-            copy_speed_limit_mb_per_sec: 10.0,
-            checksum_algorithm: ChecksumAlgorithm::SHA256,
+impl FileViewerMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FileViewerMode::Text => "Text",
+            FileViewerMode::Hex => "Hex",
+            FileViewerMode::Image => "Image",
         }
     }
 }
-#[derive(Debug)]
-pub enum ContextAction {
-    NavigateToDirectory { path: String, panel_index: usize },
-    OpenFile { path: String },
-    CopyFile { source: String, destination: String, speed_limit: f64 },
-    MoveFile { source: String, destination: String },
-    DeleteFile { path: String },
-    Cut,
-    ShowProperties { file_info: FileInfo },
-    LogMessage(String),
-    CloseMenu,
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum TextEncoding {
+    Utf8,
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+    Latin1,
 }
-
-#[derive(Clone, Debug)]
-pub struct FileOperation {
-    pub operation_type: FileOperationType,
-    pub source_path: String,
-    pub destination_path: Option<String>,
-    pub original_path: Option<String>, // For undo/restore
-    pub timestamp: std::time::SystemTime,
+impl TextEncoding {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TextEncoding::Utf8 => "UTF-8",
+            TextEncoding::Utf8Bom => "UTF-8 (BOM)",
+            TextEncoding::Utf16Le => "UTF-16 LE",
+            TextEncoding::Utf16Be => "UTF-16 BE",
+            TextEncoding::Latin1 => "Latin-1",
+        }
+    }
+    /// The byte order mark files in this encoding may start with. It is
+    /// only skipped when actually present, since the encoding can be
+    /// picked by hand.
+    pub fn bom(&self) -> &'static [u8] {
+        match self {
+            TextEncoding::Utf8Bom => &[0xEF, 0xBB, 0xBF],
+            TextEncoding::Utf16Le => &[0xFF, 0xFE],
+            TextEncoding::Utf16Be => &[0xFE, 0xFF],
+            TextEncoding::Utf8 | TextEncoding::Latin1 => &[],
+        }
+    }
+}
+pub struct FileViewerState {
+    pub is_open: bool,
+    pub path: String,
+    pub file_size: u64,
+    pub mode: FileViewerMode,
+    pub encoding: TextEncoding,
+    pub text_page: usize,
+    pub text_page_offsets: Vec<u64>,
+    pub text_lines: Vec<String>,
+    pub text_reached_eof: bool,
+    /// Background scan for page offsets, started when a page further than
+    /// the next unknown one is requested.
+    pub text_indexer: Option<std::sync::mpsc::Receiver<FileViewerIndexMessage>>,
+    /// Page waiting for the indexer to reach it.
+    pub pending_page: Option<usize>,
+    pub goto_line_input: String,
+    pub highlight_line: Option<usize>,
+    pub hex_offset: u64,
+    pub hex_bytes: Vec<u8>,
+    pub hex_offset_input: String,
+    pub image_texture: Option<egui::TextureHandle>,
+    pub error_msg: Option<String>,
+}
+pub enum FileViewerIndexMessage {
+    /// Offsets of pages `first..first + offsets.len()`.
+    Pages { first: usize, offsets: Vec<u64> },
+    /// The end of the file was reached; all page offsets have been sent.
+    Finished,
+    Failed(String),
 }
-
 #[derive(Clone, Debug)]
-pub enum FileOperationType {
+pub struct FileSearchResult {
+    pub path: String,
+    pub name: String,
+    pub size: u64,
+    pub modified: std::time::SystemTime,
+    pub is_directory: bool,
+    pub matched_line: Option<(usize, String)>,
+}
+/// What to do with a search result, picked from its row.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FileSearchAction {
+    Jump,
+    View,
+    CopyPath,
     Copy,
-    Move,
     Delete,
-    Create,
-    Rename,
 }
-
+pub enum FileSearchMessage {
+    Found(FileSearchResult),
+    Progress { scanned_dirs: usize, scanned_files: usize },
+    Finished { cancelled: bool },
+}
+pub struct FileSearchState {
+    pub is_open: bool,
+    pub root_path: String,
+    pub source_panel: usize,
+    pub name_pattern: String,
+    pub use_regex: bool,
+    pub case_sensitive: bool,
+    pub content_pattern: String,
+    pub min_size: String,
+    pub max_size: String,
+    pub modified_after: String,
+    pub modified_before: String,
+    pub include_directories: bool,
+    pub results: Vec<FileSearchResult>,
+    pub scanned_dirs: usize,
+    pub scanned_files: usize,
+    pub is_running: bool,
+    pub started_at: Option<std::time::Instant>,
+    pub receiver: Option<std::sync::mpsc::Receiver<FileSearchMessage>>,
+    pub cancel_flag: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+    pub selected_result: Option<usize>,
+    pub error_msg: Option<String>,
+}
 #[derive(Clone, Debug)]
-pub struct TrashItem {
-    pub original_path: String,
-    pub trash_path: String,
-    pub deletion_time: std::time::SystemTime,
-    pub file_type: FileOperationType,
+pub struct DiskUsageNode {
+    pub name: String,
+    pub path: String,
+    pub size: u64,
+    pub file_count: u64,
+    pub children: Vec<DiskUsageNode>,
+}
+#[derive(Clone, Debug)]
+pub struct DiskUsageReport {
+    pub root: DiskUsageNode,
+    pub largest_files: Vec<(String, u64)>,
+    pub unreadable_entries: usize,
+    pub elapsed: std::time::Duration,
+}
+pub enum DiskUsageMessage {
+    Progress { scanned_files: u64, scanned_bytes: u64 },
+    Finished(Result<DiskUsageReport, String>),
+}
+pub struct DiskUsageState {
+    pub is_open: bool,
+    pub root_path: String,
+    pub source_panel: usize,
+    pub is_running: bool,
+    pub receiver: Option<std::sync::mpsc::Receiver<DiskUsageMessage>>,
+    pub cancel_flag: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+    pub report: Option<DiskUsageReport>,
+    pub focus: Vec<usize>,
+    pub top_n: usize,
+    pub scanned_files: u64,
+    pub scanned_bytes: u64,
+    pub error_msg: Option<String>,
+}
+#[derive(Clone, Debug)]
+pub struct DuplicateFile {
+    pub path: String,
+    pub modified: std::time::SystemTime,
+}
+#[derive(Clone, Debug)]
+pub struct DuplicateGroup {
+    pub size: u64,
+    pub hash: String,
+    pub files: Vec<DuplicateFile>,
+    pub keep_index: usize,
+}
+impl DuplicateGroup {
+    pub fn reclaimable(&self) -> u64 {
+        self.size * self.files.len().saturating_sub(1) as u64
+    }
+}
+/// What to do with the copies of a duplicate group that are not kept.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DuplicateStrategy {
+    Trash,
+    Hardlink,
+    /// Keep the most recently modified copy and trash the rest.
+    KeepNewest,
+}
+pub enum DuplicateScanMessage {
+    Progress { stage: String, done: u64, total: u64 },
+    Finished(Result<Vec<DuplicateGroup>, String>),
+}
+pub struct DuplicateFinderState {
+    pub is_open: bool,
+    pub scan_left: bool,
+    pub scan_right: bool,
+    pub recursive: bool,
+    pub min_size: String,
+    pub algorithm: ChecksumAlgorithm,
+    pub is_running: bool,
+    pub receiver: Option<std::sync::mpsc::Receiver<DuplicateScanMessage>>,
+    pub cancel_flag: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+    pub groups: Vec<DuplicateGroup>,
+    pub stage: String,
+    pub progress_done: u64,
+    pub progress_total: u64,
+    pub error_msg: Option<String>,
+}
+/// Editable attributes of a single file, as shown in the properties editor.
+/// Owner and group hold user/group names, or numeric ids when unresolvable.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FileProperties {
+    pub mode: u32,
+    pub owner: String,
+    pub group: String,
+    pub accessed: String,
+    pub modified: String,
+    pub symlink_target: Option<String>,
+    pub readonly: bool,
+    pub hidden: bool,
+}
+pub struct PropertiesEditorState {
+    pub is_open: bool,
+    pub path: String,
+    pub is_directory: bool,
+    pub size: u64,
+    pub original: FileProperties,
+    pub edited: FileProperties,
+    pub mode_text: String,
+    pub recursive: bool,
+    pub error_msg: Option<String>,
+}
+/// Cached Filepane directory listings kept fresh by filesystem notifications
+/// (inotify on Linux, ReadDirectoryChangesW on Windows).
+pub struct DirectoryWatchState {
+    pub watcher: Option<notify::RecommendedWatcher>,
+    pub receiver: Option<std::sync::mpsc::Receiver<notify::Result<notify::Event>>>,
+    pub watched: HashSet<std::path::PathBuf>,
+    /// Directories with unprocessed events and when the latest one arrived.
+    pub pending: std::collections::HashMap<std::path::PathBuf, std::time::Instant>,
+    /// When each directory was read, its entries, and its parent for the
+    /// ".." row, stat-ed once with the listing rather than every frame.
+    pub listings: std::collections::HashMap<
+        std::path::PathBuf,
+        (std::time::Instant, Vec<FileInfo>, Option<FileInfo>),
+    >,
+    /// Remote directories that could not be listed, kept until refreshed.
+    pub failures: std::collections::HashMap<std::path::PathBuf, String>,
+    /// Directories whose watch could not be registered, and when; they
+    /// are not retried until `WATCH_RETRY` has passed.
+    pub unwatchable: std::collections::HashMap<std::path::PathBuf, std::time::Instant>,
+    /// When creating the watcher itself last failed.
+    pub watcher_failed_at: Option<std::time::Instant>,
+    /// The panel paths of every tab when the watches were last synced;
+    /// `None` forces a sync on the next frame.
+    pub synced_paths: Option<Vec<String>>,
+    pub error_msg: Option<String>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FilepaneKeyAction {
+    MoveUp,
+    MoveDown,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Open,
+    Parent,
+    SwitchPanel,
+    ToggleMark,
+    View,
+    Copy,
+    Move,
+    MakeDirectory,
+    Delete,
+    Refresh,
+    Back,
+    Forward,
+    QuickJump,
+    ToggleBookmark,
+    ShowHelp,
+}
+impl FilepaneKeyAction {
+    pub const ALL: [FilepaneKeyAction; 21] = [
+        FilepaneKeyAction::MoveUp,
+        FilepaneKeyAction::MoveDown,
+        FilepaneKeyAction::PageUp,
+        FilepaneKeyAction::PageDown,
+        FilepaneKeyAction::Home,
+        FilepaneKeyAction::End,
+        FilepaneKeyAction::Open,
+        FilepaneKeyAction::Parent,
+        FilepaneKeyAction::SwitchPanel,
+        FilepaneKeyAction::ToggleMark,
+        FilepaneKeyAction::View,
+        FilepaneKeyAction::Copy,
+        FilepaneKeyAction::Move,
+        FilepaneKeyAction::MakeDirectory,
+        FilepaneKeyAction::Delete,
+        FilepaneKeyAction::Refresh,
+        FilepaneKeyAction::Back,
+        FilepaneKeyAction::Forward,
+        FilepaneKeyAction::QuickJump,
+        FilepaneKeyAction::ToggleBookmark,
+        FilepaneKeyAction::ShowHelp,
+    ];
+    /// Key used for this action in the `[Keymap]` config section.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FilepaneKeyAction::MoveUp => "MoveUp",
+            FilepaneKeyAction::MoveDown => "MoveDown",
+            FilepaneKeyAction::PageUp => "PageUp",
+            FilepaneKeyAction::PageDown => "PageDown",
+            FilepaneKeyAction::Home => "Home",
+            FilepaneKeyAction::End => "End",
+            FilepaneKeyAction::Open => "Open",
+            FilepaneKeyAction::Parent => "Parent",
+            FilepaneKeyAction::SwitchPanel => "SwitchPanel",
+            FilepaneKeyAction::ToggleMark => "ToggleMark",
+            FilepaneKeyAction::View => "View",
+            FilepaneKeyAction::Copy => "Copy",
+            FilepaneKeyAction::Move => "Move",
+            FilepaneKeyAction::MakeDirectory => "MakeDirectory",
+            FilepaneKeyAction::Delete => "Delete",
+            FilepaneKeyAction::Refresh => "Refresh",
+            FilepaneKeyAction::Back => "Back",
+            FilepaneKeyAction::Forward => "Forward",
+            FilepaneKeyAction::QuickJump => "QuickJump",
+            FilepaneKeyAction::ToggleBookmark => "ToggleBookmark",
+            FilepaneKeyAction::ShowHelp => "ShowHelp",
+        }
+    }
+    pub fn description(&self) -> &'static str {
+        match self {
+            FilepaneKeyAction::MoveUp => "Move cursor up",
+            FilepaneKeyAction::MoveDown => "Move cursor down",
+            FilepaneKeyAction::PageUp => "Move cursor one page up",
+            FilepaneKeyAction::PageDown => "Move cursor one page down",
+            FilepaneKeyAction::Home => "Jump to first entry",
+            FilepaneKeyAction::End => "Jump to last entry",
+            FilepaneKeyAction::Open => "Open directory or file",
+            FilepaneKeyAction::Parent => "Go to parent directory",
+            FilepaneKeyAction::SwitchPanel => "Switch active panel",
+            FilepaneKeyAction::ToggleMark => "Toggle checkbox and move down",
+            FilepaneKeyAction::View => "View file",
+            FilepaneKeyAction::Copy => "Copy to other panel",
+            FilepaneKeyAction::Move => "Move to other panel",
+            FilepaneKeyAction::MakeDirectory => "Create directory",
+            FilepaneKeyAction::Delete => "Delete (to trash)",
+            FilepaneKeyAction::Refresh => "Re-read both panels",
+            FilepaneKeyAction::Back => "Go back in panel history",
+            FilepaneKeyAction::Forward => "Go forward in panel history",
+            FilepaneKeyAction::QuickJump => "Fuzzy jump to a directory",
+            FilepaneKeyAction::ToggleBookmark => "Bookmark current directory",
+            FilepaneKeyAction::ShowHelp => "Show this help",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|action| action.name().eq_ignore_ascii_case(name))
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KeyBinding {
+    pub key: egui::Key,
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+impl KeyBinding {
+    pub const fn plain(key: egui::Key) -> Self {
+        Self { key, ctrl: false, shift: false, alt: false }
+    }
+    pub const fn ctrl(key: egui::Key) -> Self {
+        Self { key, ctrl: true, shift: false, alt: false }
+    }
+    /// Parses bindings such as `F5`, `Ctrl+R` or `Shift+Alt+Delete`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut binding = Self::plain(egui::Key::Escape);
+        let mut key = None;
+        for part in text.split('+').map(str::trim) {
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" | "cmd" => binding.ctrl = true,
+                "shift" => binding.shift = true,
+                "alt" => binding.alt = true,
+                _ => key = Some(egui::Key::from_name(part)?),
+            }
+        }
+        binding.key = key?;
+        Some(binding)
+    }
+    pub fn label(&self) -> String {
+        let mut label = String::new();
+        if self.ctrl {
+            label.push_str("Ctrl+");
+        }
+        if self.shift {
+            label.push_str("Shift+");
+        }
+        if self.alt {
+            label.push_str("Alt+");
+        }
+        label.push_str(self.key.name());
+        label
+    }
+    pub fn matches(&self, key: egui::Key, modifiers: egui::Modifiers) -> bool {
+        self.key == key && self.ctrl == modifiers.command && self.shift == modifiers.shift
+            && self.alt == modifiers.alt
+    }
+}
+/// Filepane key bindings, loaded from the `[Keymap]` config section as
+/// `Action=Binding, Binding`.
+#[derive(Clone, Debug)]
+pub struct FilepaneKeymap {
+    pub bindings: Vec<(FilepaneKeyAction, Vec<KeyBinding>)>,
+}
+impl FilepaneKeymap {
+    pub fn action_for(&self, key: egui::Key, modifiers: egui::Modifiers) -> Option<FilepaneKeyAction> {
+        self.bindings
+            .iter()
+            .find(|(_, bindings)| bindings.iter().any(|b| b.matches(key, modifiers)))
+            .map(|(action, _)| *action)
+    }
+    pub fn bindings_for(&self, action: FilepaneKeyAction) -> &[KeyBinding] {
+        self.bindings
+            .iter()
+            .find(|(a, _)| *a == action)
+            .map(|(_, bindings)| bindings.as_slice())
+            .unwrap_or(&[])
+    }
+    /// Applies one config line; unknown actions or keys are rejected so a
+    /// typo cannot silently unbind an action.
+    pub fn set_from_config(&mut self, action: &str, value: &str) -> Result<(), String> {
+        let action = FilepaneKeyAction::from_name(action)
+            .ok_or_else(|| format!("unknown action '{}'", action))?;
+        let bindings = value
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(|part| KeyBinding::parse(part).ok_or_else(|| format!("unknown key '{}'", part)))
+            .collect::<Result<Vec<_>, _>>()?;
+        match self.bindings.iter_mut().find(|(a, _)| *a == action) {
+            Some((_, existing)) => *existing = bindings,
+            None => self.bindings.push((action, bindings)),
+        }
+        Ok(())
+    }
+    pub fn to_config(&self) -> String {
+        let mut content = String::new();
+        for (action, bindings) in &self.bindings {
+            let keys: Vec<String> = bindings.iter().map(|b| b.label()).collect();
+            content.push_str(&format!("{}={}\n", action.name(), keys.join(", ")));
+        }
+        content
+    }
+}
+pub struct FilepaneKeyboardState {
+    /// Panel receiving keyboard commands: 0 = left path, 1 = right path.
+    pub active_panel: usize,
+    pub cursor: [Option<String>; 2],
+    /// Entries as last drawn, in display order, for cursor movement.
+    pub visible: [Vec<FileInfo>; 2],
+    pub page_rows: usize,
+    pub scroll_to_cursor: bool,
+    pub pending_actions: Vec<FilepaneKeyAction>,
+    pub pending_text: String,
+    pub jump_buffer: String,
+    pub last_jump: Option<std::time::Instant>,
+    pub show_help: bool,
+    pub mkdir_name: Option<String>,
+}
+#[derive(Clone, Debug)]
+pub struct FilepaneTab {
+    pub name: String,
+    pub left_path: String,
+    pub right_path: String,
+    pub selected_left: Vec<String>,
+    pub selected_right: Vec<String>,
+    pub filter: String,
+    pub left_checkboxes: HashSet<String>,
+    pub right_checkboxes: HashSet<String>,
+    pub show_checkboxes: bool,
+    pub command_history: Vec<FilepaneCommand>,
+    pub undo_stack: Vec<FilepaneCommand>,
+    pub redo_stack: Vec<FilepaneCommand>,
+    pub copy_speed_limit_mb_per_sec: f64,
+    pub checksum_algorithm: ChecksumAlgorithm,
+    pub left_history: PanelHistory,
+    pub right_history: PanelHistory,
+}
+impl FilepaneTab {
+    pub fn new(name: String, left_path: String, right_path: String) -> Self {
+        Self {
+            name,
+            left_history: PanelHistory::new(&left_path),
+            right_history: PanelHistory::new(&right_path),
+            left_path,
+            right_path,
+            selected_left: Vec::new(),
+            selected_right: Vec::new(),
+            filter: String::new(),
+            left_checkboxes: HashSet::new(),
+            right_checkboxes: HashSet::new(),
+            show_checkboxes: false,
+            command_history: Vec::new(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            copy_speed_limit_mb_per_sec: 10.0,
+            checksum_algorithm: ChecksumAlgorithm::SHA256,
+        }
+    }
+    pub fn history(&mut self, panel: usize) -> &mut PanelHistory {
+        if panel == 0 { &mut self.left_history } else { &mut self.right_history }
+    }
+}
+/// Back/forward stacks of one Filepane panel; `current` is the last path
+/// recorded, so a differing panel path means the user navigated.
+#[derive(Clone, Debug, Default)]
+pub struct PanelHistory {
+    pub back: Vec<String>,
+    pub forward: Vec<String>,
+    pub current: String,
+}
+impl PanelHistory {
+    pub fn new(path: &str) -> Self {
+        Self {
+            back: Vec::new(),
+            forward: Vec::new(),
+            current: path.to_string(),
+        }
+    }
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct FilepaneBookmark {
+    pub name: String,
+    pub path: String,
+}
+impl FilepaneBookmark {
+    /// `name=path`, with `%` and `=` in the name written as `%25` and `%3D`
+    /// so the first `=` always ends the name.
+    pub fn to_config_line(&self) -> String {
+        format!("{}={}", self.name.replace('%', "%25").replace('=', "%3D"), self.path)
+    }
+    pub fn from_config_line(line: &str) -> Option<FilepaneBookmark> {
+        let (name, path) = line.split_once('=')?;
+        Some(FilepaneBookmark {
+            name: name.trim().replace("%3D", "=").replace("%25", "%"),
+            path: path.trim().to_string(),
+        })
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuickJumpSource {
+    Bookmark,
+    History,
+    Recent,
+}
+impl QuickJumpSource {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            QuickJumpSource::Bookmark => "⭐",
+            QuickJumpSource::History => "🕘",
+            QuickJumpSource::Recent => "📂",
+        }
+    }
+}
+pub struct QuickJumpState {
+    pub is_open: bool,
+    pub query: String,
+    pub selected: usize,
+}
+/// Filesystem operations Filepane panels and file commands rely on. Paths are
+/// panel paths: plain local paths, or `sftp://user@host:port/path` for a
+/// remote panel, so the same `FilepaneCommand`s work on either side.
+pub trait FileSystemBackend: Send + Sync {
+    /// Short name for logs and panel headers.
+    fn label(&self) -> String;
+    fn read_dir(&self, path: &str) -> std::io::Result<Vec<FileInfo>>;
+    /// Entry details without following a final symlink; `is_directory` and
+    /// `size` describe the link target when it exists.
+    fn stat(&self, path: &str) -> std::io::Result<FileInfo>;
+    fn create_dir_all(&self, path: &str) -> std::io::Result<()>;
+    fn rename(&self, from: &str, to: &str) -> std::io::Result<()>;
+    fn remove_file(&self, path: &str) -> std::io::Result<()>;
+    fn remove_dir(&self, path: &str) -> std::io::Result<()>;
+    fn read_link(&self, path: &str) -> std::io::Result<String>;
+    /// Links `link_path` to `target`. An existing `link_path` fails with
+    /// `AlreadyExists` unless `replace` is set, in which case it is
+    /// swapped for the link only once the new link is in place.
+    fn hard_link(&self, target: &str, link_path: &str, replace: bool) -> std::io::Result<()>;
+    /// Fails with `AlreadyExists` if `link_path` exists.
+    fn symlink(&self, target: &str, link_path: &str) -> std::io::Result<()>;
+    fn open_read(&self, path: &str) -> std::io::Result<Box<dyn std::io::Read + '_>>;
+    fn create_file(&self, path: &str) -> std::io::Result<Box<dyn std::io::Write + '_>>;
+}
+/// The machine's own disks, through `std::fs`.
+pub struct LocalBackend;
+/// Server half of an `sftp://user@host:port/path` panel path.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SftpTarget {
+    pub user: String,
+    pub host: String,
+    pub port: u16,
+}
+impl SftpTarget {
+    /// Splits a panel path into its server and remote path, or `None` for a
+    /// local path. Backslashes from Windows path joins are read as `/`.
+    pub fn parse(path: &str) -> Option<(SftpTarget, String)> {
+        let rest = path.strip_prefix("sftp://")?.replace('\\', "/");
+        let (authority, remote) = match rest.find('/') {
+            Some(index) => (&rest[..index], rest[index..].to_string()),
+            None => (rest.as_str(), "/".to_string()),
+        };
+        let (user, host_port) = authority.rsplit_once('@')?;
+        let (host, port) = match host_port.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().ok()?),
+            None => (host_port, 22),
+        };
+        if user.is_empty() || host.is_empty() {
+            return None;
+        }
+        let remote = match remote.trim_end_matches('/') {
+            "" => "/".to_string(),
+            trimmed => trimmed.to_string(),
+        };
+        Some((
+            SftpTarget {
+                user: user.to_string(),
+                host: host.to_string(),
+                port,
+            },
+            remote,
+        ))
+    }
+    /// Identifies the connection serving this target.
+    pub fn key(&self) -> String {
+        format!("{}@{}:{}", self.user, self.host, self.port)
+    }
+    pub fn uri(&self, remote_path: &str) -> String {
+        format!("sftp://{}{}", self.key(), remote_path)
+    }
+}
+/// How to log in to an SFTP server; an empty key path and password fall
+/// back to the running ssh-agent.
+#[derive(Clone, Debug, Default)]
+pub struct SftpCredentials {
+    pub password: String,
+    pub key_path: String,
+    /// Trust a host key missing from `~/.ssh/known_hosts` and record it.
+    pub accept_new_host_key: bool,
+}
+/// An authenticated SFTP session serving one `SftpTarget`.
+pub struct SftpBackend {
+    pub target: SftpTarget,
+    pub session: ssh2::Session,
+    pub sftp: ssh2::Sftp,
+    /// Remote directory the server starts the user in.
+    pub home: String,
+}
+impl SftpBackend {
+    pub fn connect(target: SftpTarget, credentials: &SftpCredentials) -> Result<Self, String> {
+        use std::net::ToSocketAddrs;
+        let address = (target.host.as_str(), target.port)
+            .to_socket_addrs()
+            .map_err(|e| format!("Cannot resolve {}: {}", target.host, e))?
+            .next()
+            .ok_or_else(|| format!("Cannot resolve {}", target.host))?;
+        let stream = std::net::TcpStream::connect_timeout(
+                &address,
+                std::time::Duration::from_secs(10),
+            )
+            .map_err(|e| format!("Cannot connect to {}: {}", address, e))?;
+        let mut session = ssh2::Session::new().map_err(|e| e.to_string())?;
+        session.set_tcp_stream(stream);
+        session.set_timeout(15_000);
+        session.handshake().map_err(|e| format!("SSH handshake failed: {}", e))?;
+        Self::check_host_key(&session, &target, credentials.accept_new_host_key)?;
+        let result = if !credentials.key_path.is_empty() {
+            let passphrase = Some(credentials.password.as_str()).filter(|p| !p.is_empty());
+            let key_path = match credentials.key_path.strip_prefix("~/") {
+                Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+                None => std::path::PathBuf::from(&credentials.key_path),
+            };
+            session.userauth_pubkey_file(&target.user, None, &key_path, passphrase)
+        } else if !credentials.password.is_empty() {
+            session.userauth_password(&target.user, &credentials.password)
+        } else {
+            session.userauth_agent(&target.user)
+        };
+        result.map_err(|e| format!("Authentication failed: {}", e))?;
+        if !session.authenticated() {
+            return Err("Authentication failed".to_string());
+        }
+        let sftp = session.sftp().map_err(|e| format!("SFTP subsystem unavailable: {}", e))?;
+        let home = sftp
+            .realpath(std::path::Path::new("."))
+            .map(|path| path.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_else(|_| "/".to_string());
+        Ok(SftpBackend {
+            target,
+            session,
+            sftp,
+            home,
+        })
+    }
+    /// Refuses changed host keys; unknown ones are only accepted, and then
+    /// remembered, when the user asked for it.
+    fn check_host_key(
+        session: &ssh2::Session,
+        target: &SftpTarget,
+        accept_new: bool,
+    ) -> Result<(), String> {
+        let (key, key_type) = session
+            .host_key()
+            .ok_or_else(|| "Server sent no host key".to_string())?;
+        let fingerprint = session
+            .host_key_hash(ssh2::HashType::Sha256)
+            .map(|hash| hash.iter().map(|b| format!("{:02x}", b)).collect::<String>())
+            .unwrap_or_default();
+        let mut known_hosts = session.known_hosts().map_err(|e| e.to_string())?;
+        let known_hosts_path = dirs::home_dir()
+            .map(|home| home.join(".ssh").join("known_hosts"));
+        if let Some(path) = &known_hosts_path {
+            let _ = known_hosts.read_file(path, ssh2::KnownHostFileKind::OpenSSH);
+        }
+        match known_hosts.check_port(&target.host, target.port, key) {
+            ssh2::CheckResult::Match => Ok(()),
+            ssh2::CheckResult::Mismatch => {
+                Err(format!(
+                    "Host key for {} does not match known_hosts (SHA256 {}); refusing to connect",
+                    target.host, fingerprint
+                ))
+            }
+            ssh2::CheckResult::NotFound | ssh2::CheckResult::Failure if accept_new => {
+                let host = if target.port == 22 {
+                    target.host.clone()
+                } else {
+                    format!("[{}]:{}", target.host, target.port)
+                };
+                let _ = known_hosts.add(&host, key, "added by winshell", key_type.into());
+                if let Some(path) = &known_hosts_path {
+                    if let Some(parent) = path.parent() {
+                        let _ = std::fs::create_dir_all(parent);
+                    }
+                    let _ = known_hosts.write_file(path, ssh2::KnownHostFileKind::OpenSSH);
+                }
+                Ok(())
+            }
+            _ => {
+                Err(format!(
+                    "Unknown host key for {} (SHA256 {}); tick \"Trust new host key\" to accept it",
+                    target.host, fingerprint
+                ))
+            }
+        }
+    }
+    /// Builds a `FileInfo` from `lstat` attributes, resolving symlinks the
+    /// way `FileInfo::new` does for local entries.
+    pub(crate) fn remote_file_info(&self, remote: &str, link_stat: ssh2::FileStat) -> FileInfo {
+        let (symlink_target, stat, is_broken_link) = if link_stat.file_type().is_symlink() {
+            let target = self
+                .sftp
+                .readlink(std::path::Path::new(remote))
+                .map(|target| target.to_string_lossy().to_string())
+                .unwrap_or_default();
+            match self.sftp.stat(std::path::Path::new(remote)) {
+                Ok(stat) => (Some(target), stat, false),
+                Err(_) => (Some(target), link_stat, true),
+            }
+        } else {
+            (None, link_stat, false)
+        };
+        let name = remote.rsplit('/').next().unwrap_or("").to_string();
+        let is_directory = stat.is_dir();
+        let extension = if is_directory {
+            None
+        } else {
+            std::path::Path::new(&name)
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| ext.to_lowercase())
+        };
+        FileInfo {
+            path: self.target.uri(remote),
+            name,
+            is_directory,
+            size: if is_directory { 0 } else { stat.size.unwrap_or(0) },
+            modified: std::time::UNIX_EPOCH
+                + std::time::Duration::from_secs(stat.mtime.unwrap_or(0)),
+            extension,
+            symlink_target,
+            is_broken_link,
+            hard_links: 1,
+            is_mount_point: false,
+        }
+    }
+    pub(crate) fn join_remote(directory: &str, name: &str) -> String {
+        format!("{}/{}", directory.trim_end_matches('/'), name)
+    }
+    /// The remote part of a panel path served by this session.
+    pub fn remote_path(&self, path: &str) -> std::io::Result<String> {
+        match SftpTarget::parse(path) {
+            Some((target, remote)) if target == self.target => Ok(remote),
+            _ => {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("{} is not on {}", path, self.target.key()),
+                ))
+            }
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+impl LogLevel {
+    pub const ALL: [LogLevel; 5] = [
+        LogLevel::Error,
+        LogLevel::Warn,
+        LogLevel::Info,
+        LogLevel::Debug,
+        LogLevel::Trace,
+    ];
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Trace => "TRACE",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<LogLevel> {
+        LogLevel::ALL
+            .into_iter()
+            .find(|level| level.name().eq_ignore_ascii_case(name.trim()))
+    }
+    pub fn color(&self) -> egui::Color32 {
+        match self {
+            LogLevel::Error => egui::Color32::from_rgb(230, 80, 80),
+            LogLevel::Warn => egui::Color32::from_rgb(230, 180, 60),
+            LogLevel::Info => egui::Color32::from_rgb(120, 180, 230),
+            LogLevel::Debug | LogLevel::Trace => egui::Color32::GRAY,
+        }
+    }
+    pub fn to_filter(&self) -> log::LevelFilter {
+        match self {
+            LogLevel::Error => log::LevelFilter::Error,
+            LogLevel::Warn => log::LevelFilter::Warn,
+            LogLevel::Info => log::LevelFilter::Info,
+            LogLevel::Debug => log::LevelFilter::Debug,
+            LogLevel::Trace => log::LevelFilter::Trace,
+        }
+    }
+}
+/// One entry of the application log.
+#[derive(Clone, Debug)]
+pub struct LogRecord {
+    /// Increases with every record; stable while older records are evicted.
+    pub id: u64,
+    pub timestamp: chrono::DateTime<chrono::Local>,
+    pub level: LogLevel,
+    /// App module that logged it, or the `log` target of a library.
+    pub module: String,
+    pub message: String,
+}
+/// Records kept in memory; older ones are dropped first.
+pub const LOG_BUFFER_LIMIT: usize = 5000;
+#[derive(Clone, Debug)]
+pub struct LogSettings {
+    pub to_file: bool,
+    /// The log file is rotated once it grows past this size.
+    pub max_file_kb: u64,
+    /// Most verbose level accepted from libraries through the `log` facade.
+    pub library_level: LogLevel,
+    /// Saved history files older than this many days are deleted; 0 keeps
+    /// them forever.
+    pub history_retention_days: u64,
+}
+/// Appends records to `winshell.log`, keeping `LOG_FILE_ROTATIONS` older
+/// files as `winshell.log.1`, `.2`, ...
+pub struct LogFileSink {
+    pub path: std::path::PathBuf,
+    pub file: Option<std::fs::File>,
+    pub written: u64,
+}
+pub const LOG_FILE_ROTATIONS: usize = 3;
+impl LogFileSink {
+    pub fn new(path: std::path::PathBuf) -> Self {
+        let written = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        Self { path, file: None, written }
+    }
+    pub fn write(&mut self, record: &LogRecord, max_bytes: u64) -> std::io::Result<()> {
+        use std::io::Write;
+        if self.written >= max_bytes.max(1) {
+            self.rotate()?;
+        }
+        if self.file.is_none() {
+            self.file = Some(
+                std::fs::OpenOptions::new().create(true).append(true).open(&self.path)?,
+            );
+        }
+        let line = format!("{}\n", record);
+        if let Some(file) = self.file.as_mut() {
+            file.write_all(line.as_bytes())?;
+        }
+        self.written += line.len() as u64;
+        Ok(())
+    }
+    fn rotate(&mut self) -> std::io::Result<()> {
+        self.file = None;
+        let rotated = |index: usize| {
+            let mut name = self.path.clone().into_os_string();
+            name.push(format!(".{}", index));
+            std::path::PathBuf::from(name)
+        };
+        let _ = std::fs::remove_file(rotated(LOG_FILE_ROTATIONS));
+        for index in (1..LOG_FILE_ROTATIONS).rev() {
+            let _ = std::fs::rename(rotated(index), rotated(index + 1));
+        }
+        std::fs::rename(&self.path, rotated(1))?;
+        self.written = 0;
+        Ok(())
+    }
+}
+/// Filters and options of the Logs view.
+pub struct LogViewState {
+    pub min_level: LogLevel,
+    pub module_filter: Option<String>,
+    pub search: String,
+    pub next_id: u64,
+    pub settings: LogSettings,
+    pub file_sink: Option<LogFileSink>,
+    pub file_error: Option<String>,
+    /// Indices into `logs` that pass the filters, newest first.
+    pub visible: Vec<usize>,
+    /// (level, module, search, front record id, next id) that `visible` was
+    /// computed for; the filter is only re-run when one of them changes.
+    pub visible_key: Option<(LogLevel, Option<String>, String, u64, u64)>,
+}
+/// `log` facade backend. Library records wait here until the UI thread
+/// moves them into the app log.
+pub struct AppLogger {
+    pub inbox: std::sync::Mutex<std::collections::VecDeque<LogRecord>>,
+}
+pub static APP_LOGGER: AppLogger = AppLogger {
+    inbox: std::sync::Mutex::new(std::collections::VecDeque::new()),
+};
+/// One log line recovered from a saved history file.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct HistoryEntry {
+    /// Session timestamp of the file the line came from.
+    pub session: String,
+    pub timestamp: Option<String>,
+    pub level: Option<String>,
+    pub module: Option<String>,
+    pub message: String,
+}
+/// A `history_<timestamp>.txt` file written by `save_conversation_history`.
+#[derive(Clone, Debug)]
+pub struct HistoryFile {
+    pub path: std::path::PathBuf,
+    pub session: String,
+    pub modified: std::time::SystemTime,
+    pub entries: Vec<HistoryEntry>,
+}
+impl HistoryFile {
+    pub fn load(path: &std::path::Path) -> std::io::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let session = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.strip_prefix("history_"))
+            .unwrap_or_default()
+            .to_string();
+        let modified = std::fs::metadata(path)?.modified()?;
+        let mut entries = Vec::new();
+        let mut in_conversation = false;
+        for line in content.lines() {
+            let line = line.trim_end();
+            if line == "------------" {
+                in_conversation = true;
+                continue;
+            }
+            if line == "=== End of History ===" {
+                break;
+            }
+            if !in_conversation || line.trim().is_empty() || line.starts_with("=== ") {
+                continue;
+            }
+            entries.push(HistoryEntry::parse(&session, line));
+        }
+        Ok(HistoryFile {
+            path: path.to_path_buf(),
+            session,
+            modified,
+            entries,
+        })
+    }
+}
+impl HistoryEntry {
+    /// Reads `[001] <date> <time> LEVEL [module] message` lines, and the
+    /// older `<date> <time> - message` form; anything else is kept verbatim.
+    pub fn parse(session: &str, line: &str) -> HistoryEntry {
+        let mut entry = HistoryEntry {
+            session: session.to_string(),
+            timestamp: None,
+            level: None,
+            module: None,
+            message: line.to_string(),
+        };
+        let rest = match line.strip_prefix('[').and_then(|rest| rest.split_once("] ")) {
+            Some((number, rest)) if number.chars().all(|c| c.is_ascii_digit()) => rest,
+            _ => line,
+        };
+        let mut parts = rest.splitn(3, ' ');
+        let (Some(date), Some(time), Some(rest)) = (parts.next(), parts.next(), parts.next()) else {
+            return entry;
+        };
+        let looks_like_date = date.len() == 10 && date.as_bytes()[4] == b'-';
+        if !looks_like_date || !time.contains(':') {
+            return entry;
+        }
+        entry.timestamp = Some(format!("{} {}", date, time));
+        if let Some(message) = rest.strip_prefix("- ") {
+            entry.message = message.to_string();
+            return entry;
+        }
+        let rest = rest.trim_start();
+        let (level, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+        if LogLevel::from_name(level).is_none() {
+            entry.message = rest.to_string();
+            return entry;
+        }
+        entry.level = Some(level.to_string());
+        let rest = rest.trim_start();
+        match rest.strip_prefix('[').and_then(|rest| rest.split_once("] ")) {
+            Some((module, message)) => {
+                entry.module = Some(module.to_string());
+                entry.message = message.to_string();
+            }
+            None => entry.message = rest.to_string(),
+        }
+        entry
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HistoryExportFormat {
+    Text,
+    Json,
+    Html,
+}
+impl HistoryExportFormat {
+    pub const ALL: [HistoryExportFormat; 3] = [
+        HistoryExportFormat::Text,
+        HistoryExportFormat::Json,
+        HistoryExportFormat::Html,
+    ];
+    pub fn name(&self) -> &'static str {
+        match self {
+            HistoryExportFormat::Text => "Text",
+            HistoryExportFormat::Json => "JSON",
+            HistoryExportFormat::Html => "HTML",
+        }
+    }
+    pub fn extension(&self) -> &'static str {
+        match self {
+            HistoryExportFormat::Text => "txt",
+            HistoryExportFormat::Json => "json",
+            HistoryExportFormat::Html => "html",
+        }
+    }
+}
+/// The history file browser in the Logs view.
+pub struct HistoryBrowserState {
+    pub is_open: bool,
+    /// Newest session first.
+    pub files: Vec<HistoryFile>,
+    /// Session shown, or `None` to search all of them.
+    pub selected_file: Option<usize>,
+    pub search: String,
+    /// Selected entries as (file index, entry index).
+    pub selected: HashSet<(usize, usize)>,
+    pub export_format: HistoryExportFormat,
+    pub status: Option<String>,
+}
+/// The kind of Filepane operation an audit record describes. Stored in
+/// lowercase, e.g. `"hardlink"`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditOperation {
+    Copy,
+    Move,
+    Delete,
+    Rename,
+    Mkdir,
+    Hardlink,
+    Symlink,
+}
+impl AuditOperation {
+    pub const ALL: [AuditOperation; 7] = [
+        AuditOperation::Copy,
+        AuditOperation::Move,
+        AuditOperation::Delete,
+        AuditOperation::Rename,
+        AuditOperation::Mkdir,
+        AuditOperation::Hardlink,
+        AuditOperation::Symlink,
+    ];
+    pub fn name(&self) -> &'static str {
+        match self {
+            AuditOperation::Copy => "copy",
+            AuditOperation::Move => "move",
+            AuditOperation::Delete => "delete",
+            AuditOperation::Rename => "rename",
+            AuditOperation::Mkdir => "mkdir",
+            AuditOperation::Hardlink => "hardlink",
+            AuditOperation::Symlink => "symlink",
+        }
+    }
+    /// Whether the source exists beforehand and is worth sizing and hashing.
+    /// A new directory has no source, and a symlink target is only a name.
+    pub fn inspects_source(&self) -> bool {
+        !matches!(self, AuditOperation::Mkdir | AuditOperation::Symlink)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditOutcome {
+    Success,
+    Failure,
+}
+/// One line of the append-only Filepane audit log (JSON lines).
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AuditRecord {
+    /// RFC 3339 local time the operation started.
+    pub timestamp: String,
+    pub duration_ms: u64,
+    pub user: String,
+    pub host: String,
+    pub operation: AuditOperation,
+    pub source: String,
+    pub destination: Option<String>,
+    pub is_directory: bool,
+    /// Size of the source before the operation, for files.
+    pub size: Option<u64>,
+    /// SHA-256 of the source before the operation, for files up to
+    /// `AUDIT_HASH_LIMIT` bytes.
+    pub sha256: Option<String>,
+    pub outcome: AuditOutcome,
+    pub error: Option<String>,
+}
+/// A command on an SFTP panel, or a speed-limited copy, running on a
+/// worker thread so transfers never block the UI. The worker sizes and
+/// hashes the source first and sends the audit record back with the
+/// result and any directories it skipped.
+pub struct BackendJob {
+    pub command: FilepaneCommand,
+    /// The record as started, used if the worker never reports back.
+    pub audit: (AuditRecord, std::time::Instant),
+    /// The `CopyBatch` this job counts towards, if any.
+    pub batch: Option<u64>,
+    pub receiver: std::sync::mpsc::Receiver<(
+        AuditRecord,
+        Result<String, String>,
+        Vec<(std::path::PathBuf, TraversalSkip)>,
+    )>,
+}
+/// Copies started together from the file panes; the log gets one summary
+/// line once every job in the batch has finished.
+pub struct CopyBatch {
+    pub id: u64,
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+}
+/// Files larger than this are audited by size only. Local operations hash
+/// on the UI thread before they run, so this stays small.
+pub const AUDIT_HASH_LIMIT: u64 = 16 * 1024 * 1024;
+/// The audit log viewer in the Logs view.
+pub struct AuditLogState {
+    pub is_open: bool,
+    pub records: Vec<AuditRecord>,
+    /// Lines of the file that could not be parsed.
+    pub skipped_lines: usize,
+    pub operation_filter: Option<AuditOperation>,
+    pub path_filter: String,
+    pub error_msg: Option<String>,
+}
+/// State of the SFTP connect dialog and its background login.
+pub struct SftpConnectState {
+    pub is_open: bool,
+    /// Panel that switches to the server once connected.
+    pub panel: usize,
+    pub host: String,
+    pub port: String,
+    pub user: String,
+    pub remote_path: String,
+    pub credentials: SftpCredentials,
+    pub connecting: Option<std::sync::mpsc::Receiver<Result<SftpBackend, String>>>,
+    pub error_msg: Option<String>,
+}
+#[derive(Debug)]
+pub enum ContextAction {
+    NavigateToDirectory { path: String, panel_index: usize },
+    OpenFile { path: String },
+    ViewFile { path: String },
+    CopyFile { source: String, destination: String, speed_limit: f64 },
+    MoveFile { source: String, destination: String },
+    DeleteFile { path: String },
+    Cut,
+    ShowProperties { file_info: FileInfo },
+    LogMessage(String),
+    CloseMenu,
+}
+
+#[derive(Clone, Debug)]
+pub struct FileOperation {
+    pub operation_type: FileOperationType,
+    pub source_path: String,
+    pub destination_path: Option<String>,
+    pub original_path: Option<String>, // For undo/restore
+    pub timestamp: std::time::SystemTime,
+}
+
+#[derive(Clone, Debug)]
+pub enum FileOperationType {
+    Copy,
+    Move,
+    Delete,
+    Create,
+    Rename,
+}
+
+#[derive(Clone, Debug)]
+pub struct TrashItem {
+    pub original_path: String,
+    pub trash_path: String,
+    pub deletion_time: std::time::SystemTime,
+    pub file_type: FileOperationType,
+}
+#[cfg(target_os = "linux")]
+x11rb::atom_manager! {
+    /// Atoms used to read and drive EWMH window managers.
+    pub EwmhAtoms: EwmhAtomsCookie {
+        _NET_CLIENT_LIST,
+        _NET_ACTIVE_WINDOW,
+        _NET_WM_PID,
+        _NET_WM_NAME,
+        _NET_WM_WINDOW_TYPE,
+        _NET_WM_WINDOW_TYPE_DOCK,
+        _NET_WM_WINDOW_TYPE_DESKTOP,
+        _NET_WM_STATE,
+        _NET_WM_STATE_MAXIMIZED_VERT,
+        _NET_WM_STATE_MAXIMIZED_HORZ,
+        _NET_CLOSE_WINDOW,
+        WM_CHANGE_STATE,
+        _NET_WORKAREA,
+        _NET_MOVERESIZE_WINDOW,
+        _NET_FRAME_EXTENTS,
+        UTF8_STRING,
+    }
+}
+/// A top-level window reported by the Linux window system.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Debug)]
+pub struct LinuxWindow {
+    pub id: u64,
+    /// `None` when the window does not say which process owns it.
+    pub pid: Option<u32>,
+    pub title: String,
+    /// `WM_CLASS` on X11, the app id on Wayland.
+    pub class: String,
+    pub is_active: bool,
+}
+/// An X11 connection to an EWMH-compliant window manager.
+#[cfg(target_os = "linux")]
+pub struct X11Windows {
+    pub conn: x11rb::rust_connection::RustConnection,
+    pub root: u32,
+    pub atoms: EwmhAtoms,
+    /// Composite lets obscured and off-screen windows be captured.
+    pub has_composite: bool,
+    /// MIT-SHM 1.2 lets the server hand image data over in shared memory.
+    pub has_shm: bool,
+    /// Windows this connection has redirected for capture, until `release`.
+    pub redirected: std::sync::Mutex<HashSet<u32>>,
+}
+#[cfg(target_os = "linux")]
+impl X11Windows {
+    pub fn connect() -> Result<Self, String> {
+        use x11rb::connection::Connection;
+        use x11rb::protocol::composite::ConnectionExt as _;
+        use x11rb::protocol::shm::ConnectionExt as _;
+        let (conn, screen) = x11rb::connect(None).map_err(|e| e.to_string())?;
+        let root = conn.setup().roots[screen].root;
+        let atoms = EwmhAtoms::new(&conn)
+            .map_err(|e| e.to_string())?
+            .reply()
+            .map_err(|e| e.to_string())?;
+        let has_composite = conn
+            .composite_query_version(0, 4)
+            .ok()
+            .and_then(|cookie| cookie.reply().ok())
+            .is_some_and(|version| (version.major_version, version.minor_version) >= (0, 2));
+        let has_shm = conn
+            .shm_query_version()
+            .ok()
+            .and_then(|cookie| cookie.reply().ok())
+            .is_some_and(|version| (version.major_version, version.minor_version) >= (1, 2));
+        let windows = X11Windows {
+            conn,
+            root,
+            atoms,
+            has_composite,
+            has_shm,
+            redirected: std::sync::Mutex::new(HashSet::new()),
+        };
+        // Without a window manager there is no client list to read.
+        windows.property32(root, windows.atoms._NET_CLIENT_LIST)?.ok_or_else(|| {
+            "the X11 window manager does not publish _NET_CLIENT_LIST".to_string()
+        })?;
+        Ok(windows)
+    }
+    pub fn property32(&self, window: u32, property: u32) -> Result<Option<Vec<u32>>, String> {
+        use x11rb::protocol::xproto::{AtomEnum, ConnectionExt};
+        let reply = self
+            .conn
+            .get_property(false, window, property, AtomEnum::ANY, 0, u32::MAX)
+            .map_err(|e| e.to_string())?
+            .reply()
+            .map_err(|e| e.to_string())?;
+        Ok(reply.value32().map(|values| values.collect()))
+    }
+    fn property_string(&self, window: u32, property: u32) -> Result<Option<String>, String> {
+        use x11rb::protocol::xproto::{AtomEnum, ConnectionExt};
+        let reply = self
+            .conn
+            .get_property(false, window, property, AtomEnum::ANY, 0, u32::MAX)
+            .map_err(|e| e.to_string())?
+            .reply()
+            .map_err(|e| e.to_string())?;
+        if reply.value.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(String::from_utf8_lossy(&reply.value).trim_end_matches('\0').to_string()))
+    }
+    /// Sends an EWMH client message about `window` to the root window,
+    /// where the window manager picks it up.
+    fn send_client_message(&self, window: u32, message_type: u32, data: [u32; 5]) -> Result<(), String> {
+        use x11rb::connection::Connection;
+        use x11rb::protocol::xproto::{ClientMessageEvent, ConnectionExt, EventMask};
+        let event = ClientMessageEvent::new(32, window, message_type, data);
+        self.conn
+            .send_event(
+                false,
+                self.root,
+                EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY,
+                event,
+            )
+            .map_err(|e| e.to_string())?;
+        self.conn.flush().map_err(|e| e.to_string())
+    }
+    pub fn activate(&self, window: u32) -> Result<(), String> {
+        // Source indication 2 marks the request as coming from a pager, which
+        // window managers honour without focus-stealing checks.
+        self.send_client_message(window, self.atoms._NET_ACTIVE_WINDOW, [2, 0, 0, 0, 0])
+    }
+    /// The usable area of the monitor showing most of `window`, excluding
+    /// panels and docks. `_NET_WORKAREA` spans every monitor, so it is
+    /// clipped to the RandR monitor; without RandR 1.5 the whole screen is
+    /// one monitor.
+    pub fn work_area(&self, window: u32) -> Result<WindowRect, String> {
+        use x11rb::connection::Connection;
+        use x11rb::protocol::randr::ConnectionExt as _;
+        let screen = &self.conn.setup().roots[0];
+        let desktop = WindowRect {
+            x: 0,
+            y: 0,
+            width: screen.width_in_pixels as i32,
+            height: screen.height_in_pixels as i32,
+        };
+        let work_area = match self.property32(self.root, self.atoms._NET_WORKAREA)? {
+            Some(area) if area.len() >= 4 => {
+                WindowRect {
+                    x: area[0] as i32,
+                    y: area[1] as i32,
+                    width: area[2] as i32,
+                    height: area[3] as i32,
+                }
+            }
+            _ => desktop,
+        };
+        let monitors: Vec<(WindowRect, bool)> = self
+            .conn
+            .randr_get_monitors(self.root, true)
+            .ok()
+            .and_then(|cookie| cookie.reply().ok())
+            .map(|reply| {
+                reply
+                    .monitors
+                    .iter()
+                    .map(|monitor| {
+                        let rect = WindowRect {
+                            x: monitor.x as i32,
+                            y: monitor.y as i32,
+                            width: monitor.width as i32,
+                            height: monitor.height as i32,
+                        };
+                        (rect, monitor.primary)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let window_rect = self.outer_rect(window).ok();
+        let overlap = |monitor: &WindowRect| {
+            window_rect
+                .and_then(|rect| rect.intersect(monitor))
+                .map_or(0, |shared| shared.area())
+        };
+        let monitor = monitors
+            .iter()
+            .max_by_key(|(monitor, primary)| (overlap(monitor), *primary))
+            .map_or(desktop, |(monitor, _)| *monitor);
+        Ok(monitor.intersect(&work_area).unwrap_or(monitor))
+    }
+    /// Left, right, top and bottom decoration sizes added by the window
+    /// manager.
+    fn frame_extents(&self, window: u32) -> Result<[i32; 4], String> {
+        let extents = self.property32(window, self.atoms._NET_FRAME_EXTENTS)?.unwrap_or_default();
+        Ok(match extents.as_slice() {
+            [left, right, top, bottom, ..] => {
+                [*left as i32, *right as i32, *top as i32, *bottom as i32]
+            }
+            _ => [0; 4],
+        })
+    }
+    /// The window's rectangle on screen, decorations included.
+    pub fn outer_rect(&self, window: u32) -> Result<WindowRect, String> {
+        use x11rb::protocol::xproto::ConnectionExt;
+        let geometry = self
+            .conn
+            .get_geometry(window)
+            .map_err(|e| e.to_string())?
+            .reply()
+            .map_err(|e| e.to_string())?;
+        let origin = self
+            .conn
+            .translate_coordinates(window, self.root, 0, 0)
+            .map_err(|e| e.to_string())?
+            .reply()
+            .map_err(|e| e.to_string())?;
+        let [left, right, top, bottom] = self.frame_extents(window)?;
+        Ok(WindowRect {
+            x: origin.dst_x as i32 - left,
+            y: origin.dst_y as i32 - top,
+            width: geometry.width as i32 + left + right,
+            height: geometry.height as i32 + top + bottom,
+        })
+    }
+    /// Asks the window manager to place the window's frame at `rect`,
+    /// leaving the maximized state first so the request is honoured.
+    pub fn move_resize(&self, window: u32, rect: WindowRect) -> Result<(), String> {
+        const NET_WM_STATE_REMOVE: u32 = 0;
+        const NORTH_WEST_GRAVITY: u32 = 1;
+        const SET_POSITION_AND_SIZE: u32 = 0xF << 8;
+        const FROM_PAGER: u32 = 2 << 12;
+        let atoms = &self.atoms;
+        self.send_client_message(
+            window,
+            atoms._NET_WM_STATE,
+            [
+                NET_WM_STATE_REMOVE,
+                atoms._NET_WM_STATE_MAXIMIZED_VERT,
+                atoms._NET_WM_STATE_MAXIMIZED_HORZ,
+                2,
+                0,
+            ],
+        )?;
+        let [left, right, top, bottom] = self.frame_extents(window)?;
+        self.send_client_message(
+            window,
+            atoms._NET_MOVERESIZE_WINDOW,
+            [
+                NORTH_WEST_GRAVITY | SET_POSITION_AND_SIZE | FROM_PAGER,
+                rect.x as u32,
+                rect.y as u32,
+                (rect.width - left - right).max(1) as u32,
+                (rect.height - top - bottom).max(1) as u32,
+            ],
+        )
+    }
+    pub fn perform(&self, window: u32, action: WindowAction) -> Result<(), String> {
+        const ICONIC_STATE: u32 = 3;
+        const NET_WM_STATE_TOGGLE: u32 = 2;
+        let atoms = &self.atoms;
+        match action {
+            WindowAction::Minimize => {
+                self.send_client_message(window, atoms.WM_CHANGE_STATE, [ICONIC_STATE, 0, 0, 0, 0])
+            }
+            WindowAction::ToggleMaximize => {
+                self.send_client_message(
+                    window,
+                    atoms._NET_WM_STATE,
+                    [
+                        NET_WM_STATE_TOGGLE,
+                        atoms._NET_WM_STATE_MAXIMIZED_VERT,
+                        atoms._NET_WM_STATE_MAXIMIZED_HORZ,
+                        2,
+                        0,
+                    ],
+                )
+            }
+            WindowAction::Close => {
+                self.send_client_message(window, atoms._NET_CLOSE_WINDOW, [0, 2, 0, 0, 0])
+            }
+        }
+    }
+    /// Captures `window` scaled down to fit `max_width` x `max_height`.
+    pub fn capture(&self, window: u32, max_width: usize, max_height: usize) -> Result<egui::ColorImage, String> {
+        use x11rb::connection::Connection;
+        use x11rb::protocol::composite::{ConnectionExt as _, Redirect};
+        use x11rb::protocol::xproto::{ConnectionExt as _, ImageOrder};
+        let geometry = self
+            .conn
+            .get_geometry(window)
+            .map_err(|e| e.to_string())?
+            .reply()
+            .map_err(|e| e.to_string())?;
+        if !matches!(geometry.depth, 24 | 32) {
+            return Err(format!("unsupported window depth {}", geometry.depth));
+        }
+        let (width, height) = (geometry.width, geometry.height);
+        if width == 0 || height == 0 {
+            return Err("the window has no size".to_string());
+        }
+        // With Composite the window keeps its own backing pixmap, so its
+        // content is intact even when covered by other windows.
+        let mut pixmap = None;
+        if self.has_composite {
+            let mut redirected = self.redirected.lock().unwrap_or_else(|e| e.into_inner());
+            if !redirected.contains(&window) {
+                let done = self
+                    .conn
+                    .composite_redirect_window(window, Redirect::AUTOMATIC)
+                    .map_err(|e| e.to_string())?
+                    .check();
+                if done.is_ok() {
+                    redirected.insert(window);
+                }
+            }
+            let id = self.conn.generate_id().map_err(|e| e.to_string())?;
+            let named = self
+                .conn
+                .composite_name_window_pixmap(window, id)
+                .map_err(|e| e.to_string())?
+                .check();
+            if named.is_ok() {
+                pixmap = Some(id);
+            }
+        }
+        let image = self.read_image(pixmap.unwrap_or(window), width, height);
+        if let Some(pixmap) = pixmap {
+            let _ = self.conn.free_pixmap(pixmap);
+            let _ = self.conn.flush();
+        }
+        let mut data = image?;
+        if self.conn.setup().image_byte_order == ImageOrder::MSB_FIRST {
+            for pixel in data.chunks_exact_mut(4) {
+                pixel.reverse();
+            }
+        }
+        Ok(crate::ws::downscale_bgra(&data, width as usize, height as usize, max_width, max_height))
+    }
+    /// Undoes the Composite redirection `capture` set up for `window`. The
+    /// window may already be gone, so errors are ignored.
+    pub fn release(&self, window: u32) {
+        use x11rb::connection::Connection;
+        use x11rb::protocol::composite::{ConnectionExt as _, Redirect};
+        let mut redirected = self.redirected.lock().unwrap_or_else(|e| e.into_inner());
+        if redirected.remove(&window) {
+            if let Ok(cookie) = self.conn.composite_unredirect_window(window, Redirect::AUTOMATIC) {
+                let _ = cookie.check();
+            }
+            let _ = self.conn.flush();
+        }
+    }
+    /// Reads a 32-bit ZPixmap image, through shared memory when possible.
+    fn read_image(&self, drawable: u32, width: u16, height: u16) -> Result<Vec<u8>, String> {
+        use x11rb::connection::Connection;
+        use x11rb::protocol::shm::ConnectionExt as _;
+        use x11rb::protocol::xproto::{ConnectionExt as _, ImageFormat};
+        use std::os::fd::AsRawFd;
+        let size = width as usize * height as usize * 4;
+        if self.has_shm {
+            let segment = self.conn.generate_id().map_err(|e| e.to_string())?;
+            let created = self
+                .conn
+                .shm_create_segment(segment, size as u32, false)
+                .map_err(|e| e.to_string())?
+                .reply();
+            if let Ok(created) = created {
+                let mapping = unsafe {
+                    libc::mmap(
+                        std::ptr::null_mut(),
+                        size,
+                        libc::PROT_READ,
+                        libc::MAP_SHARED,
+                        created.shm_fd.as_raw_fd(),
+                        0,
+                    )
+                };
+                let result = if mapping == libc::MAP_FAILED {
+                    Err("could not map the shared memory segment".to_string())
+                } else {
+                    let fetched = self
+                        .conn
+                        .shm_get_image(
+                            drawable,
+                            0,
+                            0,
+                            width,
+                            height,
+                            u32::MAX,
+                            ImageFormat::Z_PIXMAP.into(),
+                            segment,
+                            0,
+                        )
+                        .map_err(|e| e.to_string())
+                        .and_then(|cookie| cookie.reply().map_err(|e| e.to_string()));
+                    let data = fetched.map(|_| {
+                        unsafe { std::slice::from_raw_parts(mapping as *const u8, size) }.to_vec()
+                    });
+                    unsafe { libc::munmap(mapping, size) };
+                    data
+                };
+                let _ = self.conn.shm_detach(segment);
+                let _ = self.conn.flush();
+                return result;
+            }
+        }
+        let reply = self
+            .conn
+            .get_image(ImageFormat::Z_PIXMAP, drawable, 0, 0, width, height, u32::MAX)
+            .map_err(|e| e.to_string())?
+            .reply()
+            .map_err(|e| e.to_string())?;
+        if reply.data.len() < size {
+            return Err("the server returned a truncated image".to_string());
+        }
+        Ok(reply.data)
+    }
+    pub fn list_windows(&self) -> Result<Vec<LinuxWindow>, String> {
+        let atoms = &self.atoms;
+        let active = self
+            .property32(self.root, atoms._NET_ACTIVE_WINDOW)?
+            .and_then(|values| values.first().copied())
+            .unwrap_or(0);
+        let clients = self.property32(self.root, atoms._NET_CLIENT_LIST)?.unwrap_or_default();
+        // Clients can disappear between listing and querying; an error for
+        // one window skips that window, not the whole list.
+        Ok(
+            clients
+                .into_iter()
+                .filter_map(|window| self.client_window(window, active).ok().flatten())
+                .collect(),
+        )
+    }
+    /// Reads one `_NET_CLIENT_LIST` entry; `None` for docks and desktops.
+    fn client_window(&self, window: u32, active: u32) -> Result<Option<LinuxWindow>, String> {
+        use x11rb::protocol::xproto::AtomEnum;
+        let atoms = &self.atoms;
+        let types = self.property32(window, atoms._NET_WM_WINDOW_TYPE)?;
+        let skipped = [atoms._NET_WM_WINDOW_TYPE_DOCK, atoms._NET_WM_WINDOW_TYPE_DESKTOP];
+        if types.is_some_and(|types| types.iter().any(|t| skipped.contains(t))) {
+            return Ok(None);
+        }
+        let title = match self.property_string(window, atoms._NET_WM_NAME)? {
+            Some(title) => title,
+            None => self.property_string(window, AtomEnum::WM_NAME.into())?.unwrap_or_default(),
+        };
+        let pid = self
+            .property32(window, atoms._NET_WM_PID)?
+            .and_then(|values| values.first().copied())
+            .filter(|pid| *pid > 0);
+        // WM_CLASS is "instance\0class\0".
+        let class = self
+            .property_string(window, AtomEnum::WM_CLASS.into())?
+            .and_then(|class| class.split('\0').nth(1).map(str::to_string))
+            .unwrap_or_default();
+        Ok(Some(LinuxWindow {
+            id: window as u64,
+            pid,
+            title,
+            class,
+            is_active: window == active,
+        }))
+    }
+}
+/// A toplevel announced by the wlr-foreign-toplevel-management protocol.
+#[cfg(target_os = "linux")]
+pub struct WaylandToplevel {
+    pub handle: wayland_protocols_wlr::foreign_toplevel::v1::client::zwlr_foreign_toplevel_handle_v1::ZwlrForeignToplevelHandleV1,
+    pub title: String,
+    pub app_id: String,
+    pub activated: bool,
+    pub maximized: bool,
+    pub minimized: bool,
+}
+/// Dispatch state for the Wayland event queue.
+#[cfg(target_os = "linux")]
+#[derive(Default)]
+pub struct WaylandToplevelState {
+    pub toplevels: Vec<WaylandToplevel>,
+}
+/// A Wayland connection bound to the compositor's foreign-toplevel manager.
+#[cfg(target_os = "linux")]
+pub struct WaylandToplevels {
+    pub conn: wayland_client::Connection,
+    pub queue: wayland_client::EventQueue<WaylandToplevelState>,
+    pub state: WaylandToplevelState,
+    pub manager: wayland_protocols_wlr::foreign_toplevel::v1::client::zwlr_foreign_toplevel_manager_v1::ZwlrForeignToplevelManagerV1,
+    pub seat: Option<wayland_client::protocol::wl_seat::WlSeat>,
+}
+#[cfg(target_os = "linux")]
+impl WaylandToplevels {
+    pub fn connect() -> Result<Self, String> {
+        use wayland_client::globals::registry_queue_init;
+        let conn = wayland_client::Connection::connect_to_env().map_err(|e| e.to_string())?;
+        let (globals, mut queue) = registry_queue_init::<WaylandToplevelState>(&conn)
+            .map_err(|e| e.to_string())?;
+        let qh = queue.handle();
+        let manager = globals
+            .bind(&qh, 1..=3, ())
+            .map_err(|_| {
+                "the compositor does not support wlr-foreign-toplevel-management".to_string()
+            })?;
+        let seat = globals.bind(&qh, 1..=1, ()).ok();
+        let mut state = WaylandToplevelState::default();
+        queue.roundtrip(&mut state).map_err(|e| e.to_string())?;
+        Ok(WaylandToplevels {
+            conn,
+            queue,
+            state,
+            manager,
+            seat,
+        })
+    }
+    fn toplevel(&self, id: u64) -> Result<&WaylandToplevel, String> {
+        use wayland_client::Proxy;
+        self.state
+            .toplevels
+            .iter()
+            .find(|toplevel| toplevel.handle.id().protocol_id() as u64 == id)
+            .ok_or_else(|| "the window has closed".to_string())
+    }
+    pub fn activate(&self, id: u64) -> Result<(), String> {
+        let seat = self.seat.as_ref().ok_or_else(|| "the compositor has no seat".to_string())?;
+        self.toplevel(id)?.handle.activate(seat);
+        self.conn.flush().map_err(|e| e.to_string())
+    }
+    pub fn perform(&self, id: u64, action: WindowAction) -> Result<(), String> {
+        let toplevel = self.toplevel(id)?;
+        match action {
+            WindowAction::Minimize => toplevel.handle.set_minimized(),
+            WindowAction::ToggleMaximize if toplevel.maximized => toplevel.handle.unset_maximized(),
+            WindowAction::ToggleMaximize => toplevel.handle.set_maximized(),
+            WindowAction::Close => toplevel.handle.close(),
+        }
+        self.conn.flush().map_err(|e| e.to_string())
+    }
+    pub fn list_windows(&mut self) -> Result<Vec<LinuxWindow>, String> {
+        use wayland_client::Proxy;
+        self.queue.roundtrip(&mut self.state).map_err(|e| e.to_string())?;
+        Ok(
+            self
+                .state
+                .toplevels
+                .iter()
+                .map(|toplevel| LinuxWindow {
+                    id: toplevel.handle.id().protocol_id() as u64,
+                    // The protocol does not expose pids; they are matched by
+                    // app id against process names.
+                    pid: None,
+                    title: toplevel.title.clone(),
+                    class: toplevel.app_id.clone(),
+                    is_active: toplevel.activated,
+                })
+                .collect(),
+        )
+    }
+}
+#[cfg(target_os = "linux")]
+const WAYLAND_NO_PLACEMENT: &str = "Wayland compositors do not let other clients move or resize windows";
+/// Whichever Linux window source could be reached.
+#[cfg(target_os = "linux")]
+pub enum LinuxWindowSystem {
+    X11(X11Windows),
+    Wayland(WaylandToplevels),
+}
+#[cfg(target_os = "linux")]
+impl LinuxWindowSystem {
+    /// Tries X11 EWMH first and the wlroots foreign-toplevel protocol second.
+    /// In a Wayland session the order is reversed, because XWayland only
+    /// knows about X11 clients.
+    pub fn connect() -> Result<Self, String> {
+        let x11 = || X11Windows::connect().map(LinuxWindowSystem::X11);
+        let wayland = || WaylandToplevels::connect().map(LinuxWindowSystem::Wayland);
+        let wayland_session = std::env::var_os("WAYLAND_DISPLAY").is_some();
+        let (first, second): (&dyn Fn() -> Result<Self, String>, &dyn Fn() -> Result<Self, String>) =
+            if wayland_session { (&wayland, &x11) } else { (&x11, &wayland) };
+        first()
+            .or_else(|first_error| {
+                second()
+                    .map_err(|second_error| format!("{}; {}", first_error, second_error))
+            })
+    }
+    pub fn name(&self) -> &'static str {
+        match self {
+            LinuxWindowSystem::X11(_) => "X11 (EWMH)",
+            LinuxWindowSystem::Wayland(_) => "Wayland (foreign-toplevel)",
+        }
+    }
+    pub fn activate(&self, id: u64) -> Result<(), String> {
+        match self {
+            LinuxWindowSystem::X11(x11) => x11.activate(id as u32),
+            LinuxWindowSystem::Wayland(wayland) => wayland.activate(id),
+        }
+    }
+    pub fn perform(&self, id: u64, action: WindowAction) -> Result<(), String> {
+        match self {
+            LinuxWindowSystem::X11(x11) => x11.perform(id as u32, action),
+            LinuxWindowSystem::Wayland(wayland) => wayland.perform(id, action),
+        }
+    }
+    pub fn work_area(&self, id: u64) -> Result<WindowRect, String> {
+        match self {
+            LinuxWindowSystem::X11(x11) => x11.work_area(id as u32),
+            LinuxWindowSystem::Wayland(_) => Err(WAYLAND_NO_PLACEMENT.to_string()),
+        }
+    }
+    pub fn outer_rect(&self, id: u64) -> Result<WindowRect, String> {
+        match self {
+            LinuxWindowSystem::X11(x11) => x11.outer_rect(id as u32),
+            LinuxWindowSystem::Wayland(_) => Err(WAYLAND_NO_PLACEMENT.to_string()),
+        }
+    }
+    pub fn move_resize(&self, id: u64, rect: WindowRect) -> Result<(), String> {
+        match self {
+            LinuxWindowSystem::X11(x11) => x11.move_resize(id as u32, rect),
+            LinuxWindowSystem::Wayland(_) => Err(WAYLAND_NO_PLACEMENT.to_string()),
+        }
+    }
+    pub fn list_windows(&mut self) -> Result<Vec<LinuxWindow>, String> {
+        match self {
+            LinuxWindowSystem::X11(x11) => x11.list_windows(),
+            LinuxWindowSystem::Wayland(wayland) => wayland.list_windows(),
+        }
+    }
+}
+/// Number of recent captures kept for the Live view latency figures.
+pub const THUMBNAIL_STATS_WINDOW: usize = 100;
+pub struct ThumbnailJob {
+    pub window_id: u64,
+    pub max_width: i32,
+    pub max_height: i32,
+    pub queued_at: std::time::Instant,
+}
+pub struct ThumbnailResult {
+    pub window_id: u64,
+    pub image: Option<egui::ColorImage>,
+    pub queue_wait: std::time::Duration,
+    pub capture_time: std::time::Duration,
+}
+/// Pending captures; workers drain `visible` before `background`.
+#[derive(Default)]
+pub struct ThumbnailQueue {
+    pub visible: std::collections::VecDeque<ThumbnailJob>,
+    pub background: std::collections::VecDeque<ThumbnailJob>,
+    /// Windows each worker should stop keeping capturable, by worker index.
+    pub released: Vec<Vec<u64>>,
+    pub shutdown: bool,
+}
+/// Worker threads that capture and downscale window thumbnails off the UI
+/// thread. Stopped when dropped.
+pub struct ThumbnailPipeline {
+    pub queue: std::sync::Arc<(std::sync::Mutex<ThumbnailQueue>, std::sync::Condvar)>,
+    pub results: std::sync::mpsc::Receiver<ThumbnailResult>,
+    /// Windows queued or being captured, so each is requested once at a time.
+    pub in_flight: HashSet<u64>,
+    /// Last capture attempt per window; failed windows are retried on the
+    /// same schedule rather than every frame.
+    pub captured_at: std::collections::HashMap<u64, std::time::Instant>,
+    pub capture_times: std::collections::VecDeque<std::time::Duration>,
+    pub queue_waits: std::collections::VecDeque<std::time::Duration>,
+    pub failures: u64,
+}
+impl ThumbnailPipeline {
+    pub fn start(workers: usize) -> Self {
+        let workers = workers.max(1);
+        let queue = std::sync::Arc::new((
+            std::sync::Mutex::new(ThumbnailQueue {
+                released: vec![Vec::new(); workers],
+                ..ThumbnailQueue::default()
+            }),
+            std::sync::Condvar::new(),
+        ));
+        let (sender, results) = std::sync::mpsc::channel();
+        for index in 0..workers {
+            let queue = queue.clone();
+            let sender = sender.clone();
+            let _ = std::thread::Builder::new()
+                .name(format!("thumbnail-{}", index))
+                .spawn(move || Self::run_worker(index, &queue, &sender));
+        }
+        ThumbnailPipeline {
+            queue,
+            results,
+            in_flight: HashSet::new(),
+            captured_at: std::collections::HashMap::new(),
+            capture_times: std::collections::VecDeque::new(),
+            queue_waits: std::collections::VecDeque::new(),
+            failures: 0,
+        }
+    }
+    fn run_worker(
+        index: usize,
+        queue: &(std::sync::Mutex<ThumbnailQueue>, std::sync::Condvar),
+        sender: &std::sync::mpsc::Sender<ThumbnailResult>,
+    ) {
+        let (lock, ready) = queue;
+        loop {
+            let (released, job) = {
+                let mut pending = lock.lock().unwrap_or_else(|e| e.into_inner());
+                loop {
+                    if pending.shutdown {
+                        return;
+                    }
+                    let released = std::mem::take(&mut pending.released[index]);
+                    if !released.is_empty() {
+                        break (released, None);
+                    }
+                    if let Some(job) = pending.visible.pop_front().or_else(|| pending.background.pop_front()) {
+                        break (Vec::new(), Some(job));
+                    }
+                    pending = ready.wait(pending).unwrap_or_else(|e| e.into_inner());
+                }
+            };
+            for window_id in released {
+                crate::ws::release_window_capture(window_id);
+            }
+            let Some(job) = job else {
+                continue;
+            };
+            let started = std::time::Instant::now();
+            let image = crate::ws::capture_window_image(job.window_id, job.max_width, job.max_height);
+            let result = ThumbnailResult {
+                window_id: job.window_id,
+                image,
+                queue_wait: started - job.queued_at,
+                capture_time: started.elapsed(),
+            };
+            if sender.send(result).is_err() {
+                return;
+            }
+        }
+    }
+    pub fn submit(&mut self, job: ThumbnailJob, visible: bool) {
+        if !self.in_flight.insert(job.window_id) {
+            return;
+        }
+        let (lock, ready) = &*self.queue;
+        let mut pending = lock.lock().unwrap_or_else(|e| e.into_inner());
+        if visible {
+            pending.visible.push_back(job);
+        } else {
+            pending.background.push_back(job);
+        }
+        ready.notify_one();
+    }
+    /// Moves queued jobs between the two lanes after the visible page
+    /// changed, and drops jobs for windows that no longer exist.
+    pub fn reprioritize(&mut self, visible: &HashSet<u64>, existing: &HashSet<u64>) {
+        let (lock, _) = &*self.queue;
+        let mut pending = lock.lock().unwrap_or_else(|e| e.into_inner());
+        let pending = &mut *pending;
+        let jobs: Vec<ThumbnailJob> = pending
+            .visible
+            .drain(..)
+            .chain(pending.background.drain(..))
+            .collect();
+        for job in jobs {
+            if !existing.contains(&job.window_id) {
+                self.in_flight.remove(&job.window_id);
+            } else if visible.contains(&job.window_id) {
+                pending.visible.push_back(job);
+            } else {
+                pending.background.push_back(job);
+            }
+        }
+    }
+    /// Tells every worker to drop what it keeps around to capture these
+    /// windows, such as X11 Composite redirections.
+    pub fn release(&mut self, window_ids: &[u64]) {
+        if window_ids.is_empty() {
+            return;
+        }
+        let (lock, ready) = &*self.queue;
+        let mut pending = lock.lock().unwrap_or_else(|e| e.into_inner());
+        for released in &mut pending.released {
+            released.extend_from_slice(window_ids);
+        }
+        ready.notify_all();
+    }
+    pub fn queued(&self) -> usize {
+        let (lock, _) = &*self.queue;
+        let pending = lock.lock().unwrap_or_else(|e| e.into_inner());
+        pending.visible.len() + pending.background.len()
+    }
+    pub fn record(&mut self, result: &ThumbnailResult) {
+        self.in_flight.remove(&result.window_id);
+        self.captured_at.insert(result.window_id, std::time::Instant::now());
+        if result.image.is_none() {
+            self.failures += 1;
+            return;
+        }
+        self.capture_times.push_back(result.capture_time);
+        self.queue_waits.push_back(result.queue_wait);
+        while self.capture_times.len() > THUMBNAIL_STATS_WINDOW {
+            self.capture_times.pop_front();
+            self.queue_waits.pop_front();
+        }
+    }
+}
+/// Number of samples kept for each throughput graph.
+pub const DISK_HISTORY_LIMIT: usize = 100;
+/// Throughput of one block device from `/proc/diskstats`.
+#[derive(Clone, Debug, Default)]
+pub struct BlockDeviceStats {
+    pub name: String,
+    pub read_total: u64,
+    pub write_total: u64,
+    pub read_rate: u64,
+    pub write_rate: u64,
+    /// (seconds since start, read bytes/s, write bytes/s)
+    pub history: std::collections::VecDeque<(f64, f64, f64)>,
+}
+impl BlockDeviceStats {
+    pub fn push_history(&mut self, elapsed: f64) {
+        self.history.push_back((elapsed, self.read_rate as f64, self.write_rate as f64));
+        if self.history.len() > DISK_HISTORY_LIMIT {
+            self.history.pop_front();
+        }
+    }
+}
+pub struct DisksState {
+    pub disks: sysinfo::Disks,
+    /// Mount list being read on a background thread, since statting a
+    /// stale network mount can block.
+    pub loader: Option<std::sync::mpsc::Receiver<sysinfo::Disks>>,
+    /// A mount listing has completed, even if it was empty.
+    pub loaded: bool,
+    /// Whole block devices; partitions are folded into their disk.
+    pub devices: Vec<BlockDeviceStats>,
+    /// Sum over all devices, or over all processes where the platform has
+    /// no per-device counters.
+    pub total: BlockDeviceStats,
+    /// Device whose graph is shown; `None` shows the total.
+    pub selected_device: Option<String>,
+    pub sampled_at: Option<std::time::Instant>,
+    /// Cumulative (read, written) bytes per PID at the last refresh.
+    pub process_totals: std::collections::HashMap<u32, (u64, u64)>,
+    pub processes_sampled_at: Option<std::time::Instant>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bookmark_config_line_round_trips() {
+        for (name, path) in [
+            ("plain", "/home/user"),
+            ("a=b", "/srv/a=b"),
+            ("back\\slash", "C:\\Users\\me"),
+            ("=\\=", "D:\\x=y\\"),
+            ("100%", "/tmp/%3D"),
+            ("literal %3D and %25", "/data"),
+        ] {
+            let bookmark = FilepaneBookmark {
+                name: name.to_string(),
+                path: path.to_string(),
+            };
+            let line = bookmark.to_config_line();
+            assert_eq!(FilepaneBookmark::from_config_line(&line), Some(bookmark), "{}", line);
+        }
+        assert_eq!(FilepaneBookmark::from_config_line("no separator"), None);
+    }
+
+    #[test]
+    fn sftp_target_parse() {
+        let target = |user: &str, host: &str, port| SftpTarget {
+            user: user.to_string(),
+            host: host.to_string(),
+            port,
+        };
+        assert_eq!(
+            SftpTarget::parse("sftp://alice@example.com:2222/var/log/"),
+            Some((target("alice", "example.com", 2222), "/var/log".to_string()))
+        );
+        assert_eq!(
+            SftpTarget::parse("sftp://bob@host"),
+            Some((target("bob", "host", 22), "/".to_string()))
+        );
+        // Windows path joins, and an `@` inside the user name.
+        assert_eq!(
+            SftpTarget::parse("sftp://me@corp@host\\home\\me"),
+            Some((target("me@corp", "host", 22), "/home/me".to_string()))
+        );
+        assert_eq!(SftpTarget::parse("/home/alice"), None);
+        assert_eq!(SftpTarget::parse("sftp://host/path"), None);
+        assert_eq!(SftpTarget::parse("sftp://@host/path"), None);
+        assert_eq!(SftpTarget::parse("sftp://alice@:22/path"), None);
+        assert_eq!(SftpTarget::parse("sftp://alice@host:ssh/path"), None);
+        assert_eq!(SftpTarget::parse("sftp://alice@host:70000/path"), None);
+    }
 }
@@ -0,0 +1,110 @@
+//! # WaylandToplevelState - Trait Implementations
+//!
+//! This module contains trait implementations for `WaylandToplevelState`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Dispatch<WlRegistry, GlobalListContents>`
+//! - `Dispatch<WlSeat, ()>`
+//! - `Dispatch<ZwlrForeignToplevelManagerV1, ()>`
+//! - `Dispatch<ZwlrForeignToplevelHandleV1, ()>`
+
+#![cfg(target_os = "linux")]
+
+use wayland_client::globals::GlobalListContents;
+use wayland_client::protocol::{wl_registry, wl_seat};
+use wayland_client::{Connection, Dispatch, Proxy, QueueHandle};
+use wayland_protocols_wlr::foreign_toplevel::v1::client::zwlr_foreign_toplevel_handle_v1::{
+    self, ZwlrForeignToplevelHandleV1,
+};
+use wayland_protocols_wlr::foreign_toplevel::v1::client::zwlr_foreign_toplevel_manager_v1::{
+    self, ZwlrForeignToplevelManagerV1,
+};
+use super::types::{WaylandToplevel, WaylandToplevelState};
+
+impl Dispatch<wl_registry::WlRegistry, GlobalListContents> for WaylandToplevelState {
+    fn event(
+        _: &mut Self,
+        _: &wl_registry::WlRegistry,
+        _: wl_registry::Event,
+        _: &GlobalListContents,
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {}
+}
+
+impl Dispatch<wl_seat::WlSeat, ()> for WaylandToplevelState {
+    fn event(
+        _: &mut Self,
+        _: &wl_seat::WlSeat,
+        _: wl_seat::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {}
+}
+
+impl Dispatch<ZwlrForeignToplevelManagerV1, ()> for WaylandToplevelState {
+    fn event(
+        state: &mut Self,
+        _: &ZwlrForeignToplevelManagerV1,
+        event: zwlr_foreign_toplevel_manager_v1::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+        if let zwlr_foreign_toplevel_manager_v1::Event::Toplevel { toplevel } = event {
+            state
+                .toplevels
+                .push(WaylandToplevel {
+                    handle: toplevel,
+                    title: String::new(),
+                    app_id: String::new(),
+                    activated: false,
+                    maximized: false,
+                    minimized: false,
+                });
+        }
+    }
+
+    wayland_client::event_created_child!(
+        WaylandToplevelState, ZwlrForeignToplevelManagerV1, [
+            zwlr_foreign_toplevel_manager_v1::EVT_TOPLEVEL_OPCODE => (ZwlrForeignToplevelHandleV1, ()),
+        ]
+    );
+}
+
+impl Dispatch<ZwlrForeignToplevelHandleV1, ()> for WaylandToplevelState {
+    fn event(
+        state: &mut Self,
+        handle: &ZwlrForeignToplevelHandleV1,
+        event: zwlr_foreign_toplevel_handle_v1::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+        use zwlr_foreign_toplevel_handle_v1::{Event, State};
+        let Some(index) = state.toplevels.iter().position(|t| t.handle.id() == handle.id()) else {
+            return;
+        };
+        let toplevel = &mut state.toplevels[index];
+        match event {
+            Event::Title { title } => toplevel.title = title,
+            Event::AppId { app_id } => toplevel.app_id = app_id,
+            Event::State { state: states } => {
+                // A list of native-endian u32 state values.
+                let states: Vec<u32> = states
+                    .chunks_exact(4)
+                    .map(|chunk| u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                    .collect();
+                toplevel.activated = states.contains(&(State::Activated as u32));
+                toplevel.maximized = states.contains(&(State::Maximized as u32));
+                toplevel.minimized = states.contains(&(State::Minimized as u32));
+            }
+            Event::Closed => {
+                state.toplevels.remove(index).handle.destroy();
+            }
+            _ => {}
+        }
+    }
+}
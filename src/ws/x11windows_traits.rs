@@ -0,0 +1,26 @@
+//! # X11Windows - Trait Implementations
+//!
+//! This module contains trait implementations for `X11Windows`.
+//!
+//! ## Implemented Traits
+//!
+//! - `Drop`
+
+#![cfg(target_os = "linux")]
+
+use super::types::X11Windows;
+
+impl Drop for X11Windows {
+    /// The server would undo the redirections when the connection closes;
+    /// undoing them here keeps that from depending on how the socket ends.
+    fn drop(&mut self) {
+        let redirected: Vec<u32> = self
+            .redirected
+            .lock()
+            .map(|redirected| redirected.iter().copied().collect())
+            .unwrap_or_default();
+        for window in redirected {
+            self.release(window);
+        }
+    }
+}